@@ -0,0 +1,186 @@
+// game/src/ai.rs
+//
+// The Pac-Man autopilot.
+//
+// One bot drives Pac-Man wherever the computer plays him:
+//
+// - the attract demo (`start_demo()`),
+// - "ghosts vs CPU": PvP with the autopilot on, so a human plays the ghost,
+// - as an assist: `Assist::autopilot` takes over from the player.
+//
+// The bot is greedy: it heads along the shortest path to the nearest dot or
+// power pellet, treating tiles next to a dangerous (chasing or scattering)
+// ghost as walls. When no safe path exists it runs, taking whichever open
+// direction leads furthest from the nearest such ghost. It reads only
+// `GameStateInner`, and steers through the same input buffer as a player.
+
+use std::collections::VecDeque;
+
+use crate::entities::{Direction, GhostMode};
+use crate::maze::CellType;
+use crate::state::{GameMode, GameStateInner};
+
+// ─── Autopilot ──────────────────────────────────────────────────────────────
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Left,
+    Direction::Down,
+    Direction::Right,
+];
+
+impl GameStateInner {
+    /// Steer Pac-Man for this step, as the autopilot would.
+    pub(crate) fn autopilot_steer(&mut self) {
+        if let Some(dir) = self.autopilot_direction() {
+            self.pacman.buffer_turn(dir);
+        }
+    }
+
+    /// The direction the autopilot wants Pac-Man to go, if any.
+    pub fn autopilot_direction(&self) -> Option<Direction> {
+        self.path_to_nearest_dot()
+            .or_else(|| self.escape_direction())
+    }
+
+    /// Start a PvP game with the autopilot as Pac-Man, so a human plays
+    /// the ghost against the computer.
+    pub fn ghosts_vs_cpu() -> GameStateInner {
+        let mut state = GameStateInner::new(GameMode::PvP);
+        state.config.assist.autopilot = true;
+        state
+    }
+
+    /// Manhattan distance from (col, row) to the nearest dangerous ghost.
+    fn ghost_distance(&self, col: usize, row: usize) -> Option<usize> {
+        self.ghosts
+            .iter()
+            .filter(|g| matches!(g.mode, GhostMode::Chase | GhostMode::Scatter))
+            .map(|g| {
+                let (gc, gr) = g.position.to_grid();
+                gc.abs_diff(col) + gr.abs_diff(row)
+            })
+            .min()
+    }
+
+    /// The tile one step from (col, row) in `dir`, wrapping through
+    /// tunnels.
+    fn step_from(&self, col: usize, row: usize, dir: Direction) -> (usize, usize) {
+        let (w, h) = (self.maze.width as isize, self.maze.height as isize);
+        let (dx, dy) = dir.to_vector();
+        (
+            (col as isize + dx as isize).rem_euclid(w) as usize,
+            (row as isize + dy as isize).rem_euclid(h) as usize,
+        )
+    }
+
+    /// First step of a shortest path from Pac-Man to a dot or pellet,
+    /// treating tiles next to dangerous ghosts as blocked.
+    fn path_to_nearest_dot(&self) -> Option<Direction> {
+        let (w, h) = (self.maze.width, self.maze.height);
+        let (start_col, start_row) = self.pacman.position.to_grid();
+        if start_col >= w || start_row >= h {
+            return None; // Mid-wrap through a tunnel
+        }
+        let danger = |col, row| self.ghost_distance(col, row).is_some_and(|d| d <= 1);
+
+        let mut first: Vec<Option<Direction>> = vec![None; w * h];
+        let mut seen = vec![false; w * h];
+        let mut queue = VecDeque::new();
+        seen[start_row * w + start_col] = true;
+        queue.push_back((start_col, start_row));
+
+        while let Some((col, row)) = queue.pop_front() {
+            let here = row * w + col;
+            if here != start_row * w + start_col
+                && matches!(
+                    self.maze.get_cell(row, col),
+                    Some(CellType::Dot | CellType::PowerPellet)
+                )
+            {
+                return first[here];
+            }
+            for dir in DIRECTIONS {
+                if !self.maze.can_move(col as f64, row as f64, dir) {
+                    continue;
+                }
+                let (next_col, next_row) = self.step_from(col, row, dir);
+                let next = next_row * w + next_col;
+                if seen[next] || danger(next_col, next_row) {
+                    continue;
+                }
+                seen[next] = true;
+                first[next] = first[here].or(Some(dir));
+                queue.push_back((next_col, next_row));
+            }
+        }
+        None
+    }
+
+    /// With no safe path to a dot: the open direction that leads furthest
+    /// from the nearest dangerous ghost.
+    fn escape_direction(&self) -> Option<Direction> {
+        let (col, row) = self.pacman.position.to_grid();
+        if col >= self.maze.width || row >= self.maze.height {
+            return None;
+        }
+        DIRECTIONS
+            .into_iter()
+            .filter(|&dir| self.maze.can_move(col as f64, row as f64, dir))
+            .max_by_key(|&dir| {
+                let (c, r) = self.step_from(col, row, dir);
+                self.ghost_distance(c, r).unwrap_or(usize::MAX)
+            })
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::Position;
+    use crate::state::GamePhase;
+
+    fn clear_dots(state: &mut GameStateInner) {
+        for row in state.maze.cells.iter_mut() {
+            for cell in row.iter_mut() {
+                if matches!(cell, CellType::Dot | CellType::PowerPellet) {
+                    *cell = CellType::Empty;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn autopilot_heads_for_the_nearest_dot() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        // Only dot left is straight up the column from (6, 23)
+        clear_dots(&mut state);
+        state.maze.cells[20][6] = CellType::Dot;
+        state.pacman.position = Position::new(6.0, 23.0);
+        assert_eq!(state.autopilot_direction(), Some(Direction::Up));
+
+        // A ghost in the way: go round it
+        state.ghosts[0].position = Position::new(6.0, 21.0);
+        state.ghosts[0].mode = GhostMode::Chase;
+        assert_ne!(state.autopilot_direction(), Some(Direction::Up));
+
+        // Nothing safe to eat: run from the ghost
+        state.maze.cells[20][6] = CellType::Empty;
+        state.ghosts[0].position = Position::new(8.0, 23.0);
+        let dir = state.autopilot_direction();
+        assert!(dir.is_some() && dir != Some(Direction::Right));
+    }
+
+    #[test]
+    fn the_cpu_plays_pacman_against_a_human_ghost() {
+        let mut state = GameStateInner::ghosts_vs_cpu();
+        state.phase = GamePhase::Playing;
+        for _ in 0..60 {
+            state.tick(1.0 / 60.0);
+        }
+        assert!(state.pacman.score > 0);
+        assert_eq!(state.player_ghost(), Some(0));
+    }
+}
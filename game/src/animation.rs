@@ -0,0 +1,128 @@
+// game/src/animation.rs
+//
+// Sprite animation frames.
+//
+// The renderer shouldn't need its own clocks to animate sprites: `tick()`
+// advances them here and the snapshot carries plain frame indices, so a
+// frontend only maps an index to a sprite.
+//
+// - Pac-Man's mouth is tied to movement: it works through `MOUTH_FRAMES`
+//   as he covers ground and stays put while he's blocked or stalled.
+// - Ghost legs wiggle between two frames on a fixed clock while play runs.
+// - Frightened ghosts show frame 0 (blue) or 1 (white) while they flash.
+// - The death animation runs through `DEATH_FRAMES` over `DYING_DURATION`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{GhostMode, Position};
+use crate::levels::FrightSchedule;
+use crate::state::{GamePhase, GameStateInner, DYING_DURATION};
+
+// ─── Timing ─────────────────────────────────────────────────────────────────
+
+/// Mouth frames per cycle: closed, half open, open, half open.
+pub const MOUTH_FRAMES: u8 = 4;
+
+/// Tiles Pac-Man covers per mouth frame.
+pub const TILES_PER_MOUTH_FRAME: f64 = 0.25;
+
+/// Seconds each ghost leg frame is shown (eight arcade frames).
+pub const WIGGLE_PERIOD: f64 = 8.0 / 60.0;
+
+/// Frames of the death animation, from the stunned pose to the last
+/// sparkle.
+pub const DEATH_FRAMES: u8 = 11;
+
+// ─── Animation state ────────────────────────────────────────────────────────
+
+/// Frame indices for this tick, plus the clocks behind them.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Animation {
+    /// Pac-Man's mouth, `0..MOUTH_FRAMES` (0 = closed).
+    pub mouth_frame: u8,
+    /// Ghost legs, 0 or 1 (all ghosts wiggle together).
+    pub wiggle_frame: u8,
+    /// Flashing frightened ghosts: 0 = blue, 1 = white.
+    pub flash_frame: u8,
+    /// Death animation, `0..DEATH_FRAMES`; 0 outside `Dying`.
+    pub death_frame: u8,
+    /// Tiles travelled along the mouth cycle.
+    pub mouth_travel: f64,
+    /// Seconds into the current wiggle cycle.
+    pub wiggle_clock: f64,
+}
+
+impl GameStateInner {
+    /// Advance the animation frames after a tick of `dt` seconds in which
+    /// Pac-Man moved from `pac_before`.
+    pub(crate) fn update_animation(&mut self, dt: f64, pac_before: &Position) {
+        let anim = &mut self.animation;
+        let live = matches!(self.phase, GamePhase::Playing | GamePhase::Demo);
+
+        let moved = (self.pacman.position.x - pac_before.x).abs()
+            + (self.pacman.position.y - pac_before.y).abs();
+        // A jump of a tile or more is a tunnel wrap or a warp, not movement
+        if live && moved < 1.0 {
+            let cycle = MOUTH_FRAMES as f64 * TILES_PER_MOUTH_FRAME;
+            anim.mouth_travel = (anim.mouth_travel + moved) % cycle;
+            anim.mouth_frame = (anim.mouth_travel / TILES_PER_MOUTH_FRAME) as u8;
+        }
+
+        if live {
+            anim.wiggle_clock = (anim.wiggle_clock + dt) % (2.0 * WIGGLE_PERIOD);
+            anim.wiggle_frame = (anim.wiggle_clock >= WIGGLE_PERIOD) as u8;
+        }
+
+        let white = FrightSchedule::for_level(self.level).is_white(self.frightened_timer);
+        let any_frightened = self.ghosts.iter().any(|g| g.mode == GhostMode::Frightened);
+        anim.flash_frame = (white && any_frightened) as u8;
+
+        anim.death_frame = if self.phase == GamePhase::Dying {
+            let progress = 1.0 - self.phase_timer / DYING_DURATION;
+            ((progress * DEATH_FRAMES as f64) as u8).min(DEATH_FRAMES - 1)
+        } else {
+            0
+        };
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::GameMode;
+
+    #[test]
+    fn mouth_follows_movement_and_legs_follow_time() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        let before = state.pacman.position.clone();
+        state.pacman.position.x -= 0.6;
+        state.update_animation(WIGGLE_PERIOD, &before);
+        assert_eq!(state.animation.mouth_frame, 2);
+        assert_eq!(state.animation.wiggle_frame, 1);
+
+        // Standing still keeps the mouth where it was
+        let here = state.pacman.position.clone();
+        state.update_animation(WIGGLE_PERIOD, &here);
+        assert_eq!(state.animation.mouth_frame, 2);
+        assert_eq!(state.animation.wiggle_frame, 0);
+    }
+
+    #[test]
+    fn death_frames_run_across_the_dying_phase() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Dying;
+        let here = state.pacman.position.clone();
+
+        state.phase_timer = DYING_DURATION;
+        state.update_animation(0.0, &here);
+        assert_eq!(state.animation.death_frame, 0);
+
+        state.phase_timer = 0.0;
+        state.update_animation(0.0, &here);
+        assert_eq!(state.animation.death_frame, DEATH_FRAMES - 1);
+    }
+}
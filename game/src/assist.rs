@@ -0,0 +1,143 @@
+// game/src/assist.rs
+//
+// Assist options: accessibility settings applied by the simulation.
+//
+// A frontend can offer "can't die", slower ghosts, more lives or a slower
+// game without touching the rules itself; every assist is applied in
+// `tick()` and the collision checks, so all frontends behave the same.
+//
+// The assists live in `GameConfig::assist`, so they are saved and restored
+// with the rest of the player's settings:
+//
+// ```text
+// game.set_assist({ invincible: true, ghost_slowdown: 0.3 })
+// ```
+//
+// Everything off (`Assist::default()`) is the normal game.
+
+use serde::{Deserialize, Serialize};
+
+use crate::state::GameStateInner;
+
+// ─── Assist options ─────────────────────────────────────────────────────────
+
+/// Accessibility assists, all off by default.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Assist {
+    /// Ghosts pass through Pac-Man instead of catching him.
+    pub invincible: bool,
+    /// Fraction taken off every ghost speed (0 to below 1; 0.25 makes them
+    /// a quarter slower).
+    pub ghost_slowdown: f64,
+    /// Lives added at the start of each game.
+    pub extra_lives: u8,
+    /// Speed of the whole game (above 0, up to 1), on top of
+    /// `set_time_scale`.
+    pub game_speed: f64,
+    /// A power pellet's fright never wears off; ghosts stay blue until
+    /// eaten.
+    pub infinite_frightened: bool,
+    /// The computer plays Pac-Man (see `ai.rs`); player input is
+    /// overridden while this is on.
+    pub autopilot: bool,
+}
+
+impl Default for Assist {
+    fn default() -> Self {
+        Assist {
+            invincible: false,
+            ghost_slowdown: 0.0,
+            extra_lives: 0,
+            game_speed: 1.0,
+            infinite_frightened: false,
+            autopilot: false,
+        }
+    }
+}
+
+impl Assist {
+    /// Why these assists can't be used, if they can't.
+    pub fn check(&self) -> Result<(), String> {
+        if !(0.0..1.0).contains(&self.ghost_slowdown) {
+            return Err(format!(
+                "ghost_slowdown must be at least 0 and below 1, not {}",
+                self.ghost_slowdown
+            ));
+        }
+        if !(self.game_speed > 0.0 && self.game_speed <= 1.0) {
+            return Err(format!(
+                "game_speed must be above 0 and at most 1, not {}",
+                self.game_speed
+            ));
+        }
+        Ok(())
+    }
+
+    /// Factor on ghost speeds.
+    pub fn ghost_speed(&self) -> f64 {
+        1.0 - self.ghost_slowdown
+    }
+}
+
+impl GameStateInner {
+    /// Switch assists on or off mid-game. `extra_lives` counts from the
+    /// next game; everything else applies from the next tick.
+    pub fn set_assist(&mut self, assist: Assist) -> Result<(), String> {
+        assist.check()?;
+        self.config.assist = assist;
+        Ok(())
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::GhostMode;
+    use crate::state::{GameMode, GamePhase};
+
+    #[test]
+    fn invincible_pacman_walks_through_ghosts() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        let assist = Assist {
+            invincible: true,
+            extra_lives: 2,
+            ..Assist::default()
+        };
+        state.set_assist(assist).unwrap();
+        state.phase = GamePhase::Playing;
+        state.ghosts[0].position = state.pacman.position.clone();
+        state.tick(1.0 / 60.0);
+        assert_eq!(state.phase, GamePhase::Playing);
+
+        state.reset();
+        assert_eq!(state.pacman.lives, 5);
+    }
+
+    #[test]
+    fn infinite_fright_never_wears_off() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        let assist = Assist {
+            infinite_frightened: true,
+            game_speed: 0.5,
+            ..Assist::default()
+        };
+        state.set_assist(assist).unwrap();
+        state.phase = GamePhase::Playing;
+        state.frightened_timer = 1.0;
+        state.ghosts[1].mode = GhostMode::Frightened;
+        for _ in 0..120 {
+            state.tick(1.0 / 60.0);
+        }
+        assert_eq!(state.frightened_timer, 1.0);
+        assert_eq!(state.ghosts[1].mode, GhostMode::Frightened);
+
+        let bad = Assist {
+            ghost_slowdown: 1.0,
+            ..Assist::default()
+        };
+        assert!(state.set_assist(bad).is_err());
+    }
+}
@@ -0,0 +1,194 @@
+// game/src/audio.rs
+//
+// Synthesized sound effects (feature `audio`).
+//
+// Every `SoundCue` can be rendered to mono PCM here, so a frontend can play
+// classic-sounding effects through WebAudio without shipping samples:
+//
+// ```text
+// const pcm = GameState.synthesize_sound("waka", ctx.sampleRate);
+// const buf = ctx.createBuffer(1, pcm.length, ctx.sampleRate);
+// buf.copyToChannel(pcm, 0);
+// ```
+//
+// The sounds are approximations built from frequency sweeps of square and
+// triangle waves, the way the arcade's wavetable chip did it, not samples
+// of the original. Loops (`is_loop()` cues) are a single cycle meant to be
+// played with `loop = true`; one-shots fade in and out over a couple of
+// milliseconds so they don't click.
+
+use crate::events::SoundCue;
+
+// ─── Cues ───────────────────────────────────────────────────────────────────
+
+/// Every cue, in `SoundCue` order, sirens lowest first.
+pub const ALL_CUES: [SoundCue; 13] = [
+    SoundCue::Waka,
+    SoundCue::WakaAlt,
+    SoundCue::PowerPellet,
+    SoundCue::GhostEaten,
+    SoundCue::Death,
+    SoundCue::ExtraLife,
+    SoundCue::Siren(0),
+    SoundCue::Siren(1),
+    SoundCue::Siren(2),
+    SoundCue::Siren(3),
+    SoundCue::Siren(4),
+    SoundCue::PowerLoop,
+    SoundCue::EyesLoop,
+];
+
+impl SoundCue {
+    /// The cue whose `as_str()` is `name`.
+    pub fn from_name(name: &str) -> Option<SoundCue> {
+        ALL_CUES.into_iter().find(|cue| cue.as_str() == name)
+    }
+}
+
+// ─── Synthesis ──────────────────────────────────────────────────────────────
+
+/// Peak amplitude, leaving headroom for several sounds at once.
+const VOLUME: f32 = 0.3;
+/// Fade at each end of a one-shot, in seconds.
+const FADE: f64 = 0.002;
+
+#[derive(Clone, Copy)]
+enum Wave {
+    Square,
+    Triangle,
+}
+
+impl Wave {
+    /// Sample at `phase` (in cycles), in -1..=1.
+    fn at(self, phase: f64) -> f32 {
+        let p = phase.fract();
+        match self {
+            Wave::Square => {
+                if p < 0.5 {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
+            Wave::Triangle => (1.0 - 4.0 * (p - 0.5).abs()) as f32,
+        }
+    }
+}
+
+/// Appends sweeps to a buffer, keeping the phase continuous between them.
+struct Synth {
+    rate: f64,
+    phase: f64,
+    out: Vec<f32>,
+}
+
+impl Synth {
+    fn new(sample_rate: u32) -> Self {
+        Synth {
+            rate: sample_rate as f64,
+            phase: 0.0,
+            out: Vec::new(),
+        }
+    }
+
+    /// `seconds` of `wave` gliding from `from` to `to` Hz.
+    fn sweep(&mut self, wave: Wave, from: f64, to: f64, seconds: f64) {
+        let n = (seconds * self.rate).round() as usize;
+        for i in 0..n {
+            let freq = from + (to - from) * i as f64 / n as f64;
+            self.out.push(wave.at(self.phase) * VOLUME);
+            self.phase += freq / self.rate;
+        }
+    }
+
+    fn silence(&mut self, seconds: f64) {
+        let n = (seconds * self.rate).round() as usize;
+        self.out.extend(std::iter::repeat_n(0.0, n));
+        self.phase = 0.0;
+    }
+
+    /// The buffer, faded at both ends.
+    fn one_shot(mut self) -> Vec<f32> {
+        let fade = ((FADE * self.rate) as usize).min(self.out.len() / 2).max(1);
+        let len = self.out.len();
+        for i in 0..fade.min(len) {
+            let gain = i as f32 / fade as f32;
+            self.out[i] *= gain;
+            self.out[len - 1 - i] *= gain;
+        }
+        self.out
+    }
+}
+
+/// Mono PCM for `cue` at `sample_rate` Hz, samples in -1..=1.
+pub fn synthesize(cue: SoundCue, sample_rate: u32) -> Vec<f32> {
+    let mut s = Synth::new(sample_rate);
+    match cue {
+        SoundCue::Waka => s.sweep(Wave::Triangle, 480.0, 260.0, 0.13),
+        SoundCue::WakaAlt => s.sweep(Wave::Triangle, 260.0, 480.0, 0.13),
+        SoundCue::PowerPellet => s.sweep(Wave::Square, 200.0, 800.0, 0.12),
+        SoundCue::GhostEaten => s.sweep(Wave::Square, 100.0, 1200.0, 0.5),
+        SoundCue::Death => {
+            // Nine falling wobbles, each starting lower, then two blips
+            for i in 0..9 {
+                let top = 850.0 - i as f64 * 60.0;
+                s.sweep(Wave::Triangle, top, top * 0.6, 0.06);
+                s.sweep(Wave::Triangle, top * 0.6, top, 0.06);
+            }
+            for _ in 0..2 {
+                s.sweep(Wave::Square, 700.0, 100.0, 0.09);
+                s.silence(0.04);
+            }
+        }
+        SoundCue::ExtraLife => {
+            for _ in 0..6 {
+                s.sweep(Wave::Square, 1400.0, 1400.0, 0.07);
+                s.silence(0.05);
+            }
+        }
+        SoundCue::Siren(level) => {
+            // One rise and fall; higher and faster as the board empties
+            let level = level.min(4) as f64;
+            let low = 400.0 + 60.0 * level;
+            let half = 0.2 - 0.02 * level;
+            s.sweep(Wave::Triangle, low, low * 1.5, half);
+            s.sweep(Wave::Triangle, low * 1.5, low, half);
+            return s.out;
+        }
+        SoundCue::PowerLoop => {
+            s.sweep(Wave::Square, 300.0, 650.0, 0.14);
+            return s.out;
+        }
+        SoundCue::EyesLoop => {
+            s.sweep(Wave::Square, 1300.0, 500.0, 0.1);
+            return s.out;
+        }
+    }
+    s.one_shot()
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_cue_synthesizes_by_name() {
+        for cue in ALL_CUES {
+            assert_eq!(SoundCue::from_name(cue.as_str()), Some(cue));
+            let pcm = synthesize(cue, 22_050);
+            assert!(!pcm.is_empty(), "{:?}", cue);
+            assert!(pcm.iter().all(|s| s.abs() <= 1.0), "{:?}", cue);
+        }
+        assert_eq!(SoundCue::from_name("boing"), None);
+    }
+
+    #[test]
+    fn one_shots_fade_and_length_follows_the_rate() {
+        let pcm = synthesize(SoundCue::Waka, 44_100);
+        assert_eq!(pcm.len(), (0.13 * 44_100.0_f64).round() as usize);
+        assert_eq!(pcm[0], 0.0);
+        assert_eq!(synthesize(SoundCue::Waka, 22_050).len() * 2, pcm.len() + 1);
+    }
+}
@@ -0,0 +1,84 @@
+// game/src/boost.rs
+//
+// The speed-boost power-up.
+//
+// A `CellType::SpeedBoost` tile ('+' in layouts) makes Pac-Man
+// `BOOST_FACTOR` times as fast for `BOOST_SECONDS`. The effect lives on
+// the `PacMan` that ate it (`PacMan::boost`, the seconds left), so in Co-op
+// each player has their own, a death ends it, and the snapshot carries it
+// for the renderer to draw a trail. Another pickup restarts the clock.
+
+use crate::entities::PacMan;
+use crate::state::GameStateInner;
+
+// ─── Speed boost ────────────────────────────────────────────────────────────
+
+/// Seconds a boost lasts.
+pub const BOOST_SECONDS: f64 = 5.0;
+
+/// Factor on Pac-Man's speed while boosted.
+pub const BOOST_FACTOR: f64 = 1.5;
+
+impl PacMan {
+    /// Factor on this Pac-Man's speed from a boost (1 when there's none).
+    pub fn boost_factor(&self) -> f64 {
+        if self.boost > 0.0 {
+            BOOST_FACTOR
+        } else {
+            1.0
+        }
+    }
+}
+
+impl GameStateInner {
+    /// Run down every Pac-Man's boost.
+    pub(crate) fn update_boosts(&mut self, dt: f64) {
+        for pacman in std::iter::once(&mut self.pacman).chain(self.pacman2.as_mut()) {
+            pacman.boost = (pacman.boost - dt).max(0.0);
+        }
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maze::CellType;
+    use crate::state::{GameMode, GamePhase};
+
+    /// How far Pac-Man runs left along his starting row in `frames`.
+    fn run(state: &mut GameStateInner, frames: usize) -> f64 {
+        let start = state.pacman.position.x;
+        for _ in 0..frames {
+            state.tick(1.0 / 60.0);
+        }
+        start - state.pacman.position.x
+    }
+
+    #[test]
+    fn a_boost_speeds_pacman_up_for_a_while() {
+        let mut plain = GameStateInner::new(GameMode::Classic);
+        plain.phase = GamePhase::Playing;
+        let mut boosted = plain.clone();
+        let (col, row) = boosted.pacman.position.to_grid();
+        boosted.maze.set_cell(row, col, CellType::SpeedBoost);
+
+        run(&mut plain, 1);
+        run(&mut boosted, 1);
+        assert_eq!(boosted.maze.get_cell(row, col), Some(CellType::Empty));
+        assert!(boosted.pacman.boost > BOOST_SECONDS - 0.1);
+        let (slow, fast) = (run(&mut plain, 20), run(&mut boosted, 20));
+        assert!(
+            (fast - slow * BOOST_FACTOR).abs() < 0.2,
+            "{} vs {}",
+            fast,
+            slow
+        );
+
+        boosted.pacman.boost = 0.01;
+        run(&mut boosted, 1);
+        assert_eq!(boosted.pacman.boost, 0.0);
+        assert_eq!(boosted.pacman.boost_factor(), 1.0);
+    }
+}
@@ -0,0 +1,232 @@
+// game/src/campaign.rs
+//
+// Campaigns: a sequence of custom mazes played back to back.
+//
+// Between mazes only the board and entity positions are reset. Score,
+// lives, and Pac-Man's power-up inventory (wall breakers, keys) carry
+// over, and each finished maze is recorded for the final summary.
+
+use serde::{Deserialize, Serialize};
+
+use crate::maze::{Maze, MazeError};
+use crate::state::{GameMode, GamePhase, GameStateInner};
+
+// ─── Campaign state ─────────────────────────────────────────────────────────
+
+/// The outcome of one maze in a campaign.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MazeResult {
+    /// Index of the maze within the campaign.
+    pub maze: usize,
+    /// Points earned while playing this maze.
+    pub score: u32,
+    /// Whether every dot was eaten before moving on.
+    pub cleared: bool,
+}
+
+/// Progress through a campaign, stored on `GameStateInner`.
+///
+/// # Ownership note
+/// `mazes` keeps the *pristine* boards. The maze being played lives in
+/// `GameStateInner::maze` and gets mutated (dots eaten, walls broken);
+/// moving on clones the next pristine board into place.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Campaign {
+    pub mazes: Vec<Maze>,
+    /// Index of the maze currently being played.
+    pub current: usize,
+    /// Results of the mazes finished so far, in order.
+    pub results: Vec<MazeResult>,
+    /// Pac-Man's score when the current maze started.
+    pub maze_start_score: u32,
+}
+
+/// Combined summary of a campaign, for the end-of-run screen.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CampaignSummary {
+    pub mazes_total: usize,
+    pub mazes_played: usize,
+    pub mazes_cleared: usize,
+    pub final_score: u32,
+    pub lives_remaining: u8,
+    pub results: Vec<MazeResult>,
+}
+
+// ─── Campaign flow on GameStateInner ────────────────────────────────────────
+
+impl GameStateInner {
+    /// Create a game that plays `mazes` in order.
+    ///
+    /// An empty list falls back to the classic maze as a one-maze campaign.
+    pub fn new_campaign(mode: GameMode, mazes: Vec<Maze>) -> Self {
+        let mazes = if mazes.is_empty() {
+            vec![Maze::new()]
+        } else {
+            mazes
+        };
+
+        let mut state = GameStateInner::new(mode);
+        state.load_maze(mazes[0].clone());
+        state.campaign = Some(Campaign {
+            mazes,
+            current: 0,
+            results: Vec::new(),
+            maze_start_score: 0,
+        });
+        state
+    }
+
+    /// A campaign of layout texts (legend as in `CellType::from_char`),
+    /// each checked by `Maze::from_str` and `Maze::validate` like any
+    /// custom maze.
+    pub fn campaign_from_layouts(
+        mode: GameMode,
+        layouts: &[String],
+    ) -> Result<GameStateInner, String> {
+        let mut mazes = Vec::with_capacity(layouts.len());
+        for (i, layout) in layouts.iter().enumerate() {
+            let maze: Maze = layout
+                .parse()
+                .map_err(|e: MazeError| format!("campaign layout {}: {}", i, e))?;
+            let report = maze.validate();
+            if !report.is_valid() {
+                return Err(format!("campaign layout {}: {}", i, report));
+            }
+            mazes.push(maze);
+        }
+        Ok(GameStateInner::new_campaign(mode, mazes))
+    }
+
+    /// Record the current maze's result and move on to the next one.
+    ///
+    /// Score, lives, and inventory are kept. Returns `false` (and ends the
+    /// game) when there is no next maze, or if this isn't a campaign.
+    pub fn transition_to_next_maze(&mut self) -> bool {
+        let score = self.pacman.score;
+        let cleared = self.is_level_complete();
+        let Some(campaign) = self.campaign.as_mut() else {
+            return false;
+        };
+
+        campaign.results.push(MazeResult {
+            maze: campaign.current,
+            score: score.saturating_sub(campaign.maze_start_score),
+            cleared,
+        });
+        campaign.current += 1;
+        campaign.maze_start_score = score;
+
+        match campaign.mazes.get(campaign.current).cloned() {
+            Some(next) => {
+                self.load_maze(next);
+                true
+            }
+            None => {
+                self.phase = GamePhase::GameOver;
+                false
+            }
+        }
+    }
+
+    /// Summarize the campaign so far, or `None` outside a campaign.
+    pub fn campaign_summary(&self) -> Option<CampaignSummary> {
+        let campaign = self.campaign.as_ref()?;
+        Some(CampaignSummary {
+            mazes_total: campaign.mazes.len(),
+            mazes_played: campaign.results.len(),
+            mazes_cleared: campaign.results.iter().filter(|r| r.cleared).count(),
+            final_score: self.pacman.score,
+            lives_remaining: self.pacman.lives,
+            results: campaign.results.clone(),
+        })
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiny_maze() -> Maze {
+        Maze::from_rows(&["WWWWW", "W...W", "WWWWW"], 5)
+    }
+
+    #[test]
+    fn campaign_starts_on_first_maze() {
+        let state = GameStateInner::new_campaign(GameMode::Classic, vec![tiny_maze(), Maze::new()]);
+        assert_eq!(state.maze.width, 5);
+        assert_eq!(state.dots_remaining, 3);
+        assert_eq!(state.campaign.as_ref().unwrap().current, 0);
+    }
+
+    #[test]
+    fn transition_carries_score_lives_and_inventory() {
+        let mut state =
+            GameStateInner::new_campaign(GameMode::Classic, vec![tiny_maze(), Maze::new()]);
+        state.pacman.score = 1200;
+        state.pacman.lives = 2;
+        state.pacman.keys.push(1);
+        state.dots_remaining = 0;
+
+        assert!(state.transition_to_next_maze());
+
+        assert_eq!(state.maze.width, Maze::new().width);
+        assert_eq!(state.pacman.score, 1200);
+        assert_eq!(state.pacman.lives, 2);
+        assert_eq!(state.pacman.keys, vec![1]);
+        assert_eq!(state.phase, GamePhase::Ready);
+
+        // A score set lower by hand counts as nothing scored, not a wrap
+        state.pacman.score = 100;
+        state.transition_to_next_maze();
+        let results = &state.campaign.as_ref().unwrap().results;
+        assert_eq!(results[1].score, 0);
+    }
+
+    #[test]
+    fn finishing_last_maze_ends_game_with_summary() {
+        let mut state =
+            GameStateInner::new_campaign(GameMode::Classic, vec![tiny_maze(), tiny_maze()]);
+        state.pacman.score = 300;
+        state.dots_remaining = 0;
+        state.transition_to_next_maze();
+        state.pacman.score = 450;
+
+        assert!(!state.transition_to_next_maze());
+        assert_eq!(state.phase, GamePhase::GameOver);
+
+        let summary = state.campaign_summary().unwrap();
+        assert_eq!(summary.mazes_played, 2);
+        assert_eq!(summary.mazes_cleared, 1);
+        assert_eq!(summary.final_score, 450);
+        assert_eq!(summary.results[0].score, 300);
+        assert_eq!(summary.results[1].score, 150);
+    }
+
+    #[test]
+    fn campaign_layouts_are_checked() {
+        let good = "WWWWWWW\nW@oFb.W\nWWW-WWW\nWWpicWW\nWWWWWWW";
+        let state =
+            GameStateInner::campaign_from_layouts(GameMode::Classic, &[good.to_string()]).unwrap();
+        assert_eq!((state.maze.width, state.dots_remaining), (7, 2));
+
+        // Ragged, then unplayable (no house), in second place
+        for bad in ["WWW\nW.\nWWW", "WWWWW\nW...W\nWWWWW"] {
+            let layouts = [good.to_string(), bad.to_string()];
+            let err = GameStateInner::campaign_from_layouts(GameMode::Classic, &layouts);
+            assert!(
+                err.unwrap_err().starts_with("campaign layout 1:"),
+                "{}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn non_campaign_game_has_no_summary() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        assert!(!state.transition_to_next_maze());
+        assert!(state.campaign_summary().is_none());
+    }
+}
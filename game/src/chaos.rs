@@ -0,0 +1,105 @@
+// game/src/chaos.rs
+//
+// Chaos: dozens of ghosts at once.
+//
+// A stress mode for fun (and for the update loop): up to
+// `MAX_CHAOS_GHOSTS` ghosts with personalities drawn at random from the
+// seed. The house can't hold that many in the arcade's order, so the dot
+// counters are dropped and a ghost leaves every `CHAOS_RELEASE_INTERVAL`
+// seconds instead, one after another.
+//
+// Chaos is a rule setting (`GameConfig::chaos`) on an ordinary Classic
+// game, so restarts and deaths bring back the same horde. The ghost arrays
+// in the snapshot simply get longer; `get_ghost_count()` tells the flat
+// per-frame arrays how many entries to expect.
+
+use crate::config::GameConfig;
+use crate::entities::{Ghost, GhostType, CLASSIC_ROSTER};
+use crate::maze::MazeMetadata;
+use crate::rng::Rng;
+use crate::state::{GameMode, GameStateInner};
+
+// ─── Chaos ──────────────────────────────────────────────────────────────────
+
+/// Most ghosts a chaos game can have.
+pub const MAX_CHAOS_GHOSTS: usize = 64;
+
+/// Seconds between ghosts leaving the house in chaos.
+pub const CHAOS_RELEASE_INTERVAL: f64 = 0.75;
+
+/// `count` random personalities, the same for the same seed. The first is
+/// always Blinky, so the horde has a leader outside the house.
+pub fn chaos_roster(count: usize, seed: u32) -> Vec<GhostType> {
+    let mut rng = Rng::new(seed);
+    std::iter::once(GhostType::Blinky)
+        .chain((1..count).map(|_| CLASSIC_ROSTER[rng.below(CLASSIC_ROSTER.len() as u32) as usize]))
+        .collect()
+}
+
+/// The ghosts `config` starts a board described by `meta` with.
+pub(crate) fn starting_ghosts(config: &GameConfig, meta: &MazeMetadata) -> Vec<Ghost> {
+    if config.chaos {
+        Ghost::horde(&config.ghost_roster, meta)
+    } else {
+        Ghost::roster(&config.roster(), meta)
+    }
+}
+
+impl GameStateInner {
+    /// Start a chaos game with `count` ghosts (2 to `MAX_CHAOS_GHOSTS`).
+    pub fn chaos(count: usize, seed: u32) -> Result<GameStateInner, String> {
+        if !(2..=MAX_CHAOS_GHOSTS).contains(&count) {
+            return Err(format!(
+                "chaos needs 2–{} ghosts, not {}",
+                MAX_CHAOS_GHOSTS, count
+            ));
+        }
+        let config = GameConfig {
+            chaos: true,
+            ghost_roster: chaos_roster(count, seed),
+            seed,
+            ..GameConfig::default()
+        };
+        Ok(GameStateInner::with_config(GameMode::Classic, config))
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::HouseState;
+    use crate::state::GamePhase;
+
+    #[test]
+    fn a_horde_files_out_of_the_house() {
+        let mut state = GameStateInner::chaos(40, 9).unwrap();
+        assert_eq!(state.ghosts.len(), 40);
+        assert_eq!(state.entity_positions().len(), 2 * 41);
+        let kinds = &state.config.ghost_roster;
+        assert!(kinds.iter().any(|&k| k != kinds[1]));
+
+        state.phase = GamePhase::Playing;
+        for _ in 0..180 {
+            state.tick(1.0 / 60.0);
+        }
+        let out = state
+            .ghosts
+            .iter()
+            .filter(|g| g.house != HouseState::InHouse)
+            .count();
+        // Blinky, plus one every CHAOS_RELEASE_INTERVAL for three seconds
+        assert_eq!(out, 1 + (3.0 / CHAOS_RELEASE_INTERVAL) as usize);
+
+        state.reset();
+        assert_eq!(state.ghosts.len(), 40);
+    }
+
+    #[test]
+    fn chaos_size_is_checked() {
+        assert!(GameStateInner::chaos(1, 0).is_err());
+        assert!(GameStateInner::chaos(MAX_CHAOS_GHOSTS + 1, 0).is_err());
+        assert_eq!(chaos_roster(10, 3), chaos_roster(10, 3));
+    }
+}
@@ -0,0 +1,325 @@
+// game/src/config.rs
+//
+// Optional gameplay settings that tweak the rules without changing
+// the game mode. Every field has a default, so a `GameConfig` can be
+// built from partial user settings.
+//
+// Configs round-trip through JSON strings so frontends can keep user
+// settings in localStorage and hand them to a new `GameState` later.
+
+use serde::{Deserialize, Serialize};
+
+use crate::assist::Assist;
+use crate::entities::{FruitKind, GhostType, CLASSIC_ROSTER, MAX_GHOSTS};
+use crate::layouts::MazePolicy;
+
+// ─── Stuck recovery ─────────────────────────────────────────────────────────
+
+/// What the watchdog does with an entity that stopped making progress.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum StuckRecovery {
+    /// Turn toward any open exit (teleports if there is none)
+    Repath,
+    /// Move to the nearest walkable tile
+    Teleport,
+}
+
+// ─── Collision mode ─────────────────────────────────────────────────────────
+
+/// How the game decides that Pac-Man and a ghost touch.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum CollisionMode {
+    /// Centers closer than half a tile
+    Distance,
+    /// Same tile, or swapping tiles with each other in one step (the
+    /// arcade rule)
+    Tile,
+}
+
+// ─── Fidelity preset ────────────────────────────────────────────────────────
+
+/// How closely the simulation follows the arcade rules, as one switch.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Fidelity {
+    /// A plainer model: fixed 7 s scatter / 20 s chase cycle, ghosts leave
+    /// the house one after another, level-1 speeds throughout, and no Cruise
+    /// Elroy, tunnel slowdown, or no-up zones.
+    Simplified,
+    /// The classic rules, with `overflow_bug` and `collision` as set.
+    Standard,
+    /// Everything the arcade does, quirks included: forces the overflow
+    /// bug and tile-based collisions on.
+    Arcade,
+}
+
+// ─── Scoring ────────────────────────────────────────────────────────────────
+
+/// Points for everything Pac-Man can eat. Defaults are the arcade's.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoringConfig {
+    pub dot: u32,
+    pub power_pellet: u32,
+    pub ghost: u32,
+    /// Per fruit, in level order (Cherry to Key, as `FruitKind::code`).
+    pub fruit: [u32; 8],
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        let fruit = [
+            FruitKind::Cherry,
+            FruitKind::Strawberry,
+            FruitKind::Orange,
+            FruitKind::Apple,
+            FruitKind::Melon,
+            FruitKind::Galaxian,
+            FruitKind::Bell,
+            FruitKind::Key,
+        ];
+        ScoringConfig {
+            dot: 10,
+            power_pellet: 50,
+            ghost: 200,
+            fruit: fruit.map(FruitKind::points),
+        }
+    }
+}
+
+impl ScoringConfig {
+    pub fn fruit_points(&self, kind: FruitKind) -> u32 {
+        self.fruit[kind.code() as usize]
+    }
+}
+
+// ─── Game config ────────────────────────────────────────────────────────────
+
+/// Tunable rules for a single game.
+///
+/// # Why `#[serde(default)]`?
+/// Frontends store only the settings the player touched. With
+/// `#[serde(default)]`, any missing field falls back to `Default`
+/// instead of failing deserialization. Unknown fields are ignored
+/// (serde's default), so settings saved by a newer build still load.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameConfig {
+    /// Urgency mode: time bonus available at the start of each level.
+    /// `0` disables urgency mode.
+    pub urgency_bonus: u32,
+    /// Points the urgency bonus loses per second of play.
+    pub urgency_decay_per_second: f64,
+    /// Seed for every random choice the simulation makes.
+    pub seed: u32,
+    /// Ask renderers to draw ghosts by `pattern`/`look` rather than color.
+    /// The simulation doesn't use it; it travels with the state so every
+    /// frontend honours the player's choice.
+    pub high_contrast: bool,
+    /// Seconds without changing tile before an AI entity counts as stuck.
+    /// `0` disables the watchdog.
+    pub stuck_timeout: f64,
+    pub stuck_recovery: StuckRecovery,
+    /// Lives at the start of each game, including the one in play.
+    pub starting_lives: u8,
+    /// Score that earns one bonus life. `0` disables it.
+    pub extra_life_score: u32,
+    pub scoring: ScoringConfig,
+    /// How far before (or past) a tile center Pac-Man may start a buffered
+    /// turn, in tiles. He then cuts the corner diagonally. `0` only turns
+    /// at the center.
+    pub cornering_window: f64,
+    /// Seconds a buffered turn stays valid. A turn pressed just before an
+    /// opening still applies there; one older than this is dropped rather
+    /// than firing at some junction much later. `0` keeps turns until taken.
+    pub input_window: f64,
+    /// Reproduce the arcade's targeting overflow: while Pac-Man faces up,
+    /// Pinky's target and Inky's pivot also shift left (4 and 2 tiles).
+    pub overflow_bug: bool,
+    pub collision: CollisionMode,
+    /// Preset that switches the classic behaviors on or off together.
+    pub fidelity: Fidelity,
+    /// How many ghosts play (1–8): Blinky, Pinky, Inky and Clyde, then the
+    /// same again. Ignored when `ghost_roster` is set.
+    pub ghost_count: u8,
+    /// Personalities of the ghosts in play, in roster order (up to 8,
+    /// repeats allowed). Empty uses `ghost_count`.
+    pub ghost_roster: Vec<GhostType>,
+    /// Chaos: `ghost_roster` may hold dozens of ghosts, released from the
+    /// house on a timer (see `chaos.rs`).
+    pub chaos: bool,
+    /// Dark maze: Pac-Man sees this many tiles around him, plus down the
+    /// corridors he's in (see `fog.rs`). `0` lights the whole maze.
+    pub fog_radius: u8,
+    /// Which maze each level is played on (see `layouts.rs`).
+    pub maze_policy: MazePolicy,
+    /// Mirror every board the game loads left to right, spawn points and
+    /// all.
+    pub mirror: bool,
+    /// Turn every board the game loads upside down, spawn points and all.
+    pub flip: bool,
+    /// Multiplier on Pac-Man's speed from the level's speed table.
+    pub pacman_speed: f64,
+    /// Multiplier on every ghost speed from the level's speed table.
+    pub ghost_speed: f64,
+    /// Accessibility assists (see `assist.rs`).
+    pub assist: Assist,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig {
+            urgency_bonus: 0,
+            urgency_decay_per_second: 50.0,
+            seed: 0,
+            high_contrast: false,
+            stuck_timeout: 5.0,
+            stuck_recovery: StuckRecovery::Repath,
+            starting_lives: 3,
+            extra_life_score: 10_000,
+            scoring: ScoringConfig::default(),
+            cornering_window: 0.3,
+            input_window: 0.5,
+            overflow_bug: false,
+            collision: CollisionMode::Distance,
+            fidelity: Fidelity::Standard,
+            ghost_count: 4,
+            ghost_roster: Vec::new(),
+            chaos: false,
+            fog_radius: 0,
+            maze_policy: MazePolicy::Classic,
+            mirror: false,
+            flip: false,
+            pacman_speed: 1.0,
+            ghost_speed: 1.0,
+            assist: Assist::default(),
+        }
+    }
+}
+
+impl GameConfig {
+    /// Whether the classic rules (wave schedule, house dot counters, Cruise
+    /// Elroy, tunnel slowdown, no-up zones, per-level speeds) apply.
+    pub fn classic_rules(&self) -> bool {
+        self.fidelity != Fidelity::Simplified
+    }
+
+    /// `overflow_bug`, unless the preset decides.
+    pub fn uses_overflow_bug(&self) -> bool {
+        match self.fidelity {
+            Fidelity::Simplified => false,
+            Fidelity::Standard => self.overflow_bug,
+            Fidelity::Arcade => true,
+        }
+    }
+
+    /// The personalities that play, in roster order.
+    pub fn roster(&self) -> Vec<GhostType> {
+        if !self.ghost_roster.is_empty() {
+            return self.ghost_roster.clone();
+        }
+        let count = (self.ghost_count as usize).clamp(1, MAX_GHOSTS);
+        CLASSIC_ROSTER.iter().copied().cycle().take(count).collect()
+    }
+
+    /// `collision`, unless the preset decides.
+    pub fn collision_mode(&self) -> CollisionMode {
+        match self.fidelity {
+            Fidelity::Arcade => CollisionMode::Tile,
+            _ => self.collision,
+        }
+    }
+}
+
+// ─── JSON persistence ───────────────────────────────────────────────────────
+
+/// Serialize a config to a JSON string for storage.
+pub fn config_to_string(config: &GameConfig) -> String {
+    // A struct of plain numbers always serializes; no error path to surface.
+    serde_json::to_string(config).expect("GameConfig is always serializable")
+}
+
+/// Parse a config from JSON. Missing fields take their defaults and
+/// unknown fields are ignored; only malformed JSON is an error.
+pub fn config_from_string(json: &str) -> Result<GameConfig, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fidelity_preset_overrides_the_quirk_flags() {
+        let arcade = config_from_string(r#"{"fidelity":"Arcade"}"#).unwrap();
+        assert!(arcade.classic_rules() && arcade.uses_overflow_bug());
+        assert_eq!(arcade.collision_mode(), CollisionMode::Tile);
+
+        let simple = GameConfig {
+            fidelity: Fidelity::Simplified,
+            overflow_bug: true,
+            ..GameConfig::default()
+        };
+        assert!(!simple.classic_rules() && !simple.uses_overflow_bug());
+    }
+
+    #[test]
+    fn config_round_trips_through_json() {
+        let config = GameConfig {
+            urgency_bonus: 3000,
+            urgency_decay_per_second: 25.0,
+            seed: 99,
+            high_contrast: true,
+            stuck_timeout: 2.5,
+            stuck_recovery: StuckRecovery::Teleport,
+            starting_lives: 5,
+            extra_life_score: 20_000,
+            scoring: ScoringConfig {
+                dot: 20,
+                ..ScoringConfig::default()
+            },
+            cornering_window: 0.0,
+            input_window: 0.0,
+            overflow_bug: true,
+            collision: CollisionMode::Tile,
+            fidelity: Fidelity::Arcade,
+            ghost_count: 2,
+            ghost_roster: vec![GhostType::Clyde, GhostType::Clyde],
+            chaos: false,
+            fog_radius: 4,
+            maze_policy: MazePolicy::Random,
+            mirror: true,
+            flip: false,
+            pacman_speed: 1.25,
+            ghost_speed: 0.5,
+            assist: Assist {
+                invincible: true,
+                ..Assist::default()
+            },
+        };
+        let restored = config_from_string(&config_to_string(&config)).unwrap();
+        assert_eq!(restored, config);
+    }
+
+    #[test]
+    fn missing_fields_take_defaults() {
+        let config = config_from_string(r#"{ "urgency_bonus": 500 }"#).unwrap();
+        assert_eq!(config.urgency_bonus, 500);
+        assert_eq!(
+            config.urgency_decay_per_second,
+            GameConfig::default().urgency_decay_per_second
+        );
+    }
+
+    #[test]
+    fn unknown_fields_are_ignored() {
+        let config = config_from_string(r#"{ "from_the_future": true }"#).unwrap();
+        assert_eq!(config, GameConfig::default());
+    }
+
+    #[test]
+    fn malformed_json_is_an_error() {
+        assert!(config_from_string("{ not json").is_err());
+    }
+}
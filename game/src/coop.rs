@@ -0,0 +1,166 @@
+// game/src/coop.rs
+//
+// Local co-op: two Pac-Men in the same maze against the ghosts.
+//
+// Player two lives in `GameStateInner::pacman2` (`None` outside Co-op) and
+// has his own lives and score. Rather than teach every movement and
+// collision routine about a second Pac-Man, a step runs them once as usual
+// and once more with player two swapped into the `pacman` slot
+// (`with_player_two`), so both follow exactly the same rules.
+//
+// - Ghosts chase whichever Pac-Man is nearer.
+// - Either player eating the last dot clears the level.
+// - A caught player costs the pair a round: both restart from their spawns.
+//   A player with no lives left sits the rest of the game out, and the game
+//   is over when both are out.
+// - Switches answer to player one, and only player one earns the bonus
+//   life and drives `ScoreChanged`.
+
+use crate::entities::{Direction, PacMan, Position};
+use crate::state::{GameMode, GameStateInner};
+
+// ─── Players ────────────────────────────────────────────────────────────────
+
+/// Player two at the shared spawn tile with `lives`, facing the other way
+/// from player one.
+pub fn player_two(lives: u8) -> PacMan {
+    let mut pac = PacMan::new();
+    pac.lives = lives;
+    face_right(&mut pac);
+    pac
+}
+
+fn face_right(pac: &mut PacMan) {
+    pac.direction = Direction::Right;
+    pac.next_direction = Direction::Right;
+}
+
+/// The chase target in `targets` closest to `from`.
+pub(crate) fn nearest<'a>(
+    targets: &'a [(Position, Direction)],
+    from: &Position,
+) -> &'a (Position, Direction) {
+    let dist = |(p, _): &&(Position, Direction)| (p.x - from.x).powi(2) + (p.y - from.y).powi(2);
+    targets
+        .iter()
+        .min_by(|a, b| dist(a).total_cmp(&dist(b)))
+        .expect("at least one chase target")
+}
+
+impl GameStateInner {
+    /// Whether player one is still playing (always, outside Co-op).
+    pub(crate) fn player_one_in_play(&self) -> bool {
+        self.mode != GameMode::Coop || self.pacman.lives > 0
+    }
+
+    /// Whether anyone has a life left to continue with.
+    pub(crate) fn players_left(&self) -> bool {
+        self.pacman.lives > 0 || self.pacman2.as_ref().is_some_and(|p| p.lives > 0)
+    }
+
+    /// Run `f` with player two in the `pacman` slot, if he's still playing.
+    pub(crate) fn with_player_two(&mut self, f: impl FnOnce(&mut Self)) {
+        let Some(mut p2) = self.pacman2.take() else {
+            return;
+        };
+        if p2.lives > 0 {
+            std::mem::swap(&mut self.pacman, &mut p2);
+            f(self);
+            std::mem::swap(&mut self.pacman, &mut p2);
+        }
+        self.pacman2 = Some(p2);
+    }
+
+    /// Put every Pac-Man back on the spawn tile.
+    pub(crate) fn respawn_players(&mut self) {
+        let spawn = self.maze.metadata.pacman_spawn;
+        self.pacman.respawn(spawn);
+        if let Some(p2) = &mut self.pacman2 {
+            p2.respawn(spawn);
+            face_right(p2);
+        }
+    }
+
+    /// Position and heading of each Pac-Man still playing, for the ghosts
+    /// to chase (player one if nobody is).
+    pub(crate) fn chase_targets(&self) -> Vec<(Position, Direction)> {
+        let p2 = self.pacman2.iter().filter(|p| p.lives > 0);
+        let mut targets: Vec<_> = std::iter::once(&self.pacman)
+            .filter(|_| self.player_one_in_play())
+            .chain(p2)
+            .map(|p| (p.position.clone(), p.direction))
+            .collect();
+        if targets.is_empty() {
+            targets.push((self.pacman.position.clone(), self.pacman.direction));
+        }
+        targets
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::GhostMode;
+    use crate::events::GameEvent;
+    use crate::state::GamePhase;
+
+    fn coop() -> GameStateInner {
+        let mut state = GameStateInner::new(GameMode::Coop);
+        state.phase = GamePhase::Playing;
+        state.last_phase = GamePhase::Playing;
+        state
+    }
+
+    #[test]
+    fn both_players_move_and_score_on_their_own() {
+        let mut state = coop();
+        state.set_player2_direction(Direction::Right);
+        for _ in 0..30 {
+            state.tick(1.0 / 60.0);
+        }
+        let p2 = state.pacman2.as_ref().unwrap();
+        assert!(state.pacman.position.x < 14.0);
+        assert!(p2.position.x > 14.0);
+        assert!(state.pacman.score > 0 && p2.score > 0);
+        assert_eq!(
+            state.maze.dots_remaining(),
+            state.dots_remaining,
+            "both players' dots come off the board"
+        );
+    }
+
+    #[test]
+    fn ghosts_chase_the_nearer_player_and_catching_either_costs_a_life() {
+        let mut state = coop();
+        let p2 = state.pacman2.as_mut().unwrap();
+        p2.position = Position::new(6.0, 5.0);
+        let targets = state.chase_targets();
+        assert_eq!(nearest(&targets, &Position::new(5.0, 5.0)).0.x, 6.0);
+
+        state.ghosts[0].position = Position::new(6.0, 5.0);
+        state.ghosts[0].mode = GhostMode::Chase;
+        state.tick(1.0 / 60.0);
+        assert_eq!(state.phase, GamePhase::Dying);
+        assert_eq!(state.pacman2.as_ref().unwrap().lives, 2);
+        assert_eq!(state.pacman.lives, 3);
+
+        // Out of lives: player two sits out, the game goes on
+        state.pacman2.as_mut().unwrap().lives = 0;
+        state.phase_timer = 0.01;
+        state.tick(0.02);
+        assert_eq!(state.phase, GamePhase::Respawning);
+        assert_eq!(state.chase_targets().len(), 1);
+
+        state.pacman.lives = 0;
+        state.phase = GamePhase::Dying;
+        state.phase_timer = 0.01;
+        state.tick(0.02);
+        assert_eq!(state.phase, GamePhase::GameOver);
+        assert!(!state
+            .events
+            .iter()
+            .any(|e| matches!(e, GameEvent::LevelComplete { .. })));
+    }
+}
@@ -0,0 +1,168 @@
+// game/src/daily.rs
+//
+// Daily challenge: one seed, one game, for everybody.
+//
+// `GameState.daily(seed)` builds a game entirely from `seed` (typically
+// the date, e.g. `20261016`), so every player who starts it gets the same
+// board and the same luck, and scores can be compared:
+//
+// - the maze variant (`MazeVariant`),
+// - the order the bonus fruits come in,
+// - the RNG behind frightened ghosts' turns (`GameConfig::seed`).
+//
+// The game is otherwise Classic, and `restart()` replays the same
+// challenge.
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::GameConfig;
+use crate::entities::FruitKind;
+use crate::maze::{CellType, Maze};
+use crate::rng::Rng;
+use crate::state::{GameMode, GameStateInner};
+
+// ─── Maze variants ──────────────────────────────────────────────────────────
+
+/// Small changes to the classic board a challenge may come with.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum MazeVariant {
+    Classic,
+    /// The power pellets sit in the outer corridors instead of the corners.
+    OuterPellets,
+    /// The side tunnels are lined with dots.
+    TunnelDots,
+}
+
+impl MazeVariant {
+    const ALL: [MazeVariant; 3] = [
+        MazeVariant::Classic,
+        MazeVariant::OuterPellets,
+        MazeVariant::TunnelDots,
+    ];
+
+    /// The classic maze with this variant's changes.
+    pub fn maze(self) -> Maze {
+        let mut maze = Maze::new();
+        let edits: Vec<(usize, usize, CellType)> = match self {
+            MazeVariant::Classic => Vec::new(),
+            MazeVariant::OuterPellets => [(3, 1), (3, 26), (23, 1), (23, 26)]
+                .map(|(row, col)| (row, col, CellType::Dot))
+                .into_iter()
+                .chain(
+                    [(5, 1), (5, 26), (29, 1), (29, 26)]
+                        .map(|(row, col)| (row, col, CellType::PowerPellet)),
+                )
+                .collect(),
+            MazeVariant::TunnelDots => (1..=5)
+                .chain(22..=26)
+                .map(|col| (14, col, CellType::Dot))
+                .collect(),
+        };
+        for (row, col, cell) in edits {
+            maze.set_cell(row, col, cell);
+        }
+        maze
+    }
+}
+
+// ─── Daily challenge ────────────────────────────────────────────────────────
+
+/// What a challenge's seed decided.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Daily {
+    pub seed: u32,
+    pub variant: MazeVariant,
+    /// The fruit shown in place of each arcade fruit, by `FruitKind::code`.
+    pub fruit_order: [FruitKind; 8],
+}
+
+impl Daily {
+    pub fn new(seed: u32) -> Daily {
+        let mut rng = Rng::new(seed);
+        let variant = MazeVariant::ALL[rng.below(MazeVariant::ALL.len() as u32) as usize];
+        let mut fruit_order = [
+            FruitKind::Cherry,
+            FruitKind::Strawberry,
+            FruitKind::Orange,
+            FruitKind::Apple,
+            FruitKind::Melon,
+            FruitKind::Galaxian,
+            FruitKind::Bell,
+            FruitKind::Key,
+        ];
+        // Fisher–Yates
+        for i in (1..fruit_order.len()).rev() {
+            let j = rng.below(i as u32 + 1) as usize;
+            fruit_order.swap(i, j);
+        }
+        Daily {
+            seed,
+            variant,
+            fruit_order,
+        }
+    }
+}
+
+impl GameStateInner {
+    /// Start the challenge for `seed`.
+    pub fn daily(seed: u32) -> GameStateInner {
+        let daily = Daily::new(seed);
+        let config = GameConfig {
+            seed,
+            ..GameConfig::default()
+        };
+        let mut state = GameStateInner::with_config(GameMode::Classic, config);
+        state.load_maze(daily.variant.maze());
+        state.daily = Some(daily);
+        state
+    }
+
+    /// The bonus fruit for `level`, in the challenge's order if this is one.
+    pub fn fruit_for_level(&self, level: u32) -> FruitKind {
+        let kind = FruitKind::for_level(level);
+        match &self.daily {
+            Some(daily) => daily.fruit_order[kind.code() as usize],
+            None => kind,
+        }
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_challenge() {
+        let a = GameStateInner::daily(20261016);
+        let b = GameStateInner::daily(20261016);
+        assert_eq!(a.daily, b.daily);
+        assert_eq!(a.maze.cells, b.maze.cells);
+        assert_eq!(a.rng, b.rng);
+        assert_eq!(a.fruit_for_level(3), b.fruit_for_level(3));
+
+        // Different days differ somewhere
+        let days: Vec<_> = (1..=10).map(Daily::new).collect();
+        assert!(days.iter().any(|d| d.variant != days[0].variant));
+        assert!(days.iter().any(|d| d.fruit_order != days[0].fruit_order));
+    }
+
+    #[test]
+    fn variants_keep_the_board_consistent() {
+        for variant in MazeVariant::ALL {
+            let maze = variant.maze();
+            assert_eq!(maze.positions_of(CellType::PowerPellet).len(), 4);
+        }
+        let tunnel = MazeVariant::TunnelDots.maze();
+        assert_eq!(tunnel.dots_remaining(), Maze::new().dots_remaining() + 10);
+
+        let mut state = GameStateInner::daily(7);
+        state.pacman.score = 500;
+        state.reset();
+        assert_eq!(
+            state.maze.cells,
+            state.daily.as_ref().unwrap().variant.maze().cells
+        );
+    }
+}
@@ -0,0 +1,273 @@
+// game/src/debug.rs
+//
+// Debug console commands (feature `debug`).
+//
+// An in-browser console, or an integration test, often needs the game in
+// one particular situation right now: Pac-Man over there, Clyde held still,
+// Inky frightened, one life left, the top-left corner already eaten. These
+// commands change the running game directly. They skip the rules on
+// purpose, so they are kept out of release builds.
+//
+// Names from JS are matched case-insensitively, as with game modes. A
+// ghost can also be named by its roster index ("0", "1", ...), the only
+// way to reach the second of two ghosts with the same personality.
+
+use crate::entities::{EntityId, GhostLook, GhostMode, GhostPick, GhostType, HouseState, Position};
+use crate::levels::FrightSchedule;
+use crate::maze::CellType;
+use crate::state::GameStateInner;
+
+// ─── Names ──────────────────────────────────────────────────────────────────
+
+/// What `debug_teleport` moves.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DebugEntity {
+    PacMan,
+    Ghost(GhostPick),
+}
+
+impl From<EntityId> for DebugEntity {
+    fn from(entity: EntityId) -> Self {
+        match entity {
+            EntityId::PacMan => DebugEntity::PacMan,
+            EntityId::Ghost(ghost) => DebugEntity::Ghost(ghost.into()),
+        }
+    }
+}
+
+/// Parse a ghost name (`"blinky"`, `"Pinky"`, ...) or roster index.
+pub fn parse_ghost(name: &str) -> Result<GhostPick, String> {
+    if let Ok(index) = name.parse() {
+        return Ok(GhostPick::Index(index));
+    }
+    match name.to_lowercase().as_str() {
+        "blinky" => Ok(GhostType::Blinky.into()),
+        "pinky" => Ok(GhostType::Pinky.into()),
+        "inky" => Ok(GhostType::Inky.into()),
+        "clyde" => Ok(GhostType::Clyde.into()),
+        _ => Err(format!("Unknown ghost: '{}'", name)),
+    }
+}
+
+/// Parse `"pacman"` or a ghost name or index.
+pub fn parse_entity(name: &str) -> Result<DebugEntity, String> {
+    if name.eq_ignore_ascii_case("pacman") {
+        return Ok(DebugEntity::PacMan);
+    }
+    parse_ghost(name).map(DebugEntity::Ghost)
+}
+
+/// Parse a ghost mode (`"chase"`, `"scatter"`, `"frightened"`, `"eaten"`),
+/// or `"auto"` for none.
+pub fn parse_ghost_mode(name: &str) -> Result<Option<GhostMode>, String> {
+    match name.to_lowercase().as_str() {
+        "chase" => Ok(Some(GhostMode::Chase)),
+        "scatter" => Ok(Some(GhostMode::Scatter)),
+        "frightened" => Ok(Some(GhostMode::Frightened)),
+        "eaten" => Ok(Some(GhostMode::Eaten)),
+        "auto" => Ok(None),
+        _ => Err(format!("Unknown ghost mode: '{}'", name)),
+    }
+}
+
+// ─── Commands ───────────────────────────────────────────────────────────────
+
+impl GameStateInner {
+    fn debug_ghost_index(&self, ghost: GhostPick) -> Result<usize, String> {
+        ghost.find(&self.ghosts).ok_or_else(|| match ghost {
+            GhostPick::Type(ghost) => format!("{:?} is not in this game", ghost),
+            GhostPick::Index(i) => format!(
+                "there's no ghost {} (this game has {})",
+                i,
+                self.ghosts.len()
+            ),
+        })
+    }
+
+    /// Move an entity to tile (col, row). A ghost moved out of the house
+    /// is let out.
+    pub fn debug_teleport(
+        &mut self,
+        entity: impl Into<DebugEntity>,
+        col: usize,
+        row: usize,
+    ) -> Result<(), String> {
+        // `is_walkable` counts columns past the edge as tunnel
+        if col >= self.maze.width || row >= self.maze.height {
+            return Err(format!("({}, {}) is off the board", col, row));
+        }
+        if !self.maze.is_walkable(col as f64, row as f64) {
+            return Err(format!("({}, {}) is not walkable", col, row));
+        }
+        let position = Position::new(col as f64, row as f64);
+        match entity.into() {
+            DebugEntity::PacMan => self.pacman.position = position,
+            DebugEntity::Ghost(ghost) => {
+                let i = self.debug_ghost_index(ghost)?;
+                self.ghosts[i].position = position;
+                self.ghosts[i].house = HouseState::Outside;
+            }
+        }
+        Ok(())
+    }
+
+    /// Hold a ghost in place (or let it go again).
+    pub fn debug_freeze(
+        &mut self,
+        ghost: impl Into<GhostPick>,
+        frozen: bool,
+    ) -> Result<(), String> {
+        let i = self.debug_ghost_index(ghost.into())?;
+        self.ghosts[i].frozen = frozen;
+        Ok(())
+    }
+
+    /// Put a ghost in `mode`. Chase and Scatter stay until released with
+    /// `None`, whatever the wave schedule says; Frightened and Eaten play
+    /// out as usual, then the ghost returns to its pinned mode, if any.
+    /// Frightening a ghost while no power pellet is running starts the
+    /// level's fright time, so it wears off; on levels with none it's
+    /// refused.
+    pub fn debug_force_mode(
+        &mut self,
+        ghost: impl Into<GhostPick>,
+        mode: Option<GhostMode>,
+    ) -> Result<(), String> {
+        let i = self.debug_ghost_index(ghost.into())?;
+        if mode == Some(GhostMode::Frightened) && self.frightened_timer <= 0.0 {
+            let seconds = FrightSchedule::for_level(self.level).seconds;
+            if seconds <= 0.0 {
+                return Err(format!(
+                    "ghosts can't be frightened on level {}",
+                    self.level
+                ));
+            }
+            self.frightened_timer = seconds;
+        }
+        let ghost = &mut self.ghosts[i];
+        match mode {
+            Some(mode) => {
+                ghost.mode = mode;
+                ghost.look = GhostLook::for_mode(mode);
+                if matches!(mode, GhostMode::Chase | GhostMode::Scatter) {
+                    ghost.pinned_mode = Some(mode);
+                }
+            }
+            None => ghost.pinned_mode = None,
+        }
+        Ok(())
+    }
+
+    pub fn debug_set_score(&mut self, score: u32) {
+        self.pacman.score = score;
+    }
+
+    pub fn debug_set_lives(&mut self, lives: u8) {
+        self.pacman.lives = lives;
+    }
+
+    /// Eat every dot and power pellet in the `rows` × `cols` block whose
+    /// top-left tile is (row, col), without scoring them. Returns how many
+    /// were removed; clearing the last one completes the level on the next
+    /// tick of play.
+    pub fn debug_clear_region(
+        &mut self,
+        row: usize,
+        col: usize,
+        rows: usize,
+        cols: usize,
+    ) -> usize {
+        let mut cleared = 0;
+        for r in row..row.saturating_add(rows).min(self.maze.height) {
+            for c in col..col.saturating_add(cols).min(self.maze.width) {
+                if matches!(
+                    self.maze.get_cell(r, c),
+                    Some(CellType::Dot | CellType::PowerPellet)
+                ) {
+                    self.set_cell(r, c, CellType::Empty);
+                    cleared += 1;
+                }
+            }
+        }
+        self.dots_remaining -= cleared;
+        cleared
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{GameMode, GamePhase};
+
+    #[test]
+    fn frozen_ghost_stays_put_after_a_teleport() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        let clyde = parse_entity("CLYDE").unwrap();
+        state.debug_teleport(clyde, 6, 5).unwrap();
+        state.debug_freeze(GhostType::Clyde, true).unwrap();
+        state
+            .debug_force_mode(GhostType::Clyde, parse_ghost_mode("chase").unwrap())
+            .unwrap();
+        for _ in 0..30 {
+            state.tick(1.0 / 60.0);
+        }
+        let pos = &state.ghosts[3].position;
+        assert_eq!((pos.x, pos.y), (6.0, 5.0));
+        assert_eq!(state.ghosts[3].mode, GhostMode::Chase);
+
+        assert!(state.debug_teleport(EntityId::PacMan, 0, 0).is_err());
+        assert!(state.debug_teleport(EntityId::PacMan, 500, 5).is_err());
+        assert!(parse_entity("sue").is_err());
+    }
+
+    #[test]
+    fn repeated_personalities_are_reached_by_index() {
+        let config = crate::config::GameConfig {
+            ghost_roster: vec![GhostType::Clyde, GhostType::Clyde],
+            ..Default::default()
+        };
+        let mut state = GameStateInner::with_config(GameMode::Classic, config);
+        let second = parse_entity("1").unwrap();
+        state.debug_teleport(second, 6, 5).unwrap();
+        state.debug_freeze(parse_ghost("1").unwrap(), true).unwrap();
+        assert_eq!(state.ghosts[1].position.to_grid(), (6, 5));
+        assert!(state.ghosts[1].frozen && !state.ghosts[0].frozen);
+        assert!(state.debug_freeze(GhostPick::Index(2), true).is_err());
+    }
+
+    #[test]
+    fn forced_fright_wears_off() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        state
+            .debug_force_mode(GhostType::Blinky, Some(GhostMode::Frightened))
+            .unwrap();
+        assert!(state.frightened_remaining_ms() > 0.0);
+        for _ in 0..7 {
+            state.tick(1.0);
+        }
+        assert!(!state.is_any_ghost_frightened());
+
+        state.level = 21;
+        let forced = state.debug_force_mode(GhostType::Blinky, Some(GhostMode::Frightened));
+        assert!(forced.is_err());
+    }
+
+    #[test]
+    fn clearing_a_region_eats_its_dots() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        let before = state.dots_remaining;
+        let cleared = state.debug_clear_region(0, 0, 10, 10);
+        assert!(cleared > 0);
+        assert_eq!(state.dots_remaining, before - cleared);
+        assert_eq!(state.maze.dots_remaining(), state.dots_remaining);
+        assert_eq!(state.pacman.score, 0);
+
+        let all = state.debug_clear_region(0, 0, usize::MAX, usize::MAX);
+        assert_eq!(all, before - cleared);
+        assert_eq!(state.dots_remaining, 0);
+    }
+}
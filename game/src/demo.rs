@@ -0,0 +1,111 @@
+// game/src/demo.rs
+//
+// Attract (demo) mode.
+//
+// When the player sits on the READY screen, the frontend can call
+// `start_demo()` to show Pac-Man playing by himself. The demo plays on a
+// copy of the game: the real state is stashed away first and put back
+// untouched when the demo ends, so nothing that happens in the demo (lost
+// lives, eaten dots, score) leaks into the real game.
+//
+// The demo ends on any input, after `DEMO_DURATION`, or as soon as the demo
+// game leaves `Demo` (Pac-Man caught or the board cleared).
+//
+// Pac-Man is driven by the autopilot in `ai.rs`.
+
+use crate::state::{GamePhase, GameStateInner};
+
+// ─── Demo lifecycle ─────────────────────────────────────────────────────────
+
+/// Longest a demo runs before returning to the READY screen, in seconds.
+pub const DEMO_DURATION: f64 = 30.0;
+
+impl GameStateInner {
+    /// Start an autopilot demo. Only possible on the idle READY screen
+    /// (before the countdown); returns whether the demo started.
+    pub fn start_demo(&mut self) -> bool {
+        if self.phase != GamePhase::Ready || self.phase_timer > 0.0 || self.demo_backup.is_some() {
+            return false;
+        }
+        self.demo_backup = Some(Box::new(self.clone()));
+        self.phase = GamePhase::Demo;
+        self.phase_timer = DEMO_DURATION;
+        true
+    }
+
+    /// End the demo and restore the game exactly as it was before it.
+    /// Does nothing when no demo is running.
+    pub fn stop_demo(&mut self) {
+        if let Some(real) = self.demo_backup.take() {
+            let events = std::mem::take(&mut self.events);
+            let pending = std::mem::take(&mut self.pending_events);
+            let time_scale = self.time_scale;
+            *self = *real;
+            self.events = events;
+            // The queue and playback speed belong to JS, not to either game
+            self.pending_events = pending;
+            self.time_scale = time_scale;
+            // Report the way back to the READY screen as a phase change
+            self.last_phase = GamePhase::Demo;
+        }
+    }
+
+    /// Count down the demo and end it once it's over (called every tick).
+    pub(crate) fn update_demo(&mut self, dt: f64) {
+        if self.demo_backup.is_none() {
+            return;
+        }
+        if self.phase == GamePhase::Demo {
+            self.phase_timer -= dt;
+        }
+        if self.phase != GamePhase::Demo || self.phase_timer <= 0.0 {
+            self.stop_demo();
+        }
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{Direction, GhostMode};
+    use crate::events::GameEvent;
+    use crate::state::GameMode;
+
+    #[test]
+    fn demo_plays_and_restores_the_real_game() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        let dots = state.dots_remaining;
+        assert!(state.start_demo());
+        assert_eq!(state.phase, GamePhase::Demo);
+
+        for _ in 0..60 {
+            state.tick(1.0 / 60.0);
+        }
+        assert!(state.pacman.score > 0);
+
+        // Any input returns to the untouched READY screen
+        state.set_direction(Direction::Left);
+        state.tick(1.0 / 60.0);
+        assert_eq!(state.phase, GamePhase::Ready);
+        assert_eq!(state.pacman.score, 0);
+        assert_eq!(state.dots_remaining, dots);
+        assert!(state.events.contains(&GameEvent::PhaseChanged {
+            from: GamePhase::Demo,
+            to: GamePhase::Ready,
+        }));
+    }
+
+    #[test]
+    fn demo_ends_when_pacman_is_caught() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.start_demo();
+        state.ghosts[0].position = state.pacman.position.clone();
+        state.ghosts[0].mode = GhostMode::Chase;
+        state.tick(1.0 / 60.0);
+
+        assert_eq!(state.phase, GamePhase::Ready);
+        assert_eq!(state.pacman.lives, 3);
+    }
+}
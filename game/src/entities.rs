@@ -6,6 +6,10 @@
 // Movement logic and AI will be added in later phases.
 
 use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::chaos::MAX_CHAOS_GHOSTS;
+use crate::maze::MazeMetadata;
 
 // ─── Direction ──────────────────────────────────────────────────────────────
 
@@ -16,12 +20,16 @@ use serde::{Deserialize, Serialize};
 /// means it's passed by value automatically — no need for `clone()`.
 /// When you write `let d = some_entity.direction;`, Rust copies the byte
 /// instead of moving ownership. This is only safe for small, simple types.
+///
+/// Exported to JS as a numeric enum (`Direction.Up === 0`), matching
+/// `Direction::code`.
+#[wasm_bindgen]
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
+    Up = 0,
+    Down = 1,
+    Left = 2,
+    Right = 3,
 }
 
 impl Direction {
@@ -35,6 +43,28 @@ impl Direction {
         }
     }
 
+    /// The dominant cardinal direction of a swipe or stick vector in screen
+    /// coordinates (+y is down). `None` inside the deadzone: vectors
+    /// shorter than `deadzone`, e.g. a resting analog stick. An exact
+    /// diagonal resolves to the horizontal direction.
+    pub fn from_vector(dx: f64, dy: f64, deadzone: f64) -> Option<Direction> {
+        let length = dx.hypot(dy);
+        if length.is_nan() || length == 0.0 || length < deadzone {
+            return None;
+        }
+        Some(if dx.abs() >= dy.abs() {
+            if dx < 0.0 {
+                Direction::Left
+            } else {
+                Direction::Right
+            }
+        } else if dy < 0.0 {
+            Direction::Up
+        } else {
+            Direction::Down
+        })
+    }
+
     /// Returns the opposite direction.
     pub fn opposite(self) -> Direction {
         match self {
@@ -44,6 +74,27 @@ impl Direction {
             Direction::Right => Direction::Left,
         }
     }
+
+    /// Stable numeric code for flat JS arrays: Up 0, Down 1, Left 2, Right 3.
+    pub fn code(self) -> u8 {
+        match self {
+            Direction::Up => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Right => 3,
+        }
+    }
+
+    /// The direction with the given `code()`, if any.
+    pub fn from_code(code: u8) -> Option<Direction> {
+        match code {
+            0 => Some(Direction::Up),
+            1 => Some(Direction::Down),
+            2 => Some(Direction::Left),
+            3 => Some(Direction::Right),
+            _ => None,
+        }
+    }
 }
 
 // ─── Position ───────────────────────────────────────────────────────────────
@@ -76,6 +127,72 @@ impl Position {
     }
 }
 
+// ─── Turns ──────────────────────────────────────────────────────────────────
+
+/// Most turns a player can have waiting at once; pressing another drops
+/// the oldest.
+pub const TURN_QUEUE: usize = 3;
+
+/// A steered entity's waiting turns: `next` is tried first and has waited
+/// `age` seconds, then come the `queue`d ones, oldest first. `next` equal
+/// to `current` means nothing is waiting.
+pub(crate) struct Turns<'a> {
+    current: Direction,
+    next: &'a mut Direction,
+    age: &'a mut f64,
+    queue: &'a mut Vec<(Direction, f64)>,
+}
+
+impl Turns<'_> {
+    /// Wait for `direction` after the turns already waiting. Pressing the
+    /// newest waiting turn again just restarts its age.
+    pub fn push(self, direction: Direction) {
+        if *self.next == self.current {
+            *self.next = direction;
+            *self.age = 0.0;
+            self.queue.clear();
+            return;
+        }
+        match self.queue.last_mut() {
+            Some(newest) if newest.0 == direction => newest.1 = 0.0,
+            None if *self.next == direction => *self.age = 0.0,
+            _ => self.queue.push((direction, 0.0)),
+        }
+        if self.queue.len() >= TURN_QUEUE {
+            let (direction, age) = self.queue.remove(0);
+            *self.next = direction;
+            *self.age = age;
+        }
+    }
+
+    /// Age every waiting turn by `dt`, drop those older than `window`
+    /// (never, if it's 0), and move up the next turn once `next` has been
+    /// taken or dropped.
+    pub fn age(self, dt: f64, window: f64) {
+        if *self.next != self.current {
+            *self.age += dt;
+        }
+        for turn in self.queue.iter_mut() {
+            turn.1 += dt;
+        }
+        if window > 0.0 {
+            self.queue.retain(|&(_, age)| age <= window);
+            if *self.age > window {
+                *self.next = self.current;
+            }
+        }
+        while *self.next == self.current {
+            if self.queue.is_empty() {
+                *self.age = 0.0;
+                break;
+            }
+            let (direction, age) = self.queue.remove(0);
+            *self.next = direction;
+            *self.age = age;
+        }
+    }
+}
+
 // ─── Pac-Man ────────────────────────────────────────────────────────────────
 
 /// The player-controlled Pac-Man entity.
@@ -85,24 +202,79 @@ impl Position {
 /// in the struct (no pointer indirection). When `PacMan` is dropped,
 /// the `Position` is dropped with it automatically. This is Rust's
 /// ownership model in action: each value has exactly one owner.
+///
+/// Fields missing from saved data take their `PacMan::new()` values.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PacMan {
     pub position: Position,
     pub direction: Direction,
+    /// Buffered turn, taken at the next opening. Same as `direction` when
+    /// no turn is pending.
     pub next_direction: Direction,
+    /// Seconds the buffered turn has been waiting (see
+    /// `GameConfig::input_window`).
+    pub input_age: f64,
+    /// Turns pressed after `next_direction`, oldest first, with their ages;
+    /// each takes its place in turn.
+    pub queued_turns: Vec<(Direction, f64)>,
     pub lives: u8,
     pub score: u32,
+    /// Charges of the wall-breaker power-up: each lets Pac-Man smash
+    /// through one wall tile.
+    pub wall_breaks: u8,
+    /// Ids of the keys collected so far (see `CellType::Key`).
+    pub keys: Vec<u8>,
+    /// Seconds left on a speed boost (see `boost.rs`); 0 when there's none.
+    pub boost: f64,
 }
 
 impl PacMan {
-    /// Create Pac-Man at the classic starting position (row 23, col 14).
+    /// Create Pac-Man on the classic maze's spawn. Games put him on their
+    /// own maze's `pacman_spawn` with `respawn`.
     pub fn new() -> Self {
+        let (x, y) = MazeMetadata::default().pacman_spawn;
         PacMan {
-            position: Position::new(14.0, 23.0),
+            position: Position::new(x, y),
             direction: Direction::Left,
             next_direction: Direction::Left,
+            input_age: 0.0,
+            queued_turns: Vec::new(),
             lives: 3,
             score: 0,
+            wall_breaks: 0,
+            keys: Vec::new(),
+            boost: 0.0,
+        }
+    }
+
+    /// Put Pac-Man back on `spawn` (the maze's `pacman_spawn`), facing
+    /// left.
+    ///
+    /// Only the position, heading, and any speed boost are reset — lives,
+    /// score, and inventory are left alone so they persist across respawns
+    /// and mazes.
+    pub fn respawn(&mut self, spawn: (f64, f64)) {
+        let start = PacMan::new();
+        self.position = Position::new(spawn.0, spawn.1);
+        self.direction = start.direction;
+        self.next_direction = start.next_direction;
+        self.input_age = 0.0;
+        self.queued_turns.clear();
+        self.boost = 0.0;
+    }
+
+    /// Buffer a turn behind any already waiting (see `Turns::push`).
+    pub fn buffer_turn(&mut self, direction: Direction) {
+        self.turns().push(direction);
+    }
+
+    pub(crate) fn turns(&mut self) -> Turns<'_> {
+        Turns {
+            current: self.direction,
+            next: &mut self.next_direction,
+            age: &mut self.input_age,
+            queue: &mut self.queued_turns,
         }
     }
 }
@@ -133,6 +305,67 @@ pub enum GhostType {
     Clyde,
 }
 
+impl GhostType {
+    /// The fill pattern that identifies this ghost without relying on
+    /// color. Stable: renderers and saved settings may key off it.
+    pub fn pattern(self) -> GhostPattern {
+        match self {
+            GhostType::Blinky => GhostPattern::Solid,
+            GhostType::Pinky => GhostPattern::Dots,
+            GhostType::Inky => GhostPattern::Stripes,
+            GhostType::Clyde => GhostPattern::Checks,
+        }
+    }
+
+    /// Stable numeric code for flat JS arrays: Blinky 0, Pinky 1, Inky 2,
+    /// Clyde 3.
+    pub fn code(self) -> u8 {
+        match self {
+            GhostType::Blinky => 0,
+            GhostType::Pinky => 1,
+            GhostType::Inky => 2,
+            GhostType::Clyde => 3,
+        }
+    }
+}
+
+// ─── Accessible identity ────────────────────────────────────────────────────
+
+/// A per-ghost fill pattern for colorblind-friendly rendering.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum GhostPattern {
+    #[default]
+    Solid,
+    Dots,
+    Stripes,
+    Checks,
+}
+
+/// How a ghost should be drawn right now, independent of palette.
+///
+/// Renderers that can't rely on the blue/white frightened colors draw
+/// `Frightened` with a distinct shape (e.g. a wavy mouth) instead.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum GhostLook {
+    /// Body in the ghost's own pattern
+    #[default]
+    Normal,
+    /// Vulnerable: safe for Pac-Man to eat
+    Frightened,
+    /// Eyes only, heading back to the ghost house
+    Eyes,
+}
+
+impl GhostLook {
+    pub fn for_mode(mode: GhostMode) -> GhostLook {
+        match mode {
+            GhostMode::Chase | GhostMode::Scatter => GhostLook::Normal,
+            GhostMode::Frightened => GhostLook::Frightened,
+            GhostMode::Eaten => GhostLook::Eyes,
+        }
+    }
+}
+
 // ─── Ghost modes ────────────────────────────────────────────────────────────
 
 /// The behavioral state of a ghost.
@@ -155,16 +388,78 @@ pub enum GhostMode {
     Eaten,
 }
 
+impl GhostMode {
+    /// Stable numeric code for flat JS arrays: Chase 0, Scatter 1,
+    /// Frightened 2, Eaten 3.
+    pub fn code(self) -> u8 {
+        match self {
+            GhostMode::Chase => 0,
+            GhostMode::Scatter => 1,
+            GhostMode::Frightened => 2,
+            GhostMode::Eaten => 3,
+        }
+    }
+}
+
+// ─── Ghost house ────────────────────────────────────────────────────────────
+
+/// Where a ghost is relative to the ghost house (see `house.rs`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum HouseState {
+    /// Roaming the maze under normal AI
+    #[default]
+    Outside,
+    /// Waiting to be released
+    InHouse,
+    /// Released, heading up through the door
+    LeavingHouse,
+}
+
 // ─── Ghost ──────────────────────────────────────────────────────────────────
 
 /// A ghost entity with its type, position, and behavioral state.
+///
+/// Fields added after the original five are optional in saved data.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Ghost {
     pub ghost_type: GhostType,
     pub position: Position,
     pub direction: Direction,
+    /// Buffered turn of the PvP player's ghost (the AI steers directly).
     pub next_direction: Direction,
+    /// Seconds the buffered turn has been waiting, as on `PacMan`.
+    #[serde(default)]
+    pub input_age: f64,
+    /// Turns pressed after `next_direction`, as on `PacMan`.
+    #[serde(default)]
+    pub queued_turns: Vec<(Direction, f64)>,
     pub mode: GhostMode,
+    /// Colorblind-safe identity, fixed by `ghost_type`.
+    #[serde(default)]
+    pub pattern: GhostPattern,
+    /// Mirrors `mode` for rendering (refreshed every tick).
+    #[serde(default)]
+    pub look: GhostLook,
+    /// Frightened and about to recover: renderers flash it blue/white.
+    /// Saves that call it `frightened_ending` load too.
+    #[serde(default, alias = "frightened_ending")]
+    pub frightened_flashing: bool,
+    /// While `frightened_flashing`, whether this frame of the flash is the
+    /// white one.
+    #[serde(default)]
+    pub flash_white: bool,
+    #[serde(default)]
+    pub house: HouseState,
+    /// Dots counted toward leaving the house.
+    #[serde(default)]
+    pub dot_counter: u32,
+    /// Held in place by the debug console; never saved.
+    #[serde(skip)]
+    pub frozen: bool,
+    /// Chase or Scatter pinned by the debug console in place of the wave
+    /// schedule; never saved.
+    #[serde(skip)]
+    pub pinned_mode: Option<GhostMode>,
 }
 
 impl Ghost {
@@ -181,7 +476,27 @@ impl Ghost {
             position,
             direction: Direction::Up,
             next_direction: Direction::Up,
+            input_age: 0.0,
+            queued_turns: Vec::new(),
             mode: GhostMode::Scatter,
+            pattern: ghost_type.pattern(),
+            look: GhostLook::Normal,
+            frightened_flashing: false,
+            flash_white: false,
+            house: HouseState::Outside,
+            dot_counter: 0,
+            frozen: false,
+            pinned_mode: None,
+        }
+    }
+
+    /// The PvP player's waiting turns, as on `PacMan`.
+    pub(crate) fn turns(&mut self) -> Turns<'_> {
+        Turns {
+            current: self.direction,
+            next: &mut self.next_direction,
+            age: &mut self.input_age,
+            queue: &mut self.queued_turns,
         }
     }
 
@@ -190,13 +505,163 @@ impl Ghost {
     /// # Ownership note
     /// Returns a `Vec<Ghost>` — an owned, heap-allocated vector.
     /// The caller takes ownership of the entire vector and all ghosts in it.
+    ///
+    /// Blinky starts outside the house; the others wait inside it.
     pub fn create_all() -> Vec<Ghost> {
-        vec![
-            Ghost::new(GhostType::Blinky, Position::new(14.0, 11.0)),
-            Ghost::new(GhostType::Pinky, Position::new(12.0, 14.0)),
-            Ghost::new(GhostType::Inky, Position::new(14.0, 14.0)),
-            Ghost::new(GhostType::Clyde, Position::new(16.0, 14.0)),
-        ]
+        Ghost::roster(&CLASSIC_ROSTER, &MazeMetadata::default())
+    }
+
+    /// Ghosts with these personalities, in this order (at most
+    /// `MAX_GHOSTS`; an empty roster gets a lone Blinky). Personalities may
+    /// repeat.
+    ///
+    /// The first ghost starts outside the house door of the maze described
+    /// by `meta`; the rest wait inside, taking its house slots in order,
+    /// then again from the first.
+    pub fn roster(types: &[GhostType], meta: &MazeMetadata) -> Vec<Ghost> {
+        Ghost::spawn(types, MAX_GHOSTS, meta)
+    }
+
+    /// `roster` for chaos games: up to `MAX_CHAOS_GHOSTS` ghosts.
+    pub fn horde(types: &[GhostType], meta: &MazeMetadata) -> Vec<Ghost> {
+        Ghost::spawn(types, MAX_CHAOS_GHOSTS, meta)
+    }
+
+    fn spawn(types: &[GhostType], max: usize, meta: &MazeMetadata) -> Vec<Ghost> {
+        let types = if types.is_empty() {
+            &[GhostType::Blinky][..]
+        } else {
+            &types[..types.len().min(max)]
+        };
+        types
+            .iter()
+            .enumerate()
+            .map(|(i, &ghost_type)| {
+                let (x, y) = meta.house_exit;
+                if i == 0 || meta.house_slots.is_empty() {
+                    return Ghost::new(ghost_type, Position::new(x, y));
+                }
+                let (x, y) = meta.house_slots[(i - 1) % meta.house_slots.len()];
+                let mut ghost = Ghost::new(ghost_type, Position::new(x, y));
+                ghost.house = HouseState::InHouse;
+                ghost
+            })
+            .collect()
+    }
+}
+
+/// Most ghosts a game can have.
+pub const MAX_GHOSTS: usize = 8;
+
+/// The arcade's ghosts, in roster order.
+pub const CLASSIC_ROSTER: [GhostType; 4] = [
+    GhostType::Blinky,
+    GhostType::Pinky,
+    GhostType::Inky,
+    GhostType::Clyde,
+];
+
+// ─── Fruit ──────────────────────────────────────────────────────────────────
+
+/// The bonus fruit types, in level order (see `FruitKind::for_level`).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum FruitKind {
+    Cherry,
+    Strawberry,
+    Orange,
+    Apple,
+    Melon,
+    Galaxian,
+    Bell,
+    Key,
+}
+
+impl FruitKind {
+    /// The arcade fruit for `level`: cherry on 1, strawberry on 2, then
+    /// two levels per fruit, with the key from level 13 on.
+    pub fn for_level(level: u32) -> FruitKind {
+        match level {
+            0 | 1 => FruitKind::Cherry,
+            2 => FruitKind::Strawberry,
+            3 | 4 => FruitKind::Orange,
+            5 | 6 => FruitKind::Apple,
+            7 | 8 => FruitKind::Melon,
+            9 | 10 => FruitKind::Galaxian,
+            11 | 12 => FruitKind::Bell,
+            _ => FruitKind::Key,
+        }
+    }
+
+    pub fn points(self) -> u32 {
+        match self {
+            FruitKind::Cherry => 100,
+            FruitKind::Strawberry => 300,
+            FruitKind::Orange => 500,
+            FruitKind::Apple => 700,
+            FruitKind::Melon => 1000,
+            FruitKind::Galaxian => 2000,
+            FruitKind::Bell => 3000,
+            FruitKind::Key => 5000,
+        }
+    }
+
+    /// Stable numeric code for flat JS arrays, in level order (Cherry 0
+    /// to Key 7).
+    pub fn code(self) -> u8 {
+        match self {
+            FruitKind::Cherry => 0,
+            FruitKind::Strawberry => 1,
+            FruitKind::Orange => 2,
+            FruitKind::Apple => 3,
+            FruitKind::Melon => 4,
+            FruitKind::Galaxian => 5,
+            FruitKind::Bell => 6,
+            FruitKind::Key => 7,
+        }
+    }
+}
+
+/// A bonus fruit waiting to be eaten.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Fruit {
+    pub kind: FruitKind,
+    pub position: Position,
+    /// Seconds until it disappears.
+    pub remaining: f64,
+}
+
+// ─── Entity ids ─────────────────────────────────────────────────────────────
+
+/// Names a single entity in events and diagnostics.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum EntityId {
+    PacMan,
+    Ghost(GhostType),
+}
+
+/// A ghost picked out by a setup or command. Rosters may repeat a
+/// personality, so only an index reaches every ghost.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GhostPick {
+    /// The first ghost with this personality.
+    Type(GhostType),
+    /// The ghost at this index in `ghosts`.
+    Index(usize),
+}
+
+impl From<GhostType> for GhostPick {
+    fn from(ghost: GhostType) -> Self {
+        GhostPick::Type(ghost)
+    }
+}
+
+impl GhostPick {
+    /// Where the picked ghost is in `ghosts`, if it's there.
+    pub fn find(self, ghosts: &[Ghost]) -> Option<usize> {
+        match self {
+            GhostPick::Type(ghost) => ghosts.iter().position(|g| g.ghost_type == ghost),
+            GhostPick::Index(i) => (i < ghosts.len()).then_some(i),
+        }
     }
 }
 
@@ -222,12 +687,36 @@ mod tests {
         assert_eq!(row, 23);
     }
 
+    #[test]
+    fn vectors_map_to_the_dominant_direction() {
+        assert_eq!(
+            Direction::from_vector(0.9, -0.3, 0.2),
+            Some(Direction::Right)
+        );
+        assert_eq!(Direction::from_vector(-0.2, -0.7, 0.2), Some(Direction::Up));
+        assert_eq!(Direction::from_vector(0.1, 0.1, 0.2), None);
+        assert_eq!(Direction::from_vector(0.0, 0.0, 0.0), None);
+        assert_eq!(Direction::from_vector(f64::NAN, 1.0, 0.2), None);
+    }
+
     #[test]
     fn create_all_ghosts_returns_four() {
         let ghosts = Ghost::create_all();
         assert_eq!(ghosts.len(), 4);
     }
 
+    #[test]
+    fn roster_repeats_personalities_and_fills_the_house() {
+        let types = [GhostType::Clyde; 9];
+        let ghosts = Ghost::roster(&types, &MazeMetadata::default());
+        assert_eq!(ghosts.len(), MAX_GHOSTS);
+        assert_eq!(ghosts[0].house, HouseState::Outside);
+        assert!(ghosts[1..].iter().all(|g| g.house == HouseState::InHouse));
+        // Slots wrap around after the third housed ghost
+        assert_eq!(ghosts[4].position.x, ghosts[1].position.x);
+        assert_eq!(Ghost::roster(&[], &MazeMetadata::default()).len(), 1);
+    }
+
     #[test]
     fn ghosts_have_correct_types() {
         let ghosts = Ghost::create_all();
@@ -245,6 +734,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ghosts_saved_with_frightened_ending_still_load() {
+        let mut value = serde_json::to_value(&Ghost::create_all()[0]).unwrap();
+        let fields = value.as_object_mut().unwrap();
+        fields.remove("frightened_flashing");
+        fields.insert("frightened_ending".into(), true.into());
+        let ghost: Ghost = serde_json::from_value(value).unwrap();
+        assert!(ghost.frightened_flashing);
+    }
+
+    #[test]
+    fn ghost_patterns_are_distinct() {
+        let ghosts = Ghost::create_all();
+        for (i, a) in ghosts.iter().enumerate() {
+            assert_eq!(a.pattern, a.ghost_type.pattern());
+            for b in &ghosts[i + 1..] {
+                assert_ne!(a.pattern, b.pattern);
+            }
+        }
+    }
+
+    #[test]
+    fn fruit_follows_the_arcade_table() {
+        assert_eq!(FruitKind::for_level(1), FruitKind::Cherry);
+        assert_eq!(FruitKind::for_level(4), FruitKind::Orange);
+        assert_eq!(FruitKind::for_level(12), FruitKind::Bell);
+        assert_eq!(FruitKind::for_level(40), FruitKind::Key);
+        assert_eq!(FruitKind::Key.points(), 5000);
+    }
+
     #[test]
     fn position_to_grid_rounds_correctly() {
         let pos = Position::new(13.7, 22.3);
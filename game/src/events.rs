@@ -0,0 +1,532 @@
+// game/src/events.rs
+//
+// Events emitted by the simulation during `tick()`.
+//
+// The renderer shouldn't have to diff the whole maze every frame to find
+// out what changed. Instead, game logic pushes a `GameEvent` whenever
+// something noteworthy happens, and the snapshot carries the list of
+// events produced by the most recent tick.
+//
+// # Stable event codes
+// Each event type also has a numeric code (`GameEvent::code`). Audio
+// sprite maps and analytics should key on these, not on variant names
+// or order — codes are never reused or renumbered:
+//
+// ```text
+// Code  Event
+// ────  ─────────────
+//  100  CellChanged
+//  101  DoorOpened
+//  102  DotEaten
+//  103  PowerPelletEaten
+//  104  FruitSpawned
+//  105  GhostsFrozen
+//  106  GhostsThawed
+//  200  ScoreChanged
+//  201  FruitEaten
+//  202  ExtraLife
+//  203  GhostEaten
+//  300  PhaseChanged
+//  301  LevelAdvanced
+//  302  Countdown
+//  303  LifeLost
+//  304  LevelComplete
+//  305  RoundOver
+//  306  RoundStarted
+//  307  MatchOver
+//  308  PlayerChanged
+//  309  ObjectiveStarted
+//  310  HintShown
+//  400  Sound
+//  500  EntityStuck
+// ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{EntityId, FruitKind, GhostMode, GhostType};
+use crate::maze::CellType;
+use crate::state::{GamePhase, GameStateInner};
+use crate::tutorial::{Hint, Objective};
+
+// ─── Sound cues ─────────────────────────────────────────────────────────────
+
+/// The mixer channel a sound plays on.
+///
+/// A channel plays one sound at a time; a new sound on a busy channel
+/// only starts if its priority is at least that of the playing one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SoundChannel {
+    /// Jingles (start of game, intermissions)
+    Music,
+    /// The looping background siren
+    Siren,
+    /// Short gameplay effects
+    Sfx,
+    /// Stings that must be heard over everything else
+    Voice,
+}
+
+impl SoundChannel {
+    /// The channel name passed to JS callbacks.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SoundChannel::Music => "music",
+            SoundChannel::Siren => "siren",
+            SoundChannel::Sfx => "sfx",
+            SoundChannel::Voice => "voice",
+        }
+    }
+}
+
+/// A priority that stops every channel, not just its own.
+pub const PRIORITY_EXCLUSIVE: u8 = u8::MAX;
+
+/// What the frontend should do with a cue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SoundAction {
+    /// Play the clip once.
+    Play,
+    /// Start looping the clip until a matching `Stop`.
+    Start,
+    /// Stop a loop started earlier.
+    Stop,
+}
+
+impl SoundAction {
+    /// The action name passed to JS callbacks.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SoundAction::Play => "play",
+            SoundAction::Start => "start",
+            SoundAction::Stop => "stop",
+        }
+    }
+}
+
+/// A sound the frontend should play.
+///
+/// The simulation decides *when* a sound happens, and when loops start and
+/// stop; JS only maps cues to audio clips.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum SoundCue {
+    /// Pac-Man ate a dot (first half of the waka-waka)
+    Waka,
+    /// Pac-Man ate a dot (second half; alternates with `Waka`)
+    WakaAlt,
+    /// Pac-Man ate a power pellet
+    PowerPellet,
+    /// Pac-Man ate a frightened ghost
+    GhostEaten,
+    /// Pac-Man was caught
+    Death,
+    /// The score reached the bonus-life threshold
+    ExtraLife,
+    /// Background siren loop, `0`–`4` rising as the board empties
+    Siren(u8),
+    /// Loop while ghosts are frightened
+    PowerLoop,
+    /// Loop while eaten ghosts' eyes head home
+    EyesLoop,
+}
+
+impl SoundCue {
+    /// The cue name passed to JS callbacks.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SoundCue::Waka => "waka",
+            SoundCue::WakaAlt => "waka_alt",
+            SoundCue::PowerPellet => "power_pellet",
+            SoundCue::GhostEaten => "ghost_eaten",
+            SoundCue::Death => "death",
+            SoundCue::ExtraLife => "extra_life",
+            SoundCue::Siren(0) => "siren_0",
+            SoundCue::Siren(1) => "siren_1",
+            SoundCue::Siren(2) => "siren_2",
+            SoundCue::Siren(3) => "siren_3",
+            SoundCue::Siren(_) => "siren_4",
+            SoundCue::PowerLoop => "power_loop",
+            SoundCue::EyesLoop => "eyes_loop",
+        }
+    }
+
+    /// Whether this cue is a loop, sent as `Start` / `Stop` rather than
+    /// `Play`.
+    pub fn is_loop(self) -> bool {
+        matches!(
+            self,
+            SoundCue::Siren(_) | SoundCue::PowerLoop | SoundCue::EyesLoop
+        )
+    }
+
+    /// The channel this cue plays on.
+    pub fn channel(self) -> SoundChannel {
+        match self {
+            SoundCue::Waka
+            | SoundCue::WakaAlt
+            | SoundCue::PowerPellet
+            | SoundCue::GhostEaten
+            | SoundCue::ExtraLife => SoundChannel::Sfx,
+            SoundCue::Death => SoundChannel::Voice,
+            SoundCue::Siren(_) | SoundCue::PowerLoop | SoundCue::EyesLoop => SoundChannel::Siren,
+        }
+    }
+
+    /// Higher wins. `PRIORITY_EXCLUSIVE` silences all other channels.
+    pub fn priority(self) -> u8 {
+        match self {
+            SoundCue::Siren(_) => 1,
+            SoundCue::PowerLoop => 2,
+            SoundCue::EyesLoop => 3,
+            SoundCue::Waka | SoundCue::WakaAlt => 10,
+            SoundCue::PowerPellet => 50,
+            SoundCue::ExtraLife => 100,
+            SoundCue::GhostEaten => 200,
+            SoundCue::Death => PRIORITY_EXCLUSIVE,
+        }
+    }
+
+    /// Whether this cue may start while `playing` is still going (cutting
+    /// it off if they share a channel).
+    pub fn can_interrupt(self, playing: SoundCue) -> bool {
+        if self.priority() == PRIORITY_EXCLUSIVE {
+            return true;
+        }
+        let shares_channel = self.channel() == playing.channel();
+        let exclusive = playing.priority() == PRIORITY_EXCLUSIVE;
+        !exclusive && (!shares_channel || self.priority() >= playing.priority())
+    }
+}
+
+// ─── Siren ──────────────────────────────────────────────────────────────────
+
+/// Siren loop while ghosts are frightened.
+pub const SIREN_FRIGHTENED: u8 = 5;
+/// Siren loop while eaten ghosts' eyes head home (wins over frightened).
+pub const SIREN_EYES: u8 = 6;
+
+impl GameStateInner {
+    /// Which siren loop should play: `0`–`4` rising as the board empties,
+    /// or `SIREN_FRIGHTENED` / `SIREN_EYES`.
+    pub fn siren_level(&self) -> u8 {
+        if self.ghosts.iter().any(|g| g.mode == GhostMode::Eaten) {
+            return SIREN_EYES;
+        }
+        if self.ghosts.iter().any(|g| g.mode == GhostMode::Frightened) {
+            return SIREN_FRIGHTENED;
+        }
+        let total = self.level_maze.dots_remaining().max(1);
+        let eaten = total.saturating_sub(self.dots_remaining);
+        (eaten * 5 / total).min(4) as u8
+    }
+
+    /// The loop that should be playing right now: the siren (or its
+    /// frightened / eyes variant) during play, silence otherwise.
+    fn wanted_loop(&self) -> Option<SoundCue> {
+        if self.phase != GamePhase::Playing {
+            return None;
+        }
+        Some(match self.siren_level() {
+            SIREN_EYES => SoundCue::EyesLoop,
+            SIREN_FRIGHTENED => SoundCue::PowerLoop,
+            level => SoundCue::Siren(level),
+        })
+    }
+
+    /// Emit `Stop` / `Start` cues when the wanted loop changes (called
+    /// every tick), so JS never has to work out which loop to play.
+    pub(crate) fn update_sound_loops(&mut self) {
+        let wanted = self.wanted_loop();
+        if wanted == self.sound_loop {
+            return;
+        }
+        if let Some(old) = self.sound_loop {
+            self.events.push(GameEvent::sound_stop(old));
+        }
+        if let Some(new) = wanted {
+            self.events.push(GameEvent::sound_start(new));
+        }
+        self.sound_loop = wanted;
+    }
+}
+
+// ─── Events ─────────────────────────────────────────────────────────────────
+
+/// Most events kept for `drain_events()`; older ones are dropped first if JS
+/// stops draining.
+pub const MAX_PENDING_EVENTS: usize = 1024;
+
+/// Something that happened during a tick.
+///
+/// # Why struct-like variants?
+/// Named fields (`{ row, col, cell }`) serialize to plain JS objects with
+/// the same keys, so the frontend reads `event.CellChanged.row` instead of
+/// guessing what positional tuple fields mean.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum GameEvent {
+    /// A maze cell changed type (dot eaten, wall broken, ...).
+    CellChanged {
+        row: usize,
+        col: usize,
+        cell: CellType,
+    },
+    /// A locked door was opened by collecting its key.
+    DoorOpened { row: usize, col: usize, key: u8 },
+    /// Pac-Man ate the dot at (row, col).
+    DotEaten { row: usize, col: usize },
+    /// Pac-Man ate the power pellet at (row, col).
+    PowerPelletEaten { row: usize, col: usize },
+    /// A bonus fruit appeared at (row, col).
+    FruitSpawned {
+        kind: FruitKind,
+        row: usize,
+        col: usize,
+    },
+    /// Pac-Man ate the time freeze at (row, col); the ghosts stand still
+    /// for `seconds`.
+    GhostsFrozen {
+        row: usize,
+        col: usize,
+        seconds: f64,
+    },
+    /// A time freeze ran out and the ghosts move again.
+    GhostsThawed,
+    /// Pac-Man's score is different from the end of the previous tick.
+    ScoreChanged { score: u32 },
+    /// Pac-Man ate the bonus fruit.
+    FruitEaten { kind: FruitKind, points: u32 },
+    /// The score reached the bonus-life threshold.
+    ExtraLife { lives: u8 },
+    /// Pac-Man ate a frightened ghost, scoring `points`. `index` is its
+    /// place in `ghosts`, which tells apart ghosts sharing a personality.
+    GhostEaten {
+        ghost: GhostType,
+        index: usize,
+        points: u32,
+    },
+    /// The game phase is different from the end of the previous tick.
+    PhaseChanged { from: GamePhase, to: GamePhase },
+    /// The board was refilled for a new level.
+    LevelAdvanced { level: u32 },
+    /// The "READY!" countdown before play (or after a respawn) reached a
+    /// new whole second: `seconds` is what's left, rounded up.
+    Countdown { seconds: u8 },
+    /// Pac-Man was caught; `lives` is what's left.
+    LifeLost { lives: u8 },
+    /// Every dot on `level` is eaten.
+    LevelComplete { level: u32 },
+    /// A PvP match round ended; `winner` is the player (1 or 2) who scored
+    /// more, `None` on a tie.
+    RoundOver { round: u8, winner: Option<u8> },
+    /// The next PvP match round began, with player `pacman_player` (1 or 2)
+    /// now playing Pac-Man.
+    RoundStarted { round: u8, pacman_player: u8 },
+    /// The PvP match is decided; `winner` is `None` on a draw.
+    MatchOver { winner: Option<u8> },
+    /// Alternating two-player: `player` (1 or 2) takes the controls, with
+    /// their own board; refetch the maze, as after `LevelAdvanced`.
+    PlayerChanged { player: u8 },
+    /// The tutorial moved on to `objective` (see `tutorial.rs`).
+    ObjectiveStarted { objective: Objective },
+    /// The tutorial explains `hint`, which just came up for the first time.
+    HintShown { hint: Hint },
+    /// A sound should be played, or a loop started or stopped. `channel`
+    /// and `priority` are copied from the cue so frontends don't need their
+    /// own tables.
+    Sound {
+        cue: SoundCue,
+        action: SoundAction,
+        channel: SoundChannel,
+        priority: u8,
+    },
+    /// Diagnostic: an entity made no tile progress for too long and the
+    /// watchdog stepped in. (row, col) is where it was stuck.
+    EntityStuck {
+        entity: EntityId,
+        row: usize,
+        col: usize,
+    },
+}
+
+impl GameEvent {
+    /// A one-shot `Sound` event with the cue's channel and priority
+    /// filled in.
+    pub fn sound(cue: SoundCue) -> GameEvent {
+        GameEvent::sound_action(cue, SoundAction::Play)
+    }
+
+    /// A `Sound` event starting the loop `cue`.
+    pub fn sound_start(cue: SoundCue) -> GameEvent {
+        GameEvent::sound_action(cue, SoundAction::Start)
+    }
+
+    /// A `Sound` event stopping the loop `cue`.
+    pub fn sound_stop(cue: SoundCue) -> GameEvent {
+        GameEvent::sound_action(cue, SoundAction::Stop)
+    }
+
+    fn sound_action(cue: SoundCue, action: SoundAction) -> GameEvent {
+        GameEvent::Sound {
+            cue,
+            action,
+            channel: cue.channel(),
+            priority: cue.priority(),
+        }
+    }
+
+    /// The stable numeric code for this event type (see the module docs).
+    ///
+    /// New variants get new codes; existing codes never change, even if
+    /// variants are renamed or reordered.
+    pub fn code(&self) -> u16 {
+        match self {
+            GameEvent::CellChanged { .. } => 100,
+            GameEvent::DoorOpened { .. } => 101,
+            GameEvent::DotEaten { .. } => 102,
+            GameEvent::PowerPelletEaten { .. } => 103,
+            GameEvent::FruitSpawned { .. } => 104,
+            GameEvent::GhostsFrozen { .. } => 105,
+            GameEvent::GhostsThawed => 106,
+            GameEvent::ScoreChanged { .. } => 200,
+            GameEvent::FruitEaten { .. } => 201,
+            GameEvent::ExtraLife { .. } => 202,
+            GameEvent::GhostEaten { .. } => 203,
+            GameEvent::PhaseChanged { .. } => 300,
+            GameEvent::LevelAdvanced { .. } => 301,
+            GameEvent::Countdown { .. } => 302,
+            GameEvent::LifeLost { .. } => 303,
+            GameEvent::LevelComplete { .. } => 304,
+            GameEvent::RoundOver { .. } => 305,
+            GameEvent::RoundStarted { .. } => 306,
+            GameEvent::MatchOver { .. } => 307,
+            GameEvent::PlayerChanged { .. } => 308,
+            GameEvent::ObjectiveStarted { .. } => 309,
+            GameEvent::HintShown { .. } => 310,
+            GameEvent::Sound { .. } => 400,
+            GameEvent::EntityStuck { .. } => 500,
+        }
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Guards the documented table: if this fails, a code was changed.
+    #[test]
+    fn event_codes_are_stable() {
+        let cell_changed = GameEvent::CellChanged {
+            row: 0,
+            col: 0,
+            cell: CellType::Empty,
+        };
+        let door_opened = GameEvent::DoorOpened {
+            row: 0,
+            col: 0,
+            key: 0,
+        };
+        assert_eq!(cell_changed.code(), 100);
+        assert_eq!(door_opened.code(), 101);
+        assert_eq!(GameEvent::ScoreChanged { score: 0 }.code(), 200);
+        let fruit_eaten = GameEvent::FruitEaten {
+            kind: FruitKind::Cherry,
+            points: 100,
+        };
+        assert_eq!(fruit_eaten.code(), 201);
+        assert_eq!(GameEvent::ExtraLife { lives: 4 }.code(), 202);
+        let phase_changed = GameEvent::PhaseChanged {
+            from: GamePhase::Ready,
+            to: GamePhase::Playing,
+        };
+        assert_eq!(phase_changed.code(), 300);
+        assert_eq!(GameEvent::LevelAdvanced { level: 2 }.code(), 301);
+        assert_eq!(GameEvent::Countdown { seconds: 2 }.code(), 302);
+        assert_eq!(GameEvent::sound(SoundCue::Waka).code(), 400);
+        let stuck = GameEvent::EntityStuck {
+            entity: EntityId::PacMan,
+            row: 0,
+            col: 0,
+        };
+        assert_eq!(stuck.code(), 500);
+    }
+
+    #[test]
+    fn drained_events_span_ticks_until_taken() {
+        let mut state = GameStateInner::new(crate::state::GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        state.tick(0.016); // Eats the dot under Pac-Man
+        state.tick(0.016);
+
+        let drained = state.drain_events();
+        assert!(drained.contains(&GameEvent::DotEaten { row: 23, col: 14 }));
+        assert!(state.drain_events().is_empty());
+
+        for _ in 0..MAX_PENDING_EVENTS + 10 {
+            state.events.push(GameEvent::Countdown { seconds: 1 });
+            state.queue_events();
+        }
+        assert_eq!(state.drain_events().len(), MAX_PENDING_EVENTS);
+    }
+
+    #[test]
+    fn siren_rises_as_dots_are_eaten() {
+        let mut state = GameStateInner::new(crate::state::GameMode::Classic);
+        let total = state.dots_remaining;
+        assert_eq!(state.siren_level(), 0);
+        state.dots_remaining = total / 2;
+        assert_eq!(state.siren_level(), 2);
+        state.dots_remaining = 1;
+        assert_eq!(state.siren_level(), 4);
+
+        state.ghosts[1].mode = GhostMode::Frightened;
+        assert_eq!(state.siren_level(), SIREN_FRIGHTENED);
+        state.ghosts[2].mode = GhostMode::Eaten;
+        assert_eq!(state.siren_level(), SIREN_EYES);
+    }
+
+    #[test]
+    fn death_interrupts_everything() {
+        for cue in [SoundCue::Waka, SoundCue::PowerPellet, SoundCue::GhostEaten] {
+            assert!(SoundCue::Death.can_interrupt(cue));
+            assert!(!cue.can_interrupt(SoundCue::Death));
+        }
+    }
+
+    #[test]
+    fn waka_never_interrupts_ghost_eaten_sting() {
+        assert!(!SoundCue::Waka.can_interrupt(SoundCue::GhostEaten));
+        assert!(SoundCue::GhostEaten.can_interrupt(SoundCue::Waka));
+        assert!(SoundCue::PowerPellet.can_interrupt(SoundCue::Waka));
+    }
+
+    #[test]
+    fn siren_loop_switches_with_start_and_stop() {
+        let mut state = GameStateInner::new(crate::state::GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        state.update_sound_loops();
+        assert_eq!(
+            state.events,
+            vec![GameEvent::sound_start(SoundCue::Siren(0))]
+        );
+
+        state.events.clear();
+        state.ghosts[0].mode = GhostMode::Frightened;
+        state.update_sound_loops();
+        assert_eq!(
+            state.events,
+            vec![
+                GameEvent::sound_stop(SoundCue::Siren(0)),
+                GameEvent::sound_start(SoundCue::PowerLoop),
+            ]
+        );
+
+        state.events.clear();
+        state.phase = GamePhase::Dying;
+        state.update_sound_loops();
+        assert_eq!(
+            state.events,
+            vec![GameEvent::sound_stop(SoundCue::PowerLoop)]
+        );
+    }
+}
@@ -0,0 +1,129 @@
+// game/src/fog.rs
+//
+// Fog of war for dark mazes.
+//
+// With `GameConfig::fog_radius` set, Pac-Man only sees part of the board:
+//
+// - every tile within `fog_radius` tiles of him (a circle), and
+// - straight down the corridors he stands in, in all four directions, up
+//   to and including the wall that ends them.
+//
+// The mask is worked out here once a tick and kept in
+// `GameStateInner::visibility` (row-major, one entry per maze tile), so the
+// renderer can dim what's unseen without knowing anything about walls. In
+// Co-op a tile either player can see is visible. With fog off the mask is
+// empty and everything is lit.
+
+use crate::entities::Direction;
+use crate::state::GameStateInner;
+
+// ─── Visibility ─────────────────────────────────────────────────────────────
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Left,
+    Direction::Down,
+    Direction::Right,
+];
+
+impl GameStateInner {
+    /// Whether the tile at (row, col) is visible. Always true with fog off.
+    pub fn tile_visible(&self, row: usize, col: usize) -> bool {
+        self.visibility.is_empty()
+            || self.visibility.get(row * self.maze.width + col) == Some(&true)
+    }
+
+    /// Recompute `visibility` around the players.
+    pub(crate) fn update_fog(&mut self) {
+        let radius = self.config.fog_radius as isize;
+        if radius == 0 {
+            self.visibility.clear();
+            return;
+        }
+        let (w, h) = (self.maze.width as isize, self.maze.height as isize);
+        let mut mask = vec![false; (w * h) as usize];
+        let players = std::iter::once(&self.pacman).chain(self.pacman2.as_ref());
+
+        for pacman in players {
+            let (col, row) = pacman.position.to_grid();
+            let (col, row) = (col as isize, row as isize);
+            let mut see = |c: isize, r: isize| {
+                if (0..w).contains(&c) && (0..h).contains(&r) {
+                    mask[(r * w + c) as usize] = true;
+                }
+            };
+            for dy in -radius..=radius {
+                for dx in -radius..=radius {
+                    if dx * dx + dy * dy <= radius * radius {
+                        see(col + dx, row + dy);
+                    }
+                }
+            }
+            // Line of sight along the corridors, stopping at the first wall
+            for dir in DIRECTIONS {
+                let (dx, dy) = dir.to_vector();
+                let (mut c, mut r) = (col, row);
+                loop {
+                    c += dx as isize;
+                    r += dy as isize;
+                    if !(0..w).contains(&c) || !(0..h).contains(&r) {
+                        break;
+                    }
+                    see(c, r);
+                    if !self.maze.is_walkable(c as f64, r as f64) {
+                        break;
+                    }
+                }
+            }
+        }
+        self.visibility = mask;
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GameConfig;
+    use crate::state::{GameMode, GamePhase};
+
+    fn dark(radius: u8) -> GameStateInner {
+        let config = GameConfig {
+            fog_radius: radius,
+            ..GameConfig::default()
+        };
+        GameStateInner::with_config(GameMode::Classic, config)
+    }
+
+    #[test]
+    fn pacman_sees_around_him_and_down_corridors() {
+        let state = dark(2);
+        // Pac-Man starts at (14, 23), in the long corridor of row 23
+        assert_eq!(state.visibility.len(), state.maze.width * state.maze.height);
+        assert!(state.tile_visible(23, 14));
+        assert!(state.tile_visible(21, 14));
+        assert!(!state.tile_visible(20, 14));
+        // Along the corridor, well past the radius, up to its end wall
+        assert!(state.tile_visible(23, 7));
+        assert!(state.tile_visible(23, 19));
+        assert!(!state.tile_visible(23, 1));
+        // The far side of the board is dark
+        assert!(!state.tile_visible(1, 1));
+    }
+
+    #[test]
+    fn the_mask_follows_pacman_and_fog_can_be_off() {
+        let mut state = dark(3);
+        let before = state.visibility.clone();
+        state.phase = GamePhase::Playing;
+        for _ in 0..30 {
+            state.tick(1.0 / 60.0);
+        }
+        assert_ne!(state.visibility, before);
+
+        let lit = GameStateInner::new(GameMode::Classic);
+        assert!(lit.visibility.is_empty());
+        assert!(lit.tile_visible(1, 1));
+    }
+}
@@ -0,0 +1,322 @@
+// game/src/framebuffer.rs
+//
+// Software renderer into an RGBA framebuffer (feature `framebuffer`).
+//
+// For OffscreenCanvas / worker setups where JS shouldn't draw at all:
+// `render_framebuffer()` rasterizes the whole frame into a buffer owned by
+// the `GameState`, and JS blits it straight out of WASM memory:
+//
+// ```text
+// game.render_framebuffer();
+// const px = new Uint8ClampedArray(memory.buffer, game.framebuffer_ptr(),
+//                                  game.framebuffer_len());
+// ctx.putImageData(new ImageData(px, game.framebuffer_width()), 0, 0);
+// ```
+//
+// Each tile is `TILE_PX` pixels square, and a `HUD_TILES`-high strip below
+// the maze shows the score and spare lives. Sprites come from
+// `render_commands()`, so what's drawn matches every other frontend; they're
+// plain shapes rather than the arcade bitmaps.
+//
+// The pointer is only valid until the next call into the game: the buffer
+// is reallocated when the maze size changes, and WASM memory can grow.
+
+use std::f64::consts::PI;
+
+use crate::animation::{DEATH_FRAMES, MOUTH_FRAMES};
+use crate::maze::CellType;
+use crate::render::{Flip, RenderCommand, Sprite};
+use crate::state::GameStateInner;
+
+// ─── Layout and palette ─────────────────────────────────────────────────────
+
+/// Pixels per tile.
+pub const TILE_PX: usize = 8;
+/// Height of the HUD strip under the maze, in tiles.
+pub const HUD_TILES: usize = 2;
+
+type Rgb = [u8; 3];
+
+const BACKGROUND: Rgb = [0, 0, 0];
+const WALL: Rgb = [33, 33, 222];
+const DOT: Rgb = [255, 184, 151];
+const PACMAN: Rgb = [255, 255, 0];
+const GHOSTS: [Rgb; 4] = [
+    [255, 0, 0],     // Blinky
+    [255, 184, 255], // Pinky
+    [0, 255, 255],   // Inky
+    [255, 184, 82],  // Clyde
+];
+const FRIGHTENED: Rgb = [33, 33, 255];
+const FLASH: Rgb = [222, 222, 255];
+const EYE_WHITE: Rgb = [255, 255, 255];
+const PUPIL: Rgb = [33, 33, 255];
+const FRUIT: Rgb = [255, 0, 0];
+const ITEM: Rgb = [0, 255, 0];
+const TEXT: Rgb = [255, 255, 255];
+const POPUP: Rgb = [0, 255, 255];
+
+/// 3×5 digit glyphs, one row per entry, most significant bit on the left.
+const DIGITS: [[u8; 5]; 10] = [
+    [0b111, 0b101, 0b101, 0b101, 0b111],
+    [0b010, 0b110, 0b010, 0b010, 0b111],
+    [0b111, 0b001, 0b111, 0b100, 0b111],
+    [0b111, 0b001, 0b111, 0b001, 0b111],
+    [0b101, 0b101, 0b111, 0b001, 0b001],
+    [0b111, 0b100, 0b111, 0b001, 0b111],
+    [0b111, 0b100, 0b111, 0b101, 0b111],
+    [0b111, 0b001, 0b010, 0b010, 0b010],
+    [0b111, 0b101, 0b111, 0b101, 0b111],
+    [0b111, 0b101, 0b111, 0b001, 0b111],
+];
+
+// ─── Framebuffer ────────────────────────────────────────────────────────────
+
+/// An RGBA image, row-major, 4 bytes per pixel, fully opaque.
+#[derive(Clone, Debug, Default)]
+pub struct Framebuffer {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u8>,
+}
+
+impl Framebuffer {
+    /// Make the buffer `width`×`height`, cleared to the background.
+    fn reset(&mut self, width: usize, height: usize) {
+        self.width = width;
+        self.height = height;
+        self.pixels.clear();
+        self.pixels.resize(width * height * 4, 0);
+        for px in self.pixels.chunks_exact_mut(4) {
+            px[..3].copy_from_slice(&BACKGROUND);
+            px[3] = 255;
+        }
+    }
+
+    /// Color of the pixel at (x, y).
+    pub fn pixel(&self, x: usize, y: usize) -> [u8; 3] {
+        let i = (y * self.width + x) * 4;
+        [self.pixels[i], self.pixels[i + 1], self.pixels[i + 2]]
+    }
+
+    fn put(&mut self, x: isize, y: isize, color: Rgb) {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return;
+        }
+        let i = (y as usize * self.width + x as usize) * 4;
+        self.pixels[i..i + 3].copy_from_slice(&color);
+    }
+
+    fn fill_rect(&mut self, x: isize, y: isize, w: isize, h: isize, color: Rgb) {
+        for py in y..y + h {
+            for px in x..x + w {
+                self.put(px, py, color);
+            }
+        }
+    }
+
+    /// Fill the pixels within `r` of (cx, cy) for which `keep(dx, dy)`.
+    fn fill_disc(&mut self, cx: f64, cy: f64, r: f64, color: Rgb, keep: impl Fn(f64, f64) -> bool) {
+        let (x0, x1) = ((cx - r).floor() as isize, (cx + r).ceil() as isize);
+        let (y0, y1) = ((cy - r).floor() as isize, (cy + r).ceil() as isize);
+        for py in y0..y1 {
+            for px in x0..x1 {
+                let (dx, dy) = (px as f64 + 0.5 - cx, py as f64 + 0.5 - cy);
+                if dx * dx + dy * dy <= r * r && keep(dx, dy) {
+                    self.put(px, py, color);
+                }
+            }
+        }
+    }
+
+    /// Draw `value` in 3×5 digits scaled by `scale`, left edge at `x`.
+    fn draw_number(&mut self, value: u32, x: isize, y: isize, scale: isize, color: Rgb) {
+        for (i, ch) in value.to_string().bytes().enumerate() {
+            let glyph = DIGITS[(ch - b'0') as usize];
+            let left = x + i as isize * 4 * scale;
+            for (row, bits) in glyph.iter().enumerate() {
+                for col in 0..3 {
+                    if bits & (0b100 >> col) != 0 {
+                        let (px, py) = (left + col * scale, y + row as isize * scale);
+                        self.fill_rect(px, py, scale, scale, color);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ─── Rasterizing ────────────────────────────────────────────────────────────
+
+impl GameStateInner {
+    /// Draw the current frame into `fb`, resizing it to fit the maze.
+    pub fn render_into(&self, fb: &mut Framebuffer) {
+        let (w, h) = (self.maze.width, self.maze.height);
+        fb.reset(w * TILE_PX, (h + HUD_TILES) * TILE_PX);
+        let t = TILE_PX as isize;
+
+        for (row, cells) in self.maze.cells.iter().enumerate() {
+            for (col, cell) in cells.iter().enumerate() {
+                if *cell == CellType::Wall {
+                    fb.fill_rect(col as isize * t, row as isize * t, t, t, WALL);
+                }
+            }
+        }
+        for command in self.render_commands() {
+            draw_sprite(fb, &command);
+        }
+
+        // HUD: score on the left, spare lives on the right
+        let hud_y = (h * TILE_PX) as isize;
+        fb.draw_number(self.pacman.score, 2, hud_y + 3, 2, TEXT);
+        let spare = self.pacman.lives.saturating_sub(1) as usize;
+        for i in 0..spare.min(w / 2) {
+            let cx = ((w - 1 - 2 * i) * TILE_PX) as f64;
+            let cy = hud_y as f64 + TILE_PX as f64;
+            fb.fill_disc(cx, cy, 6.0, PACMAN, |dx, dy| {
+                !in_mouth(dx, dy, PI, PI / 4.0)
+            });
+        }
+    }
+}
+
+/// Whether (dx, dy) lies in a mouth facing `facing` (radians, 0 = right,
+/// y down) that's `half` radians wide on each side.
+fn in_mouth(dx: f64, dy: f64, facing: f64, half: f64) -> bool {
+    let mut angle = dy.atan2(dx) - facing;
+    while angle > PI {
+        angle -= 2.0 * PI;
+    }
+    while angle < -PI {
+        angle += 2.0 * PI;
+    }
+    angle.abs() < half
+}
+
+fn draw_sprite(fb: &mut Framebuffer, c: &RenderCommand) {
+    let t = TILE_PX as f64;
+    let (cx, cy) = (c.x * t + t / 2.0, c.y * t + t / 2.0);
+    let r = t * 0.75;
+    match c.sprite {
+        Sprite::Dot => fb.fill_rect(cx as isize - 1, cy as isize - 1, 2, 2, DOT),
+        Sprite::PowerPellet => fb.fill_disc(cx, cy, t / 2.0, DOT, |_, _| true),
+        Sprite::WallBreaker | Sprite::Key | Sprite::TimeFreeze | Sprite::SpeedBoost => {
+            fb.fill_disc(cx, cy, t / 3.0, ITEM, |_, _| true)
+        }
+        Sprite::Fruit => fb.fill_disc(cx, cy, t / 2.0, FRUIT, |_, _| true),
+        Sprite::PacMan | Sprite::PacManUp => {
+            let facing = match (c.sprite, c.flip) {
+                (Sprite::PacMan, Flip::Horizontal) => PI,
+                (Sprite::PacMan, _) => 0.0,
+                (_, Flip::Vertical) => PI / 2.0,
+                _ => -PI / 2.0,
+            };
+            // Frames 0..MOUTH_FRAMES: closed, half open, open, half open
+            let opening = [0.0, 0.5, 1.0, 0.5][(c.frame % MOUTH_FRAMES as u32) as usize];
+            let half = opening * PI / 4.0;
+            fb.fill_disc(cx, cy, r, PACMAN, |dx, dy| !in_mouth(dx, dy, facing, half));
+        }
+        Sprite::PacManDying => {
+            // The mouth opens all the way round, facing up
+            let half = PI * (c.frame + 1) as f64 / DEATH_FRAMES as f64;
+            fb.fill_disc(cx, cy, r, PACMAN, |dx, dy| {
+                !in_mouth(dx, dy, -PI / 2.0, half)
+            });
+        }
+        Sprite::Ghost => {
+            let look = if c.flip == Flip::Horizontal {
+                -1.0
+            } else {
+                1.0
+            };
+            draw_ghost(fb, cx, cy, GHOSTS[(c.frame / 2 % 4) as usize], c.frame % 2);
+            draw_eyes(fb, cx, cy, look, 0.0);
+        }
+        Sprite::FrightenedGhost => {
+            let color = if c.frame / 2 == 1 { FLASH } else { FRIGHTENED };
+            draw_ghost(fb, cx, cy, color, c.frame % 2);
+        }
+        Sprite::Eyes => {
+            let (lx, ly) = match c.frame {
+                0 => (0.0, -1.0),
+                1 => (0.0, 1.0),
+                2 => (-1.0, 0.0),
+                _ => (1.0, 0.0),
+            };
+            draw_eyes(fb, cx, cy, lx, ly);
+        }
+        Sprite::Actor => {
+            // Pac-Men are codes 0-1, every other actor is a Blinky
+            if c.frame <= 1 {
+                let facing = if c.flip == Flip::Horizontal { PI } else { 0.0 };
+                let size = if c.frame == 1 { 2.0 * r } else { r };
+                fb.fill_disc(cx, cy, size, PACMAN, |dx, dy| {
+                    !in_mouth(dx, dy, facing, PI / 4.0)
+                });
+            } else {
+                draw_ghost(fb, cx, cy, GHOSTS[0], 0);
+            }
+        }
+        Sprite::ScorePopup => {
+            let width = c.frame.to_string().len() as isize * 4 - 1;
+            fb.draw_number(c.frame, cx as isize - width / 2, cy as isize - 2, 1, POPUP);
+        }
+    }
+}
+
+/// A ghost body: round top, square skirt with wiggling feet.
+fn draw_ghost(fb: &mut Framebuffer, cx: f64, cy: f64, color: Rgb, wiggle: u32) {
+    let r = TILE_PX as f64 * 0.75;
+    fb.fill_disc(cx, cy - 1.0, r, color, |_, dy| dy <= 0.0);
+    let (left, top) = ((cx - r) as isize, (cy - 1.0) as isize);
+    let width = (2.0 * r) as isize;
+    fb.fill_rect(left, top, width, (r * 0.6) as isize, color);
+    // Feet: every other pixel pair, shifted by the wiggle frame
+    let feet_y = top + (r * 0.6) as isize;
+    for x in 0..width {
+        if (x / 2 + wiggle as isize) % 2 == 0 {
+            fb.fill_rect(left + x, feet_y, 1, 2, color);
+        }
+    }
+}
+
+/// Two eyes with pupils shifted by (lx, ly).
+fn draw_eyes(fb: &mut Framebuffer, cx: f64, cy: f64, lx: f64, ly: f64) {
+    for side in [-1.0, 1.0] {
+        let (ex, ey) = (cx + side * 2.5, cy - 2.0);
+        fb.fill_disc(ex, ey, 2.0, EYE_WHITE, |_, _| true);
+        fb.fill_disc(ex + lx, ey + ly, 1.0, PUPIL, |_, _| true);
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::GameMode;
+
+    #[test]
+    fn frame_shows_walls_dots_and_pacman() {
+        let state = GameStateInner::new(GameMode::Classic);
+        let mut fb = Framebuffer::default();
+        state.render_into(&mut fb);
+
+        let (w, h) = (state.maze.width, state.maze.height);
+        assert_eq!(
+            (fb.width, fb.height),
+            (w * TILE_PX, (h + HUD_TILES) * TILE_PX)
+        );
+        assert_eq!(fb.pixels.len(), fb.width * fb.height * 4);
+
+        let center =
+            |col: usize, row: usize| (col * TILE_PX + TILE_PX / 2, row * TILE_PX + TILE_PX / 2);
+        let (x, y) = center(0, 0);
+        assert_eq!(fb.pixel(x, y), WALL);
+        let (x, y) = center(1, 1);
+        assert_eq!(fb.pixel(x, y), DOT);
+        // Pac-Man at (14, 23), mouth closed on the READY screen
+        let (x, y) = center(14, 23);
+        assert_eq!(fb.pixel(x, y), PACMAN);
+    }
+}
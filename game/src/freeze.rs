@@ -0,0 +1,103 @@
+// game/src/freeze.rs
+//
+// The time-freeze power-up.
+//
+// A `CellType::TimeFreeze` tile ('f' in layouts) stops every ghost where it
+// stands for `FREEZE_SECONDS`: in the maze, in the house, or heading home
+// as eyes. Unlike a power pellet it doesn't frighten anyone, so a frozen
+// ghost is still deadly to touch and can't be eaten; it just can't chase.
+// One timer covers all the ghosts (`GameStateInner::freeze_timer`), and a
+// second pickup restarts it rather than adding to it.
+//
+// The renderer hears about it twice: `GhostsFrozen` when the tile is eaten
+// and `GhostsThawed` when the ghosts move again.
+
+use crate::events::GameEvent;
+use crate::state::GameStateInner;
+
+// ─── Time freeze ────────────────────────────────────────────────────────────
+
+/// Seconds the ghosts stay frozen after a pickup.
+pub const FREEZE_SECONDS: f64 = 4.0;
+
+impl GameStateInner {
+    /// Whether the ghosts are held by a time freeze.
+    pub fn ghosts_frozen(&self) -> bool {
+        self.freeze_timer > 0.0
+    }
+
+    /// Pac-Man ate the time-freeze at (row, col): stop the ghosts.
+    pub(crate) fn freeze_ghosts(&mut self, row: usize, col: usize) {
+        self.freeze_timer = FREEZE_SECONDS;
+        self.events.push(GameEvent::GhostsFrozen {
+            row,
+            col,
+            seconds: FREEZE_SECONDS,
+        });
+    }
+
+    /// Run the freeze down, thawing the ghosts when it runs out.
+    pub(crate) fn update_freeze(&mut self, dt: f64) {
+        if !self.ghosts_frozen() {
+            return;
+        }
+        self.freeze_timer = (self.freeze_timer - dt).max(0.0);
+        if !self.ghosts_frozen() {
+            self.events.push(GameEvent::GhostsThawed);
+        }
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::GhostMode;
+    use crate::maze::CellType;
+    use crate::state::{GameMode, GamePhase};
+
+    #[test]
+    fn eating_a_time_freeze_stops_the_ghosts() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        let (col, row) = state.pacman.position.to_grid();
+        state.maze.set_cell(row, col, CellType::TimeFreeze);
+        state.tick(1.0 / 60.0);
+
+        assert_eq!(state.maze.get_cell(row, col), Some(CellType::Empty));
+        assert!(state.events.contains(&GameEvent::GhostsFrozen {
+            row,
+            col,
+            seconds: FREEZE_SECONDS,
+        }));
+        let spots = |s: &GameStateInner| -> Vec<(f64, f64)> {
+            s.ghosts
+                .iter()
+                .map(|g| (g.position.x, g.position.y))
+                .collect()
+        };
+        let before = spots(&state);
+        for _ in 0..60 {
+            state.tick(1.0 / 60.0);
+        }
+        assert_eq!(spots(&state), before);
+        // Not frightened: still dangerous, and not worth points
+        assert!(state.ghosts.iter().all(|g| g.mode != GhostMode::Frightened));
+
+        state.freeze_timer = 0.01;
+        state.tick(1.0 / 60.0);
+        assert!(!state.ghosts_frozen());
+        assert!(state.events.contains(&GameEvent::GhostsThawed));
+    }
+
+    #[test]
+    fn frozen_ghosts_still_catch_pacman() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        state.freeze_timer = FREEZE_SECONDS;
+        state.ghosts[0].position = state.pacman.position.clone();
+        state.tick(1.0 / 60.0);
+        assert_eq!(state.phase, GamePhase::Dying);
+    }
+}
@@ -0,0 +1,125 @@
+// game/src/fruit.rs
+//
+// Bonus fruit.
+//
+// Twice per level — after 70 and after 170 dots — a fruit appears below
+// the ghost house (the maze's `fruit_spawn`) for about nine seconds. Its type and value depend on
+// the level, as in the arcade game.
+
+use crate::entities::{Fruit, Position};
+use crate::events::GameEvent;
+use crate::state::GameStateInner;
+
+// ─── Spawn rules ────────────────────────────────────────────────────────────
+
+/// Dots eaten (in the current level) that make a fruit appear.
+pub const FRUIT_DOT_THRESHOLDS: [u32; 2] = [70, 170];
+/// Seconds a fruit stays before disappearing uneaten.
+pub const FRUIT_LIFETIME: f64 = 9.5;
+
+impl GameStateInner {
+    /// Count an eaten dot or pellet toward the next fruit.
+    pub(crate) fn count_fruit_dot(&mut self) {
+        self.dots_eaten += 1;
+        if FRUIT_DOT_THRESHOLDS.contains(&self.dots_eaten) {
+            let (x, y) = self.maze.metadata.fruit_spawn;
+            let kind = self.fruit_for_level(self.level);
+            self.fruit = Some(Fruit {
+                kind,
+                position: Position::new(x, y),
+                remaining: FRUIT_LIFETIME,
+            });
+            self.events.push(GameEvent::FruitSpawned {
+                kind,
+                row: y as usize,
+                col: x as usize,
+            });
+        }
+    }
+
+    /// Age the active fruit and let Pac-Man eat it.
+    pub(crate) fn update_fruit(&mut self, dt: f64) {
+        let Some(fruit) = self.fruit.as_mut() else {
+            return;
+        };
+
+        if fruit.position.to_grid() == self.pacman.position.to_grid() {
+            let points = self.config.scoring.fruit_points(fruit.kind);
+            self.pacman.score = self.pacman.score.saturating_add(points);
+            self.score_breakdown.fruit = self.score_breakdown.fruit.saturating_add(points);
+            self.events.push(GameEvent::FruitEaten {
+                kind: fruit.kind,
+                points,
+            });
+            self.fruit = None;
+            return;
+        }
+
+        fruit.remaining -= dt;
+        if fruit.remaining <= 0.0 {
+            self.fruit = None;
+        }
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::FruitKind;
+    use crate::state::{GameMode, GamePhase};
+
+    fn playing() -> GameStateInner {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        state
+    }
+
+    #[test]
+    fn fruit_appears_at_70_and_170_dots() {
+        let mut state = playing();
+        for _ in 0..69 {
+            state.count_fruit_dot();
+        }
+        assert!(state.fruit.is_none());
+        state.count_fruit_dot();
+        let fruit = state.fruit.as_ref().unwrap();
+        assert_eq!(fruit.kind, FruitKind::Cherry);
+        assert_eq!(fruit.position.to_grid(), (14, 17));
+
+        state.fruit = None;
+        for _ in 70..170 {
+            state.count_fruit_dot();
+        }
+        assert!(state.fruit.is_some());
+    }
+
+    #[test]
+    fn uneaten_fruit_expires() {
+        let mut state = playing();
+        state.dots_eaten = 69;
+        state.count_fruit_dot();
+        state.update_fruit(FRUIT_LIFETIME - 0.5);
+        assert!(state.fruit.is_some());
+        state.update_fruit(1.0);
+        assert!(state.fruit.is_none());
+    }
+
+    #[test]
+    fn eating_fruit_scores_by_level() {
+        let mut state = playing();
+        state.level = 3;
+        state.dots_eaten = 69;
+        state.count_fruit_dot();
+        state.pacman.position = Position::new(14.0, 17.0);
+        state.update_fruit(0.016);
+
+        assert!(state.fruit.is_none());
+        assert_eq!(state.pacman.score, 500);
+        assert!(state.events.contains(&GameEvent::FruitEaten {
+            kind: FruitKind::Orange,
+            points: 500,
+        }));
+    }
+}
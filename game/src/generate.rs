@@ -0,0 +1,410 @@
+// game/src/generate.rs
+//
+// Procedural mazes.
+//
+// `Maze::generate(seed, params)` lays out a fresh classic-style board: left
+// and right mirror each other, every corridor is one tile wide, the ghost
+// house sits in the middle with a ring corridor around it, a tunnel runs
+// out of each side at house level, and there's a power pellet near each
+// corner. The same seed always gives the same maze.
+//
+// The left half is a lattice: junctions on the odd rows and columns, and
+// between two neighbouring junctions either a wall tile or a corridor
+// tile. The generator
+//
+// 1. opens the corridors the house ring and the spawn row need,
+// 2. joins every junction with a random spanning tree (so the board is in
+//    one piece),
+// 3. opens another corridor at every dead end, since Pac-Man mazes have
+//    none,
+// 4. knocks through a share (`GeneratorParams::loops`) of the walls left,
+//
+// then mirrors the half and places the house and the landmark markers
+// (see `MazeMetadata::from_rows`). Spawn points land where the classic
+// ones are, so a default-sized board plays like the classic one. Each
+// board is checked with `Maze::validate` before it's handed out.
+//
+// `MazePolicy::Generated` plays a new one every level.
+
+use crate::maze::{Maze, MAZE_HEIGHT, MAZE_WIDTH};
+use crate::rng::Rng;
+
+// ─── Parameters ─────────────────────────────────────────────────────────────
+
+/// Smallest board the generator lays out.
+pub const MIN_GENERATED_WIDTH: usize = 20;
+pub const MIN_GENERATED_HEIGHT: usize = 23;
+
+/// Boards to try before giving up on a seed.
+const ATTEMPTS: u32 = 16;
+
+/// The shape of a generated maze.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneratorParams {
+    /// Board width in tiles, rounded down to a multiple of 4 (at least
+    /// `MIN_GENERATED_WIDTH`).
+    pub width: usize,
+    /// Board height in tiles, rounded down to one less than a multiple of
+    /// 4 (at least `MIN_GENERATED_HEIGHT`).
+    pub height: usize,
+    /// Share (0–1) of the walls between junctions to knock through once
+    /// the board is joined up. More loops, more escape routes.
+    pub loops: f64,
+}
+
+impl Default for GeneratorParams {
+    fn default() -> Self {
+        GeneratorParams {
+            width: MAZE_WIDTH,
+            height: MAZE_HEIGHT,
+            loops: 0.1,
+        }
+    }
+}
+
+// ─── Plan ───────────────────────────────────────────────────────────────────
+
+/// A corridor between two junctions on the left half: the tiles between
+/// them. A crossing joins a junction next to the middle to its own mirror
+/// image, so both its ends are the same.
+struct Link {
+    ends: [(usize, usize); 2],
+    tiles: Vec<(usize, usize)>,
+}
+
+/// Where everything goes on a board of one size. Tiles are (row, col).
+struct Plan {
+    width: usize,
+    height: usize,
+    /// The first column of the right half.
+    center: usize,
+    /// Top row of the ring corridor around the house; its bottom row is 6
+    /// below, the house between them.
+    ring_top: usize,
+    /// The ring corridor's left column.
+    ring_col: usize,
+}
+
+impl Plan {
+    fn new(params: &GeneratorParams) -> Plan {
+        let width = params.width.max(MIN_GENERATED_WIDTH) / 4 * 4;
+        let height = params.height.max(MIN_GENERATED_HEIGHT);
+        let height = height - (height + 1) % 4;
+        Plan {
+            width,
+            height,
+            center: width / 2,
+            ring_top: (height - 1) / 2 - 4,
+            ring_col: width / 2 - 5,
+        }
+    }
+
+    /// The row Pac-Man starts on, below the house.
+    fn spawn_row(&self) -> usize {
+        self.ring_top + 12
+    }
+
+    /// Whether (row, col) on the left half is a junction. Beside the house
+    /// only the ring has them: the tunnel runs between the two rows there.
+    fn is_junction(&self, row: usize, col: usize) -> bool {
+        let beside_house = row == self.ring_top + 2 || row == self.ring_top + 4;
+        row % 2 == 1
+            && col % 2 == 1
+            && row < self.height - 1
+            && col <= self.center - 3
+            && (!beside_house || col == self.ring_col)
+    }
+
+    fn junctions(&self) -> Vec<(usize, usize)> {
+        (0..self.height)
+            .flat_map(|row| (0..self.center).map(move |col| (row, col)))
+            .filter(|&(row, col)| self.is_junction(row, col))
+            .collect()
+    }
+
+    /// Every corridor the lattice could have.
+    fn links(&self) -> Vec<Link> {
+        let mut links = Vec::new();
+        for (row, col) in self.junctions() {
+            if self.is_junction(row, col + 2) {
+                links.push(Link {
+                    ends: [(row, col), (row, col + 2)],
+                    tiles: vec![(row, col + 1)],
+                });
+            }
+            if self.is_junction(row + 2, col) {
+                links.push(Link {
+                    ends: [(row, col), (row + 2, col)],
+                    tiles: vec![(row + 1, col)],
+                });
+            }
+            if col == self.center - 3 {
+                links.push(Link {
+                    ends: [(row, col); 2],
+                    tiles: vec![(row, col + 1), (row, col + 2)],
+                });
+            }
+        }
+        links
+    }
+
+    /// Whether `link` is part of the house ring or crosses the spawn row.
+    fn is_fixed(&self, link: &Link) -> bool {
+        let (top, bottom, col) = (self.ring_top, self.ring_top + 6, self.ring_col);
+        let [(r0, c0), (r1, c1)] = link.ends;
+        let ring_side = c0 == col && c1 == col && r0 >= top && r1 <= bottom;
+        let ring_edge = (r0 == top || r0 == bottom) && r0 == r1 && c0 >= col;
+        let spawn = link.ends[0] == link.ends[1] && r0 == self.spawn_row();
+        ring_side || ring_edge || spawn
+    }
+}
+
+// ─── Generation ─────────────────────────────────────────────────────────────
+
+impl Maze {
+    /// A new classic-style maze from `seed` (see the top of `generate.rs`).
+    /// Falls back to the classic maze in the unlikely case no board the
+    /// seed gives passes `validate`.
+    pub fn generate(seed: u32, params: &GeneratorParams) -> Maze {
+        let plan = Plan::new(params);
+        let mut rng = Rng::new(seed);
+        for _ in 0..ATTEMPTS {
+            let maze = plan.build(&mut rng, params.loops);
+            if maze.validate().is_valid() {
+                return maze;
+            }
+        }
+        Maze::new()
+    }
+}
+
+impl Plan {
+    fn build(&self, rng: &mut Rng, loops: f64) -> Maze {
+        let links = self.links();
+        let open = self.open_links(&links, rng, loops);
+
+        // The left half, then its mirror image
+        let (w, h) = (self.width, self.height);
+        let mut grid = vec![vec!['W'; w]; h];
+        for (row, col) in self.junctions() {
+            grid[row][col] = '.';
+        }
+        for (link, _) in links.iter().zip(&open).filter(|(_, &open)| open) {
+            for &(row, col) in &link.tiles {
+                grid[row][col] = '.';
+            }
+        }
+        grid[self.ring_top + 3][..self.ring_col].fill('E');
+        grid[3][1] = 'o';
+        grid[h - 4][1] = 'o';
+        for row in grid.iter_mut() {
+            for col in 0..self.center {
+                row[w - 1 - col] = row[col];
+            }
+        }
+
+        // The house, and landmarks on the middle column (the tile beside
+        // each is emptied too, to keep the board symmetric)
+        let (top, left) = (self.ring_top, self.center - 4);
+        let house = ["WWW--WWW", "WGGGGGGW", "WGGGGGGW", "WGGGGGGW", "WWWWWWWW"];
+        for (i, line) in house.iter().enumerate() {
+            for (j, ch) in line.chars().enumerate() {
+                grid[top + 1 + i][left + j] = ch;
+            }
+        }
+        for (row, ch) in [(top, 'b'), (top + 6, 'F'), (self.spawn_row(), '@')] {
+            grid[row][self.center - 1] = 'E';
+            grid[row][self.center] = ch;
+        }
+        grid[top + 3][self.center - 2] = 'p';
+        grid[top + 3][self.center] = 'i';
+        grid[top + 3][self.center + 2] = 'c';
+
+        let rows: Vec<String> = grid.into_iter().map(String::from_iter).collect();
+        let rows: Vec<&str> = rows.iter().map(String::as_str).collect();
+        let mut maze = Maze::from_rows(&rows, w);
+        let (w, h) = (w as isize, h as isize);
+        maze.metadata.scatter_targets = [(w - 3, -3), (2, -3), (w - 1, h), (0, h)];
+        maze
+    }
+
+    /// Which of `links` are corridors rather than walls.
+    fn open_links(&self, links: &[Link], rng: &mut Rng, loops: f64) -> Vec<bool> {
+        let mut state = Corridors {
+            links,
+            width: self.width,
+            open: vec![false; links.len()],
+            degree: vec![0; self.width * self.height],
+            parent: (0..self.width * self.height).collect(),
+        };
+
+        // 1. The ring and the spawn row
+        for (i, link) in links.iter().enumerate() {
+            if self.is_fixed(link) {
+                state.join(i);
+            }
+        }
+        // 2. A random spanning tree (crossings join nothing on this half)
+        let mut order: Vec<usize> = (0..links.len()).collect();
+        for i in (1..order.len()).rev() {
+            order.swap(i, rng.below(i as u32 + 1) as usize);
+        }
+        for &i in &order {
+            let [a, b] = links[i].ends;
+            if !state.open[i] && state.root(a) != state.root(b) {
+                state.join(i);
+            }
+        }
+        // 3. No dead ends
+        for junction in self.junctions() {
+            if state.degree[junction.0 * self.width + junction.1] != 1 {
+                continue;
+            }
+            let closed: Vec<usize> = (0..links.len())
+                .filter(|&i| !state.open[i] && links[i].ends.contains(&junction))
+                .collect();
+            if !closed.is_empty() {
+                state.join(closed[rng.below(closed.len() as u32) as usize]);
+            }
+        }
+        // 4. Loops
+        let chance = (loops.clamp(0.0, 1.0) * 1000.0) as u32;
+        for &i in &order {
+            if !state.open[i] && rng.below(1000) < chance {
+                state.join(i);
+            }
+        }
+        state.open
+    }
+}
+
+/// The corridors opened so far, with each junction's count of them and
+/// which piece of the board it's in (union–find, by tile index).
+struct Corridors<'a> {
+    links: &'a [Link],
+    width: usize,
+    open: Vec<bool>,
+    degree: Vec<u32>,
+    parent: Vec<usize>,
+}
+
+impl Corridors<'_> {
+    fn root(&mut self, (row, col): (usize, usize)) -> usize {
+        let mut i = row * self.width + col;
+        while self.parent[i] != i {
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    fn join(&mut self, link: usize) {
+        self.open[link] = true;
+        let [a, b] = self.links[link].ends;
+        self.degree[a.0 * self.width + a.1] += 1;
+        if a != b {
+            self.degree[b.0 * self.width + b.1] += 1;
+        }
+        let (ra, rb) = (self.root(a), self.root(b));
+        self.parent[ra] = rb;
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GameConfig;
+    use crate::layouts::MazePolicy;
+    use crate::maze::{CellType, MazeMetadata};
+    use crate::state::{GameMode, GamePhase, GameStateInner};
+
+    /// Open neighbours of (row, col), tunnels wrapping around the sides.
+    fn exits(maze: &Maze, row: usize, col: usize) -> usize {
+        let w = maze.width;
+        [
+            (row.wrapping_sub(1), col),
+            (row + 1, col),
+            (row, (col + w - 1) % w),
+            (row, (col + 1) % w),
+        ]
+        .into_iter()
+        .filter(|&(r, c)| !matches!(maze.get_cell(r, c), None | Some(CellType::Wall)))
+        .count()
+    }
+
+    #[test]
+    fn generated_mazes_are_classic_style() {
+        for seed in 0..20 {
+            let maze = Maze::generate(seed, &GeneratorParams::default());
+            assert!(maze.validate().is_valid(), "seed {}", seed);
+            assert_eq!((maze.width, maze.height), (MAZE_WIDTH, MAZE_HEIGHT));
+            assert_eq!(maze.positions_of(CellType::PowerPellet).len(), 4);
+            assert_eq!(maze.tunnels.len() % 2, 0);
+            assert!(!maze.tunnels.is_empty());
+            assert_eq!(maze.metadata, MazeMetadata::default());
+            for (row, cells) in maze.cells.iter().enumerate() {
+                let mirrored: Vec<CellType> = cells.iter().rev().copied().collect();
+                assert_eq!(*cells, mirrored, "seed {} row {}", seed, row);
+                for (col, &cell) in cells.iter().enumerate() {
+                    if cell != CellType::Wall {
+                        assert!(exits(&maze, row, col) >= 2, "dead end at {:?}", (row, col));
+                    }
+                }
+            }
+        }
+        let params = GeneratorParams::default();
+        assert_eq!(
+            Maze::generate(7, &params).cells,
+            Maze::generate(7, &params).cells
+        );
+        assert_ne!(
+            Maze::generate(7, &params).cells,
+            Maze::generate(8, &params).cells
+        );
+    }
+
+    #[test]
+    fn sizes_are_rounded_to_fit_the_lattice() {
+        let params = GeneratorParams {
+            width: 34,
+            height: 30,
+            loops: 1.0,
+        };
+        let maze = Maze::generate(3, &params);
+        assert_eq!((maze.width, maze.height), (32, 27));
+        assert!(maze.validate().is_valid());
+
+        let tiny = GeneratorParams {
+            width: 0,
+            height: 0,
+            loops: 0.0,
+        };
+        let maze = Maze::generate(3, &tiny);
+        assert_eq!(
+            (maze.width, maze.height),
+            (MIN_GENERATED_WIDTH, MIN_GENERATED_HEIGHT)
+        );
+        assert!(maze.validate().is_valid());
+    }
+
+    #[test]
+    fn the_generated_policy_plays_a_new_maze_each_level() {
+        let config = GameConfig {
+            maze_policy: MazePolicy::Generated,
+            seed: 5,
+            ..GameConfig::default()
+        };
+        let mut state = GameStateInner::with_config(GameMode::Classic, config);
+        let first = Maze::generate(6, &GeneratorParams::default());
+        assert_eq!(state.maze.cells, first.cells);
+        assert_eq!(state.dots_remaining, first.dots_remaining());
+
+        state.phase = GamePhase::Playing;
+        state.advance_level();
+        assert_ne!(state.maze.cells, first.cells);
+        state.reset();
+        assert_eq!(state.maze.cells, first.cells);
+    }
+}
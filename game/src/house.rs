@@ -0,0 +1,240 @@
+// game/src/house.rs
+//
+// Releasing ghosts from the ghost house.
+//
+// Pinky, Inky, and Clyde start inside the house. As in the arcade game,
+// each waits for its own dot counter to reach a per-level limit, counting
+// only while it is the "preferred" ghost (the first one still inside, in
+// the order Pinky → Inky → Clyde). Two extra rules keep things moving:
+//
+// - Fallback timer: if Pac-Man goes too long without eating a dot, the
+//   preferred ghost is released anyway.
+// - Global counter: after Pac-Man dies, the personal counters are ignored
+//   and one shared counter releases ghosts at 7, 17, and 32 dots.
+//
+// A released ghost is `LeavingHouse`: it glides to the door column and out
+// through the door, ignoring walls, and then joins normal movement. Eaten
+// ghosts come back in through the door, revive at the house center, and
+// leave again the same way. Where the door and center are is up to the
+// maze (`MazeMetadata`); in the classic one the door is on top.
+
+use crate::chaos::CHAOS_RELEASE_INTERVAL;
+use crate::entities::{Direction, GhostType, HouseState};
+use crate::state::GameStateInner;
+
+// ─── Release rules ──────────────────────────────────────────────────────────
+
+/// Ghost speed inside the house, in tiles per second.
+pub const HOUSE_SPEED: f64 = 4.0;
+
+/// Dots the preferred ghost must count before leaving on `level`.
+pub fn dot_limit(ghost_type: GhostType, level: u32) -> u32 {
+    match (ghost_type, level) {
+        (GhostType::Inky, 1) => 30,
+        (GhostType::Clyde, 1) => 60,
+        (GhostType::Clyde, 2) => 50,
+        _ => 0,
+    }
+}
+
+/// Seconds without eating a dot before the preferred ghost is released.
+pub fn release_timeout(level: u32) -> f64 {
+    if level < 5 {
+        4.0
+    } else {
+        3.0
+    }
+}
+
+/// Global-counter value that releases `ghost_type` after a death.
+fn global_limit(ghost_type: GhostType) -> u32 {
+    match ghost_type {
+        GhostType::Pinky => 7,
+        GhostType::Inky => 17,
+        GhostType::Clyde | GhostType::Blinky => 32,
+    }
+}
+
+impl GameStateInner {
+    /// Index of the ghost whose dot counter is currently running: the
+    /// first one still inside, in roster order (Pinky, Inky, Clyde in the
+    /// classic roster).
+    fn preferred_house_ghost(&self) -> Option<usize> {
+        self.ghosts
+            .iter()
+            .position(|g| g.house == HouseState::InHouse)
+    }
+
+    /// Count a dot or power pellet Pac-Man just ate toward ghost release.
+    pub(crate) fn count_house_dot(&mut self) {
+        if self.config.chaos {
+            return; // Released on a timer alone
+        }
+        self.house_dot_timer = 0.0;
+        match self.global_dot_counter.as_mut() {
+            Some(counter) => {
+                *counter += 1;
+                let count = *counter;
+                if let Some(i) = self.preferred_house_ghost() {
+                    let ghost_type = self.ghosts[i].ghost_type;
+                    if count >= global_limit(ghost_type) {
+                        self.ghosts[i].house = HouseState::LeavingHouse;
+                    }
+                    // Clyde still inside at 32 switches back to personal counters
+                    if ghost_type == GhostType::Clyde && count >= global_limit(ghost_type) {
+                        self.global_dot_counter = None;
+                    }
+                }
+            }
+            None => {
+                if let Some(i) = self.preferred_house_ghost() {
+                    self.ghosts[i].dot_counter += 1;
+                }
+            }
+        }
+    }
+
+    /// Release ghosts whose time has come and move the ones leaving.
+    pub(crate) fn update_house(&mut self, dt: f64) {
+        self.house_dot_timer += dt;
+        if self.config.chaos {
+            if let Some(i) = self.preferred_house_ghost() {
+                if self.house_dot_timer >= CHAOS_RELEASE_INTERVAL {
+                    self.ghosts[i].house = HouseState::LeavingHouse;
+                    self.house_dot_timer = 0.0;
+                }
+            }
+        } else if let Some(i) = self.preferred_house_ghost() {
+            let ghost = &self.ghosts[i];
+            // Simplified rules skip the counters: ghosts file out one by one
+            let counter_done = !self.config.classic_rules()
+                || (self.global_dot_counter.is_none()
+                    && ghost.dot_counter >= dot_limit(ghost.ghost_type, self.level));
+            if counter_done || self.house_dot_timer >= release_timeout(self.level) {
+                self.ghosts[i].house = HouseState::LeavingHouse;
+                if !counter_done {
+                    self.house_dot_timer = 0.0;
+                }
+            }
+        }
+
+        let step = HOUSE_SPEED * dt;
+        let (exit_x, exit_y) = self.maze.metadata.house_exit;
+        for ghost in &mut self.ghosts {
+            if ghost.house != HouseState::LeavingHouse || ghost.frozen {
+                continue;
+            }
+            let pos = &mut ghost.position;
+            if pos.x != exit_x {
+                // Line up with the door first...
+                let dx = (exit_x - pos.x).clamp(-step, step);
+                pos.x += dx;
+                ghost.direction = if dx < 0.0 {
+                    Direction::Left
+                } else {
+                    Direction::Right
+                };
+            } else {
+                // ...then go out through it
+                let dy = (exit_y - pos.y).clamp(-step, step);
+                pos.y += dy;
+                ghost.direction = if dy > 0.0 {
+                    Direction::Down
+                } else {
+                    Direction::Up
+                };
+                if pos.y == exit_y {
+                    ghost.house = HouseState::Outside;
+                    ghost.direction = Direction::Left;
+                    ghost.next_direction = Direction::Left;
+                }
+            }
+        }
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maze::CellType;
+    use crate::state::{GameMode, GamePhase};
+
+    fn playing() -> GameStateInner {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        state
+    }
+
+    fn house(state: &GameStateInner, ghost_type: GhostType) -> HouseState {
+        state
+            .ghosts
+            .iter()
+            .find(|g| g.ghost_type == ghost_type)
+            .unwrap()
+            .house
+    }
+
+    #[test]
+    fn pinky_leaves_at_once_and_reaches_the_exit() {
+        let mut state = playing();
+        assert_eq!(house(&state, GhostType::Pinky), HouseState::InHouse);
+
+        for _ in 0..120 {
+            state.update_house(1.0 / 60.0);
+        }
+        let pinky = &state.ghosts[1];
+        assert_eq!(pinky.house, HouseState::Outside);
+        assert_eq!(pinky.position.to_grid(), (14, 11));
+        assert_eq!(house(&state, GhostType::Inky), HouseState::InHouse);
+    }
+
+    #[test]
+    fn inky_waits_for_thirty_dots_on_level_one() {
+        let mut state = playing();
+        state.ghosts[1].house = HouseState::Outside; // Pinky already out
+
+        for _ in 0..29 {
+            state.count_house_dot();
+        }
+        state.update_house(0.016);
+        assert_eq!(house(&state, GhostType::Inky), HouseState::InHouse);
+
+        state.count_house_dot();
+        state.update_house(0.016);
+        assert_eq!(house(&state, GhostType::Inky), HouseState::LeavingHouse);
+        // Clyde's counter only starts now
+        assert_eq!(state.ghosts[3].dot_counter, 0);
+    }
+
+    #[test]
+    fn fallback_timer_releases_without_dots() {
+        let mut state = playing();
+        state.ghosts[1].house = HouseState::Outside;
+
+        state.update_house(release_timeout(1) - 0.1);
+        assert_eq!(house(&state, GhostType::Inky), HouseState::InHouse);
+        state.update_house(0.2);
+        assert_eq!(house(&state, GhostType::Inky), HouseState::LeavingHouse);
+    }
+
+    #[test]
+    fn global_counter_takes_over_after_death() {
+        let mut state = playing();
+        state.global_dot_counter = Some(0);
+        state.ghosts[1].house = HouseState::Outside;
+
+        // Eating a dot in play feeds the shared counter, not Inky's own
+        for _ in 0..17 {
+            state.count_house_dot();
+        }
+        assert_eq!(house(&state, GhostType::Inky), HouseState::LeavingHouse);
+        assert_eq!(state.ghosts[2].dot_counter, 0);
+
+        // And the hook is wired into dot eating
+        state.maze.cells[23][14] = CellType::Dot;
+        state.tick(0.016);
+        assert_eq!(state.global_dot_counter, Some(18));
+    }
+}
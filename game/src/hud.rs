@@ -0,0 +1,93 @@
+// game/src/hud.rs
+//
+// HUD data in one small object.
+//
+// The score bar and the fruit row change far less than the world, and
+// need none of it: `get_hud()` gives them their own payload of a few
+// numbers, so the HUD can update without the snapshot.
+//
+// The high score is kept on the state and carries over `restart()`; JS
+// seeds it from storage with `set_high_score()`.
+
+use serde::Serialize;
+
+use crate::entities::FruitKind;
+use crate::state::{GamePhase, GameStateInner};
+
+// ─── HUD ────────────────────────────────────────────────────────────────────
+
+/// How many fruits the HUD's level row shows, newest last.
+pub const FRUIT_HISTORY: u32 = 7;
+
+/// The "1UP" label is shown, then hidden, for this long while playing, in
+/// seconds (sixteen arcade frames).
+pub const ONE_UP_BLINK_PERIOD: f64 = 16.0 / 60.0;
+
+/// Everything the HUD draws.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Hud {
+    pub score: u32,
+    pub high_score: u32,
+    /// Lives left, including the one in play.
+    pub lives: u8,
+    pub level: u32,
+    /// Level fruits for this level and the ones before it (up to
+    /// `FRUIT_HISTORY`), oldest first.
+    pub fruits: Vec<FruitKind>,
+    /// Whether the "1UP" label is lit this frame (it blinks during play).
+    pub one_up_visible: bool,
+}
+
+impl GameStateInner {
+    pub fn hud(&self) -> Hud {
+        let first = self.level.saturating_sub(FRUIT_HISTORY - 1).max(1);
+        let blink = ((self.global_timer / ONE_UP_BLINK_PERIOD) as u64).is_multiple_of(2);
+        Hud {
+            score: self.pacman.score,
+            high_score: self.high_score.max(self.pacman.score),
+            lives: self.pacman.lives,
+            level: self.level,
+            fruits: (first..=self.level)
+                .map(|level| self.fruit_for_level(level))
+                .collect(),
+            one_up_visible: self.phase != GamePhase::Playing || blink,
+        }
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::GameMode;
+
+    #[test]
+    fn fruit_row_shows_the_last_seven_levels() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        assert_eq!(state.hud().fruits, vec![FruitKind::Cherry]);
+
+        state.level = 9;
+        let fruits = state.hud().fruits;
+        assert_eq!(fruits.len(), 7);
+        assert_eq!(fruits[0], FruitKind::Orange);
+        assert_eq!(fruits[6], FruitKind::Galaxian);
+    }
+
+    #[test]
+    fn high_score_outlives_a_restart() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.pacman.score = 1234;
+        state.tick(1.0 / 60.0);
+        state.reset();
+        let hud = state.hud();
+        assert_eq!((hud.score, hud.high_score), (0, 1234));
+
+        // Blinks while playing, steady otherwise
+        state.phase = GamePhase::Playing;
+        state.global_timer = ONE_UP_BLINK_PERIOD * 1.5;
+        assert!(!state.hud().one_up_visible);
+        state.phase = GamePhase::UserPaused;
+        assert!(state.hud().one_up_visible);
+    }
+}
@@ -0,0 +1,181 @@
+// game/src/intermission.rs
+//
+// Intermission ("coffee break") cutscenes.
+//
+// After levels 2, 5, and 9 the arcade plays a short cartoon between
+// Pac-Man and Blinky. The scripts live here: each act is a function of
+// the time since the cutscene started that places a handful of actors on
+// the tunnel row. `tick()` refreshes `GameStateInner::cutscene` every frame
+// of `GamePhase::Intermission(act)`, so the frontend draws the actors and
+// never has to know the script.
+//
+// Each act has two halves of `INTERMISSION_DURATION / 2`: a chase to the
+// left, then the punchline coming back to the right.
+
+use serde::{Deserialize, Serialize};
+
+use crate::entities::Direction;
+use crate::state::{GamePhase, GameStateInner, INTERMISSION_AFTER_LEVELS, INTERMISSION_DURATION};
+
+// ─── Actors ─────────────────────────────────────────────────────────────────
+
+/// What a cutscene actor looks like.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ActorKind {
+    PacMan,
+    /// The giant Pac-Man from act 1
+    BigPacMan,
+    Blinky,
+    FrightenedBlinky,
+    /// Blinky with his cloak caught on the nail (act 2)
+    TornBlinky,
+    /// Blinky in his stitched-up cloak (act 3)
+    PatchedBlinky,
+    /// Blinky without a cloak, dragging it behind him (act 3)
+    NakedBlinky,
+}
+
+impl ActorKind {
+    /// Stable numeric code, for flat JS arrays.
+    pub fn code(self) -> u8 {
+        match self {
+            ActorKind::PacMan => 0,
+            ActorKind::BigPacMan => 1,
+            ActorKind::Blinky => 2,
+            ActorKind::FrightenedBlinky => 3,
+            ActorKind::TornBlinky => 4,
+            ActorKind::PatchedBlinky => 5,
+            ActorKind::NakedBlinky => 6,
+        }
+    }
+}
+
+/// One sprite in a cutscene frame, in tile coordinates like entities.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Actor {
+    pub kind: ActorKind,
+    pub x: f64,
+    pub y: f64,
+    pub direction: Direction,
+}
+
+// ─── Scripts ────────────────────────────────────────────────────────────────
+
+/// Row the cutscenes play on (the tunnel row, clear edge to edge).
+const STAGE_ROW: f64 = 14.0;
+/// Where actors enter on the right / leave on the left, and vice versa.
+const STAGE_RIGHT: f64 = 30.0;
+const STAGE_LEFT: f64 = -3.0;
+/// Where Blinky's cloak snags in act 2.
+const NAIL_X: f64 = 14.0;
+
+/// The act that follows `level`, if any (1, 2, or 3).
+pub fn intermission_act(level: u32) -> Option<u8> {
+    INTERMISSION_AFTER_LEVELS
+        .iter()
+        .position(|&l| l == level)
+        .map(|i| i as u8 + 1)
+}
+
+/// The actors of `act`, `elapsed` seconds into the cutscene.
+pub fn cutscene_actors(act: u8, elapsed: f64) -> Vec<Actor> {
+    let half = INTERMISSION_DURATION / 2.0;
+    let speed = (STAGE_RIGHT - STAGE_LEFT) / half;
+    let actor = |kind, x, direction| Actor {
+        kind,
+        x,
+        y: STAGE_ROW,
+        direction,
+    };
+
+    if elapsed < half {
+        // Chase to the left: Pac-Man in front, Blinky two tiles behind
+        let pac_x = STAGE_RIGHT - speed * elapsed;
+        let blinky_x = pac_x + 2.0;
+        let blinky = match act {
+            2 => actor(ActorKind::Blinky, blinky_x.max(NAIL_X), Direction::Left),
+            3 => actor(ActorKind::PatchedBlinky, blinky_x, Direction::Left),
+            _ => actor(ActorKind::Blinky, blinky_x, Direction::Left),
+        };
+        return vec![actor(ActorKind::PacMan, pac_x, Direction::Left), blinky];
+    }
+
+    let x = STAGE_LEFT + speed * (elapsed - half);
+    match act {
+        // Giant Pac-Man turns the tables
+        1 => vec![
+            actor(ActorKind::FrightenedBlinky, x, Direction::Right),
+            actor(ActorKind::BigPacMan, x - 3.0, Direction::Right),
+        ],
+        // Blinky stuck on the nail, cloak torn
+        2 => vec![actor(ActorKind::TornBlinky, NAIL_X, Direction::Up)],
+        // Blinky runs back dragging his cloak
+        _ => vec![actor(ActorKind::NakedBlinky, x, Direction::Right)],
+    }
+}
+
+impl GameStateInner {
+    /// Refresh `cutscene` for the current frame of an intermission.
+    pub(crate) fn update_cutscene(&mut self) {
+        self.cutscene = match self.phase {
+            GamePhase::Intermission(act) => {
+                cutscene_actors(act, INTERMISSION_DURATION - self.phase_timer)
+            }
+            _ => Vec::new(),
+        };
+    }
+
+    /// Cutscene actors flattened for JS as `[kind, x, y, direction, ...]`
+    /// (direction codes as in `Direction::code`).
+    pub fn cutscene_data(&self) -> Vec<f64> {
+        self.cutscene
+            .iter()
+            .flat_map(|a| [a.kind.code() as f64, a.x, a.y, a.direction.code() as f64])
+            .collect()
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{GameMode, LEVEL_COMPLETE_DURATION};
+
+    #[test]
+    fn acts_follow_levels_two_five_and_nine() {
+        assert_eq!(intermission_act(2), Some(1));
+        assert_eq!(intermission_act(5), Some(2));
+        assert_eq!(intermission_act(9), Some(3));
+        assert_eq!(intermission_act(3), None);
+    }
+
+    #[test]
+    fn act_one_chases_left_then_big_pacman_chases_back() {
+        let start = cutscene_actors(1, 0.0);
+        assert_eq!(start[0].kind, ActorKind::PacMan);
+        assert_eq!(start[0].direction, Direction::Left);
+        assert!(start[1].x > start[0].x);
+
+        let later = cutscene_actors(1, INTERMISSION_DURATION * 0.75);
+        assert_eq!(later[1].kind, ActorKind::BigPacMan);
+        assert!(later[0].x > later[1].x);
+    }
+
+    #[test]
+    fn state_plays_the_cutscene_during_intermission() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.level = 5;
+        state.phase = GamePhase::LevelComplete;
+        state.phase_timer = LEVEL_COMPLETE_DURATION;
+        state.tick(LEVEL_COMPLETE_DURATION);
+        assert_eq!(state.phase, GamePhase::Intermission(2));
+
+        state.tick(INTERMISSION_DURATION * 0.9);
+        assert_eq!(state.cutscene[0].kind, ActorKind::TornBlinky);
+        assert_eq!(state.cutscene_data().len(), 4);
+
+        state.tick(INTERMISSION_DURATION);
+        assert!(state.cutscene.is_empty());
+    }
+}
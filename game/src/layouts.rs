@@ -0,0 +1,278 @@
+// game/src/layouts.rs
+//
+// The built-in maze library, and which maze each level is played on.
+//
+// Besides the classic board there are a few more layouts (`MazeLayout`).
+// They all keep the classic middle — the ghost house, the side tunnels and
+// the fruit spot — and change the corridors above and below it, so the
+// classic `MazeMetadata` (spawn points, house, scatter corners) holds for
+// every one of them.
+//
+// `GameConfig::maze_policy` picks the layout for each level: always the
+// classic one (the default), each in turn, a seeded random pick, or a
+// freshly generated board (see `generate.rs`).
+// Campaigns and custom mazes bring their own boards and aren't affected
+// (`GameOptions` refuses a custom maze with any other policy). A mirror or
+// flip set in the config applies to every board the policy brings.
+
+use serde::{Deserialize, Serialize};
+
+use crate::generate::GeneratorParams;
+use crate::maze::{Maze, MAZE_HEIGHT, MAZE_WIDTH};
+use crate::rng::Rng;
+use crate::state::GameStateInner;
+
+// ─── Layouts ────────────────────────────────────────────────────────────────
+
+/// A maze from the built-in library.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum MazeLayout {
+    Classic,
+    /// Short pillars up top, a long open run across Pac-Man's row.
+    Arches,
+    /// Offset blocks with zig-zag side passages.
+    Spokes,
+}
+
+impl MazeLayout {
+    /// Every layout, in rotation order.
+    pub const ALL: [MazeLayout; 3] = [MazeLayout::Classic, MazeLayout::Arches, MazeLayout::Spokes];
+
+    /// The layout's rows, in the `CellType::from_char` legend.
+    pub fn rows(self) -> &'static [&'static str; MAZE_HEIGHT] {
+        match self {
+            MazeLayout::Classic => &CLASSIC,
+            MazeLayout::Arches => &ARCHES,
+            MazeLayout::Spokes => &SPOKES,
+        }
+    }
+}
+
+// Legend:
+//   W = Wall
+//   . = Dot
+//   o = Power Pellet
+//   G = Ghost House
+//   E = Empty (tunnels, ghost house entry)
+//
+// This is a simplified classic layout. Each string is one row (28 chars).
+const CLASSIC: [&str; MAZE_HEIGHT] = [
+    "WWWWWWWWWWWWWWWWWWWWWWWWWWWW", // 0
+    "W............WW............W", // 1
+    "W.WWWW.WWWWW.WW.WWWWW.WWWW.W", // 2
+    "WoWWWW.WWWWW.WW.WWWWW.WWWWoW", // 3
+    "W.WWWW.WWWWW.WW.WWWWW.WWWW.W", // 4
+    "W..........................W", // 5
+    "W.WWWW.WW.WWWWWWWW.WW.WWWW.W", // 6
+    "W.WWWW.WW.WWWWWWWW.WW.WWWW.W", // 7
+    "W......WW....WW....WW......W", // 8
+    "WWWWWW.WWWWW.WW.WWWWW.WWWWWW", // 9
+    "EEEEWW.WWWWW.WW.WWWWW.WWEEEE", // 10
+    "EEEEWW.WW..........WW.WWEEEE", // 11
+    "EEEEWW.WW.WWW--WWW.WW.WWEEEE", // 12 ← house door
+    "WWWWWW.WW.WEGGGGEW.WW.WWWWWW", // 13
+    "EEEEEE....WEGGGGEW....EEEEEE", // 14  ← tunnel row
+    "WWWWWW.WW.WEGGGGEW.WW.WWWWWW", // 15
+    "EEEEWW.WW.WWWWWWWW.WW.WWEEEE", // 16
+    "EEEEWW.WW..........WW.WWEEEE", // 17
+    "EEEEWW.WW.WWWWWWWW.WW.WWEEEE", // 18
+    "WWWWWW.WW.WWWWWWWW.WW.WWWWWW", // 19
+    "W............WW............W", // 20
+    "W.WWWW.WWWWW.WW.WWWWW.WWWW.W", // 21
+    "W.WWWW.WWWWW.WW.WWWWW.WWWW.W", // 22
+    "Wo..WW................WW..oW", // 23
+    "WWW.WW.WW.WWWWWWWW.WW.WW.WWW", // 24
+    "WWW.WW.WW.WWWWWWWW.WW.WW.WWW", // 25
+    "W......WW....WW....WW......W", // 26
+    "W.WWWWWWWWWW.WW.WWWWWWWWWW.W", // 27
+    "W.WWWWWWWWWW.WW.WWWWWWWWWW.W", // 28
+    "W..........................W", // 29
+    "WWWWWWWWWWWWWWWWWWWWWWWWWWWW", // 30
+];
+
+const ARCHES: [&str; MAZE_HEIGHT] = [
+    "WWWWWWWWWWWWWWWWWWWWWWWWWWWW", // 0
+    "W............WW............W", // 1
+    "W.WW.WWWW.WW.WW.WW.WWWW.WW.W", // 2
+    "WoWW.WWWW.WW.WW.WW.WWWW.WWoW", // 3
+    "W.WW.WWWW.WW.WW.WW.WWWW.WW.W", // 4
+    "W..........................W", // 5
+    "WWW.WW.WWW.WWWWWW.WWW.WW.WWW", // 6
+    "WWW.WW.WWW.WWWWWW.WWW.WW.WWW", // 7
+    "WWW..........WW..........WWW", // 8
+    "WWWWWW.WWWWW.WW.WWWWW.WWWWWW", // 9
+    "EEEEWW.WWWWW.WW.WWWWW.WWEEEE", // 10
+    "EEEEWW.WW..........WW.WWEEEE", // 11
+    "EEEEWW.WW.WWW--WWW.WW.WWEEEE", // 12 ← house door
+    "WWWWWW.WW.WEGGGGEW.WW.WWWWWW", // 13
+    "EEEEEE....WEGGGGEW....EEEEEE", // 14  ← tunnel row
+    "WWWWWW.WW.WEGGGGEW.WW.WWWWWW", // 15
+    "EEEEWW.WW.WWWWWWWW.WW.WWEEEE", // 16
+    "EEEEWW.WW..........WW.WWEEEE", // 17
+    "EEEEWW.WW.WWWWWWWW.WW.WWEEEE", // 18
+    "WWWWWW.WW.WWWWWWWW.WW.WWWWWW", // 19
+    "W............WW............W", // 20
+    "W.WW.WWWW.WW.WW.WW.WWWW.WW.W", // 21
+    "W.WW.WWWW.WW.WW.WW.WWWW.WW.W", // 22
+    "Wo........................oW", // 23
+    "WWW.WW.WWW.WWWWWW.WWW.WW.WWW", // 24
+    "WWW.WW.WWW.WWWWWW.WWW.WW.WWW", // 25
+    "W......WWW...WW...WWW......W", // 26
+    "W.WWWWWWWWWW.WW.WWWWWWWWWW.W", // 27
+    "W.WWWWWWWWWW.WW.WWWWWWWWWW.W", // 28
+    "W..........................W", // 29
+    "WWWWWWWWWWWWWWWWWWWWWWWWWWWW", // 30
+];
+
+const SPOKES: [&str; MAZE_HEIGHT] = [
+    "WWWWWWWWWWWWWWWWWWWWWWWWWWWW", // 0
+    "W.....W..............W.....W", // 1
+    "W.WWW.W.WWWW.WW.WWWW.W.WWW.W", // 2
+    "WoWWW...WWWW.WW.WWWW...WWWoW", // 3
+    "W.WWW.W.WWWW.WW.WWWW.W.WWW.W", // 4
+    "W..........................W", // 5
+    "W.WW.WWWWW.WWWWWW.WWWWW.WW.W", // 6
+    "W.WW.WWWWW.WWWWWW.WWWWW.WW.W", // 7
+    "W............WW............W", // 8
+    "WWWWWW.WWWWW.WW.WWWWW.WWWWWW", // 9
+    "EEEEWW.WWWWW.WW.WWWWW.WWEEEE", // 10
+    "EEEEWW.WW..........WW.WWEEEE", // 11
+    "EEEEWW.WW.WWW--WWW.WW.WWEEEE", // 12 ← house door
+    "WWWWWW.WW.WEGGGGEW.WW.WWWWWW", // 13
+    "EEEEEE....WEGGGGEW....EEEEEE", // 14  ← tunnel row
+    "WWWWWW.WW.WEGGGGEW.WW.WWWWWW", // 15
+    "EEEEWW.WW.WWWWWWWW.WW.WWEEEE", // 16
+    "EEEEWW.WW..........WW.WWEEEE", // 17
+    "EEEEWW.WW.WWWWWWWW.WW.WWEEEE", // 18
+    "WWWWWW.WW.WWWWWWWW.WW.WWWWWW", // 19
+    "W......WW....WW....WW......W", // 20
+    "W.WWWW.WWWWW.WW.WWWWW.WWWW.W", // 21
+    "W.WWWW.WWWWW.WW.WWWWW.WWWW.W", // 22
+    "Wo.WW..................WW.oW", // 23
+    "WW.WW.WWWW.WWWWWW.WWWW.WW.WW", // 24
+    "WW.WW.WWWW.WWWWWW.WWWW.WW.WW", // 25
+    "W.....W......WW......W.....W", // 26
+    "W.WWWWW.WWWW.WW.WWWW.WWWWW.W", // 27
+    "W.WWWWW.WWWW.WW.WWWW.WWWWW.W", // 28
+    "W..........................W", // 29
+    "WWWWWWWWWWWWWWWWWWWWWWWWWWWW", // 30
+];
+
+impl Maze {
+    /// A maze from the built-in library.
+    pub fn builtin(layout: MazeLayout) -> Maze {
+        let mut maze = Maze::from_rows(layout.rows(), MAZE_WIDTH);
+        if layout == MazeLayout::Classic {
+            maze.no_up = vec![(11, 12), (11, 15), (23, 12), (23, 15)];
+        }
+        maze
+    }
+}
+
+// ─── Level policy ───────────────────────────────────────────────────────────
+
+/// Which maze each level is played on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum MazePolicy {
+    /// The classic maze, or whatever board the game was started with,
+    /// every level.
+    #[default]
+    Classic,
+    /// Every layout in turn, one level each.
+    Rotate,
+    /// A random layout each level, the same for the same seed.
+    Random,
+    /// A new `Maze::generate` board each level, the same for the same
+    /// seed.
+    Generated,
+}
+
+impl MazePolicy {
+    /// The built-in layout for `level` (from 1), or `None` for policies
+    /// that don't use one.
+    pub fn layout(self, level: u32, seed: u32) -> Option<MazeLayout> {
+        let count = MazeLayout::ALL.len() as u32;
+        let index = match self {
+            MazePolicy::Classic | MazePolicy::Generated => return None,
+            MazePolicy::Rotate => (level.max(1) - 1) % count,
+            MazePolicy::Random => Rng::new(seed.wrapping_add(level)).below(count),
+        };
+        Some(MazeLayout::ALL[index as usize])
+    }
+
+    /// The maze for `level` (from 1), or `None` to keep the board.
+    pub fn maze(self, level: u32, seed: u32) -> Option<Maze> {
+        match self {
+            MazePolicy::Generated => Some(Maze::generate(
+                seed.wrapping_add(level),
+                &GeneratorParams::default(),
+            )),
+            policy => policy.layout(level, seed).map(Maze::builtin),
+        }
+    }
+}
+
+impl GameStateInner {
+    /// The maze `level` is played on under the config's `maze_policy`,
+    /// turned as `mirror` and `flip` say, or `None` to keep the current
+    /// one.
+    pub(crate) fn maze_for_level(&self, level: u32) -> Option<Maze> {
+        let config = &self.config;
+        config
+            .maze_policy
+            .maze(level, config.seed)
+            .map(|maze| maze.oriented(config.mirror, config.flip))
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GameConfig;
+    use crate::maze::{CellType, MazeMetadata};
+    use crate::state::{GameMode, GamePhase};
+
+    #[test]
+    fn every_layout_is_a_playable_board() {
+        let meta = MazeMetadata::default();
+        for layout in MazeLayout::ALL {
+            let maze = Maze::builtin(layout);
+            assert_eq!((maze.width, maze.height), (MAZE_WIDTH, MAZE_HEIGHT));
+            assert_eq!(maze.positions_of(CellType::PowerPellet).len(), 4);
+            assert_eq!(maze.get_cell(12, 14), Some(CellType::Door));
+            let (x, y) = meta.pacman_spawn;
+            assert!(maze.is_walkable(x, y), "{:?}", layout);
+            let (x, y) = meta.fruit_spawn;
+            assert!(maze.is_walkable(x, y), "{:?}", layout);
+            assert_eq!(maze.tunnels, Maze::new().tunnels, "{:?}", layout);
+        }
+        assert_eq!(Maze::builtin(MazeLayout::Classic).cells, Maze::new().cells);
+        // 284 dots and 4 power pellets, every one of them reachable
+        assert_eq!(Maze::new().dots_remaining(), 288);
+    }
+
+    #[test]
+    fn the_policy_picks_each_level_s_maze() {
+        assert_eq!(MazePolicy::Classic.layout(3, 0), None);
+        assert_eq!(MazePolicy::Rotate.layout(2, 0), Some(MazeLayout::Arches));
+        assert_eq!(MazePolicy::Rotate.layout(4, 0), Some(MazeLayout::Classic));
+        assert_eq!(
+            MazePolicy::Random.layout(5, 9),
+            MazePolicy::Random.layout(5, 9)
+        );
+
+        let config = GameConfig {
+            maze_policy: MazePolicy::Rotate,
+            ..GameConfig::default()
+        };
+        let mut state = GameStateInner::with_config(GameMode::Classic, config);
+        state.phase = GamePhase::Playing;
+        state.advance_level();
+        assert_eq!(state.maze.cells, Maze::builtin(MazeLayout::Arches).cells);
+        assert_eq!(state.dots_remaining, state.maze.dots_remaining());
+        state.reset();
+        assert_eq!(state.maze.cells, Maze::new().cells);
+    }
+}
@@ -0,0 +1,405 @@
+// game/src/levels.rs
+//
+// Level progression and per-level difficulty.
+//
+// Clearing every dot ends the level. Outside a campaign the same board is
+// refilled and play continues one level up, with faster ghosts and
+// shorter power pellets. (Campaigns move to their next maze instead; see
+// `campaign.rs`.)
+//
+// Speeds follow the arcade's per-level table, given as percentages of
+// `MAX_SPEED` so the whole game can be retuned from one constant.
+
+use crate::entities::GhostMode;
+use crate::events::GameEvent;
+use crate::state::{GameMode, GameStateInner};
+
+// ─── Difficulty curve ───────────────────────────────────────────────────────
+
+/// 100% speed, in tiles per second (Pac-Man runs at 80% on level 1).
+pub const MAX_SPEED: f64 = 13.75;
+/// Length of one blue/white flash at the end of frightened mode, in seconds.
+pub const FLASH_PERIOD: f64 = 0.4;
+
+/// Speeds for one level, as percentages of `MAX_SPEED`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpeedTable {
+    pub pacman: u8,
+    /// Pac-Man while ghosts are frightened
+    pub pacman_frightened: u8,
+    pub ghost: u8,
+    pub ghost_frightened: u8,
+    /// Ghosts inside tunnel zones
+    pub ghost_tunnel: u8,
+    /// Blinky in Cruise Elroy stage 1 / 2
+    pub elroy1: u8,
+    pub elroy2: u8,
+}
+
+impl SpeedTable {
+    /// The arcade speed table row for `level`.
+    pub fn for_level(level: u32) -> SpeedTable {
+        let row =
+            |pacman, pacman_frightened, ghost, ghost_frightened, ghost_tunnel, elroy1, elroy2| {
+                SpeedTable {
+                    pacman,
+                    pacman_frightened,
+                    ghost,
+                    ghost_frightened,
+                    ghost_tunnel,
+                    elroy1,
+                    elroy2,
+                }
+            };
+        match level {
+            0 | 1 => row(80, 90, 75, 50, 40, 80, 85),
+            2..=4 => row(90, 95, 85, 55, 45, 90, 95),
+            5..=20 => row(100, 100, 95, 60, 50, 100, 105),
+            _ => row(90, 90, 95, 60, 50, 100, 105),
+        }
+    }
+
+    /// Convert a percentage from the table to tiles per second.
+    pub fn speed(percent: u8) -> f64 {
+        MAX_SPEED * percent as f64 / 100.0
+    }
+
+    /// Blinky's speed percentage in Elroy `stage` (0 = normal ghost speed).
+    pub fn elroy(&self, stage: u8) -> u8 {
+        match stage {
+            0 => self.ghost,
+            1 => self.elroy1,
+            _ => self.elroy2,
+        }
+    }
+}
+
+/// How long a power pellet lasts on one level.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrightSchedule {
+    /// Seconds ghosts stay frightened. `0` means pellets only make them
+    /// turn around.
+    pub seconds: f64,
+    /// Flashes before it ends; ghosts flash for the last
+    /// `flashes * FLASH_PERIOD` seconds.
+    pub flashes: u8,
+}
+
+impl FrightSchedule {
+    /// The arcade frightened-time table row for `level`.
+    pub fn for_level(level: u32) -> FrightSchedule {
+        let (seconds, flashes) = match level {
+            0 | 1 => (6.0, 5),
+            2 | 6 | 10 => (5.0, 5),
+            3 => (4.0, 5),
+            4 | 14 => (3.0, 5),
+            5 | 7 | 8 | 11 => (2.0, 5),
+            9 | 12 | 13 | 15 | 16 | 18 => (1.0, 3),
+            _ => (0.0, 0),
+        };
+        FrightSchedule { seconds, flashes }
+    }
+
+    /// Whether ghosts should flash with `remaining` seconds of fright left.
+    pub fn is_flashing(&self, remaining: f64) -> bool {
+        remaining > 0.0 && remaining <= self.flashes as f64 * FLASH_PERIOD
+    }
+
+    /// Whether a flashing ghost shows white (rather than blue) with
+    /// `remaining` seconds left: the first half of each flash.
+    pub fn is_white(&self, remaining: f64) -> bool {
+        self.is_flashing(remaining) && remaining % FLASH_PERIOD >= FLASH_PERIOD / 2.0
+    }
+}
+
+// ─── Scatter / chase waves ──────────────────────────────────────────────────
+
+/// Alternating scatter/chase wave lengths for `level`, in seconds, starting
+/// with scatter. After the last entry ghosts chase for good.
+///
+/// From level 2 the third chase wave runs for over 17 minutes and the final
+/// scatter lasts a single frame, so in practice ghosts only reverse there.
+pub fn wave_schedule(level: u32) -> [f64; 7] {
+    const FRAME: f64 = 1.0 / 60.0;
+    match level {
+        0 | 1 => [7.0, 20.0, 7.0, 20.0, 5.0, 20.0, 5.0],
+        2..=4 => [7.0, 20.0, 7.0, 20.0, 5.0, 1033.0, FRAME],
+        _ => [5.0, 20.0, 5.0, 20.0, 5.0, 1037.0, FRAME],
+    }
+}
+
+/// The wave mode `elapsed` seconds into the schedule for `level`.
+pub fn wave_mode(level: u32, elapsed: f64) -> GhostMode {
+    let mut end = 0.0;
+    for (i, length) in wave_schedule(level).into_iter().enumerate() {
+        end += length;
+        if elapsed < end {
+            return if i % 2 == 0 {
+                GhostMode::Scatter
+            } else {
+                GhostMode::Chase
+            };
+        }
+    }
+    GhostMode::Chase
+}
+
+impl GameStateInner {
+    /// The current scatter/chase wave. `global_timer` only runs while no
+    /// power pellet is active, so frightened time pauses the schedule.
+    pub fn wave_mode(&self) -> GhostMode {
+        if !self.config.classic_rules() {
+            // Simplified: the same 7 s / 20 s cycle forever
+            return if self.global_timer % 27.0 < 7.0 {
+                GhostMode::Scatter
+            } else {
+                GhostMode::Chase
+            };
+        }
+        wave_mode(self.level, self.global_timer)
+    }
+
+    /// This level's row of the speed table (always level 1's when the
+    /// classic rules are off).
+    pub fn speeds(&self) -> SpeedTable {
+        let level = if self.config.classic_rules() {
+            self.level
+        } else {
+            1
+        };
+        SpeedTable::for_level(level)
+    }
+}
+
+// ─── Cruise Elroy ───────────────────────────────────────────────────────────
+
+/// Dots remaining at which Blinky enters Elroy stage 1 on `level`.
+/// Stage 2 starts at half that.
+pub fn elroy_dots(level: u32) -> u32 {
+    match level {
+        0 | 1 => 20,
+        2 => 30,
+        3..=5 => 40,
+        6..=8 => 50,
+        9..=11 => 60,
+        12..=14 => 80,
+        15..=18 => 100,
+        _ => 120,
+    }
+}
+
+impl GameStateInner {
+    /// Blinky's current Cruise Elroy stage: 0 (off), 1, or 2.
+    ///
+    /// Derived from the dots left, so it resets by itself when the board is
+    /// refilled. Only AI-driven Blinky turns Elroy — never the PvP player,
+    /// and not with the simplified rules.
+    pub fn elroy_stage(&self) -> u8 {
+        if self.mode == GameMode::PvP || !self.config.classic_rules() {
+            return 0;
+        }
+        let threshold = elroy_dots(self.level) as usize;
+        if self.dots_remaining <= threshold / 2 {
+            2
+        } else if self.dots_remaining <= threshold {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+// ─── Level advance ──────────────────────────────────────────────────────────
+
+impl GameStateInner {
+    /// Refill the board and start the next level.
+    ///
+    /// Dots, power pellets, and any walls broken this level come back
+    /// (the board is restored from `level_maze`, or is the next built-in
+    /// maze under `GameConfig::maze_policy`); entities return to their
+    /// spawns and the game waits in `Ready`. Score, lives, and inventory
+    /// carry over.
+    pub fn advance_level(&mut self) {
+        self.level += 1;
+        if let Some(maze) = self.maze_for_level(self.level) {
+            self.level_maze = maze;
+        }
+        self.maze.clone_from(&self.level_maze);
+        self.reset_board();
+        self.events
+            .push(GameEvent::LevelAdvanced { level: self.level });
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maze::CellType;
+    use crate::state::GamePhase;
+
+    #[test]
+    fn difficulty_ramps_up_and_levels_off() {
+        let level1 = SpeedTable::for_level(1);
+        assert_eq!(SpeedTable::speed(level1.pacman), 11.0);
+        assert!(SpeedTable::for_level(2).ghost > level1.ghost);
+        assert!(SpeedTable::for_level(5).ghost > SpeedTable::for_level(4).ghost);
+        assert_eq!(SpeedTable::for_level(6), SpeedTable::for_level(20));
+        assert!(level1.elroy(2) > level1.elroy(1) && level1.elroy(1) > level1.elroy(0));
+
+        assert_eq!(FrightSchedule::for_level(1).seconds, 6.0);
+        assert!(FrightSchedule::for_level(5).seconds < FrightSchedule::for_level(1).seconds);
+        assert_eq!(FrightSchedule::for_level(17).seconds, 0.0);
+        assert_eq!(FrightSchedule::for_level(99).flashes, 0);
+    }
+
+    #[test]
+    fn waves_follow_the_level_schedule() {
+        assert_eq!(wave_mode(1, 6.9), GhostMode::Scatter);
+        assert_eq!(wave_mode(1, 7.0), GhostMode::Chase);
+        assert_eq!(wave_mode(1, 27.0), GhostMode::Scatter);
+        assert_eq!(wave_mode(1, 80.0), GhostMode::Scatter);
+        assert_eq!(wave_mode(1, 84.0), GhostMode::Chase);
+        assert_eq!(wave_mode(1, 10_000.0), GhostMode::Chase);
+
+        // Later levels open with shorter scatters
+        assert_eq!(wave_mode(5, 5.5), GhostMode::Chase);
+        assert_eq!(wave_mode(3, 5.5), GhostMode::Scatter);
+    }
+
+    #[test]
+    fn frightened_time_pauses_the_wave_clock() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        state.global_timer = 6.0;
+        state.frightened_timer = 3.0;
+        state.tick(2.0);
+        assert_eq!(state.global_timer, 6.0);
+        assert_eq!(state.wave_mode(), GhostMode::Scatter);
+    }
+
+    #[test]
+    fn simplified_rules_turn_classic_behaviors_off() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.config.fidelity = crate::config::Fidelity::Simplified;
+        state.level = 7;
+        state.dots_remaining = 1;
+        assert_eq!(state.elroy_stage(), 0);
+        assert_eq!(state.speeds(), SpeedTable::for_level(1));
+        state.global_timer = 30.0; // Scatter again in the repeating cycle
+        assert_eq!(state.wave_mode(), GhostMode::Scatter);
+    }
+
+    #[test]
+    fn elroy_stages_follow_dots_remaining() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        assert_eq!(state.elroy_stage(), 0);
+        state.dots_remaining = 20;
+        assert_eq!(state.elroy_stage(), 1);
+        state.dots_remaining = 10;
+        assert_eq!(state.elroy_stage(), 2);
+
+        state.level = 3; // Threshold 40
+        state.dots_remaining = 35;
+        assert_eq!(state.elroy_stage(), 1);
+
+        let mut pvp = GameStateInner::new(GameMode::PvP);
+        pvp.dots_remaining = 1;
+        assert_eq!(pvp.elroy_stage(), 0);
+    }
+
+    #[test]
+    fn elroy_blinky_keeps_chasing_through_scatter() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        state.dots_remaining = 5;
+        state.global_timer = 0.0; // Scatter wave
+        state.tick(0.016);
+
+        assert_eq!(state.ghosts[0].mode, GhostMode::Chase);
+        assert_eq!(state.ghosts[1].mode, GhostMode::Scatter);
+    }
+
+    #[test]
+    fn clearing_the_board_starts_the_next_level() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        let full = state.dots_remaining;
+        state.phase = GamePhase::Playing;
+        state.pacman.direction = crate::entities::Direction::Up; // Parked
+        state.pacman.next_direction = crate::entities::Direction::Up;
+        state.pacman.score = 1000;
+
+        // Eat everything but the dot under Pac-Man, then let him eat it
+        for row in 0..state.maze.height {
+            for col in 0..state.maze.width {
+                if matches!(
+                    state.maze.get_cell(row, col),
+                    Some(CellType::Dot | CellType::PowerPellet)
+                ) {
+                    state.maze.cells[row][col] = CellType::Empty;
+                }
+            }
+        }
+        state.maze.cells[23][14] = CellType::Dot;
+        state.dots_remaining = 1;
+        state.tick(0.016);
+        assert!(state.is_level_complete());
+        assert_eq!(state.phase, GamePhase::LevelComplete);
+
+        state.tick(crate::state::LEVEL_COMPLETE_DURATION);
+        assert_eq!(state.level, 2);
+        assert_eq!(state.dots_remaining, full);
+        assert_eq!(state.phase, GamePhase::Ready);
+        assert_eq!(state.pacman.score, 1010);
+        assert!(state
+            .events
+            .contains(&GameEvent::LevelAdvanced { level: 2 }));
+    }
+
+    #[test]
+    fn power_pellet_uses_level_duration() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.level = 5;
+        state.phase = GamePhase::Playing;
+        state.maze.cells[23][14] = CellType::PowerPellet;
+        state.tick(0.016);
+
+        assert_eq!(state.frightened_timer, FrightSchedule::for_level(5).seconds);
+        assert_eq!(state.ghosts[0].mode, GhostMode::Frightened);
+    }
+
+    #[test]
+    fn ghosts_flash_before_fright_ends() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        state.maze.cells[23][14] = CellType::PowerPellet;
+        state.tick(0.016);
+        assert!(!state.ghosts[0].frightened_flashing);
+
+        // Level 1: 6 s with 5 flashes → flashing for the last 2 s
+        for _ in 0..41 {
+            state.tick(0.1);
+        }
+        assert!(state.ghosts[0].frightened_flashing);
+
+        // Alternating white and blue within each flash
+        let fright = FrightSchedule::for_level(1);
+        assert!(fright.is_white(1.9) && !fright.is_white(1.7));
+        assert!(!fright.is_white(3.0));
+    }
+
+    #[test]
+    fn pellets_only_reverse_ghosts_once_fright_time_runs_out() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.level = 19;
+        state.phase = GamePhase::Playing;
+        state.maze.cells[23][14] = CellType::PowerPellet;
+        let heading = state.ghosts[0].direction;
+        state.tick(0.016);
+
+        assert_eq!(state.ghosts[0].mode, GhostMode::Scatter);
+        assert_ne!(state.ghosts[0].direction, heading);
+        assert_eq!(state.pacman.score, 50);
+    }
+}
@@ -7,12 +7,59 @@
 #![allow(dead_code)]
 
 // Modules — each file becomes a module
+mod ai;
+mod animation;
+mod assist;
+#[cfg(feature = "audio")]
+mod audio;
+mod boost;
+mod campaign;
+mod chaos;
+mod config;
+mod coop;
+mod daily;
+#[cfg(feature = "debug")]
+mod debug;
+mod demo;
 mod entities;
+mod events;
+mod fog;
+#[cfg(feature = "framebuffer")]
+mod framebuffer;
+mod freeze;
+mod fruit;
+mod generate;
+mod house;
+mod hud;
+mod intermission;
+mod layouts;
+mod levels;
 mod maze;
+mod minimap;
+mod options;
+mod practice;
+mod pvp;
+mod render;
+mod rng;
+mod save;
+mod scenario;
+mod snapshot;
 mod state;
+mod stats;
+mod survival;
+mod trace;
+mod tutorial;
+mod two_player;
+mod typings;
+mod validate;
+mod walls;
+mod watchdog;
 
 // Re-export the GameState so JS can access it directly via `import { GameState } from '...'`
 pub use state::GameState;
+// Numeric enums taken and returned by `GameState` methods
+pub use entities::Direction;
+pub use state::{GameMode, Phase};
 
 use wasm_bindgen::prelude::*;
 
@@ -5,8 +5,14 @@
 // The classic Pac-Man maze is 28 columns × 31 rows.
 // Each cell is an enum variant describing what occupies that position.
 
+use std::fmt;
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
+use crate::entities::{Direction, GhostType};
+use crate::layouts::MazeLayout;
+
 // ─── Cell types ─────────────────────────────────────────────────────────────
 
 /// Every cell in the maze is exactly one of these variants.
@@ -23,6 +29,209 @@ pub enum CellType {
     Dot,
     PowerPellet,
     GhostHouse,
+    /// A gate that can only be traversed in the given direction.
+    ///
+    /// Entities may enter the cell only while moving in `Direction`, and
+    /// may not leave it by moving back against it.
+    OneWay(Direction),
+    /// Slippery floor: entities slide straight through and can't turn.
+    Ice,
+    /// Mud: entities move at the maze's `slow_multiplier` speed.
+    Slow,
+    /// One end of a warp pair. Both ends share the same id (1–9); Pac-Man
+    /// and ghosts stepping onto one come out of the other.
+    Warp(#[serde(deserialize_with = "warp_id")] u8),
+    /// Rare power-up: lets Pac-Man break through one wall tile.
+    WallBreaker,
+    /// Power-up: freezes every ghost in place for a few seconds.
+    TimeFreeze,
+    /// Power-up: makes Pac-Man faster for a few seconds.
+    SpeedBoost,
+    /// A wall segment that opens and closes on the maze's timed-wall period.
+    TimedWall {
+        open: bool,
+    },
+    /// A pressure plate that toggles its linked gates when Pac-Man steps on it.
+    Switch,
+    /// A gate opened/closed by a linked `Switch`.
+    Gate {
+        open: bool,
+    },
+    /// A key collectible (id 0–2); opens every `LockedDoor` with the same
+    /// id.
+    Key(#[serde(deserialize_with = "key_id")] u8),
+    /// Acts as a wall until Pac-Man collects the matching `Key`.
+    LockedDoor(#[serde(deserialize_with = "key_id")] u8),
+    /// The ghost house door. Pac-Man can never cross it; ghosts only pass
+    /// when leaving the house or returning to it as eyes.
+    Door,
+}
+
+// ─── Surface properties ─────────────────────────────────────────────────────
+
+/// How the ground of a cell affects movement.
+///
+/// Movement code asks the maze for the surface under an entity instead of
+/// matching on terrain cell types directly, so new terrain only needs a new
+/// `CellType::surface` arm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Surface {
+    /// Whether an entity on this cell may change direction at will.
+    pub can_turn: bool,
+    /// Factor applied to an entity's speed while on this cell.
+    pub speed_multiplier: f64,
+}
+
+impl Default for Surface {
+    fn default() -> Self {
+        Surface {
+            can_turn: true,
+            speed_multiplier: 1.0,
+        }
+    }
+}
+
+/// A saved warp id, refused unless the legend could have made it (1–9).
+fn warp_id<'de, D: serde::Deserializer<'de>>(d: D) -> Result<u8, D::Error> {
+    let id = u8::deserialize(d)?;
+    if !(1..=9).contains(&id) {
+        return Err(serde::de::Error::custom(format!(
+            "warp id {} is not 1-9",
+            id
+        )));
+    }
+    Ok(id)
+}
+
+/// A saved key or door id, refused unless it's 0–2.
+fn key_id<'de, D: serde::Deserializer<'de>>(d: D) -> Result<u8, D::Error> {
+    let id = u8::deserialize(d)?;
+    if id > 2 {
+        return Err(serde::de::Error::custom(format!(
+            "key id {} is not 0-2",
+            id
+        )));
+    }
+    Ok(id)
+}
+
+impl CellType {
+    /// Parse a single layout character into a cell.
+    ///
+    /// # Legend
+    /// ```text
+    /// W = Wall          . = Dot          o = Power Pellet
+    /// G = Ghost House   E = Empty        ^ v < > = One-way gate
+    /// _ = Ice           ~ = Slow (mud)    1-9 = Warp pair id
+    /// * = Wall breaker  # = Timed wall (starts closed)
+    /// f = Time freeze   + = Speed boost
+    /// = = Timed wall (starts open)
+    /// s = Switch        | = Gate (starts closed)
+    /// x y z = Key 0–2   X Y Z = Locked door 0–2
+    ///
+    /// Landmarks, read into `MazeMetadata` (see `MazeMetadata::from_rows`):
+    /// @ = Pac-Man spawn   F = Fruit spawn
+    /// b = First ghost's spawn, just outside the house door (house exit)
+    /// p i c = Second to fourth ghosts' spawns inside the house
+    /// B P I C = Scatter corner of Blinky, Pinky, Inky, Clyde
+    /// ```
+    /// A landmark's own tile is `Empty`, or `GhostHouse` for p i c, so a
+    /// marker replaces whatever would have been there. Put B P I C on
+    /// corridor tiles, not in the border wall (where they'd open a hole),
+    /// and expect their tiles to hold no dot; a board that leaves them out
+    /// keeps the classic scatter corners past the edge of the grid. Each
+    /// landmark may appear once; `from_str` refuses repeats.
+    /// Unknown characters fall back to `Empty`; `parse_char` refuses them.
+    pub fn from_char(ch: char) -> CellType {
+        CellType::parse_char(ch).unwrap_or(CellType::Empty)
+    }
+
+    /// Parse a single layout character into a cell, or `None` if it isn't
+    /// in the `from_char` legend.
+    pub fn parse_char(ch: char) -> Option<CellType> {
+        let cell = match ch {
+            'W' => CellType::Wall,
+            '.' => CellType::Dot,
+            'o' => CellType::PowerPellet,
+            'G' => CellType::GhostHouse,
+            '^' => CellType::OneWay(Direction::Up),
+            'v' => CellType::OneWay(Direction::Down),
+            '<' => CellType::OneWay(Direction::Left),
+            '>' => CellType::OneWay(Direction::Right),
+            '_' => CellType::Ice,
+            '~' => CellType::Slow,
+            '1'..='9' => CellType::Warp(ch as u8 - b'0'),
+            '*' => CellType::WallBreaker,
+            'f' => CellType::TimeFreeze,
+            '+' => CellType::SpeedBoost,
+            '#' => CellType::TimedWall { open: false },
+            '=' => CellType::TimedWall { open: true },
+            's' => CellType::Switch,
+            '|' => CellType::Gate { open: false },
+            'x' | 'y' | 'z' => CellType::Key(ch as u8 - b'x'),
+            'X' | 'Y' | 'Z' => CellType::LockedDoor(ch as u8 - b'X'),
+            '-' => CellType::Door,
+            'E' => CellType::Empty,
+            '@' | 'F' | 'b' | 'B' | 'P' | 'I' | 'C' => CellType::Empty,
+            'p' | 'i' | 'c' => CellType::GhostHouse,
+            _ => return None,
+        };
+        Some(cell)
+    }
+
+    /// Stable one-byte code for `Maze::to_bytes()`. Parameterised cells add
+    /// their parameter to a base (ids stay in the legend's range, which
+    /// loading enforces, so codes never overlap):
+    ///
+    /// ```text
+    ///  0 Empty        5 Ice            10/11 Timed wall closed/open
+    ///  1 Wall         6 Slow           12/13 Gate closed/open
+    ///  2 Dot          7 Wall breaker   14    Time freeze
+    ///                                  15    Speed boost
+    ///                                  16+d  One-way (d = Direction::code)
+    ///  3 Pellet       8 Switch         20+n  Warp n
+    ///  4 Ghost house  9 Door           30+n  Key n      40+n  Locked door n
+    /// ```
+    pub fn code(self) -> u8 {
+        match self {
+            CellType::Empty => 0,
+            CellType::Wall => 1,
+            CellType::Dot => 2,
+            CellType::PowerPellet => 3,
+            CellType::GhostHouse => 4,
+            CellType::Ice => 5,
+            CellType::Slow => 6,
+            CellType::WallBreaker => 7,
+            CellType::Switch => 8,
+            CellType::Door => 9,
+            CellType::TimedWall { open } => 10 + open as u8,
+            CellType::Gate { open } => 12 + open as u8,
+            CellType::TimeFreeze => 14,
+            CellType::SpeedBoost => 15,
+            CellType::OneWay(dir) => 16 + dir.code(),
+            CellType::Warp(id) => 20 + id,
+            CellType::Key(id) => 30 + id,
+            CellType::LockedDoor(id) => 40 + id,
+        }
+    }
+
+    /// The movement properties of this cell's ground.
+    ///
+    /// `Slow` reports the default mud multiplier here; use
+    /// `Maze::surface_at` to get the value authored for a specific maze.
+    pub fn surface(self) -> Surface {
+        match self {
+            CellType::Ice => Surface {
+                can_turn: false,
+                ..Surface::default()
+            },
+            CellType::Slow => Surface {
+                speed_multiplier: DEFAULT_SLOW_MULTIPLIER,
+                ..Surface::default()
+            },
+            _ => Surface::default(),
+        }
+    }
 }
 
 // ─── Maze dimensions ────────────────────────────────────────────────────────
@@ -30,6 +239,137 @@ pub enum CellType {
 pub const MAZE_WIDTH: usize = 28;
 pub const MAZE_HEIGHT: usize = 31;
 
+/// Speed factor applied on `Slow` cells unless a maze overrides it.
+pub const DEFAULT_SLOW_MULTIPLIER: f64 = 0.5;
+
+/// Seconds between timed-wall toggles unless a maze overrides it.
+pub const DEFAULT_TIMED_WALL_PERIOD: f64 = 5.0;
+
+// ─── Switch links ───────────────────────────────────────────────────────────
+
+/// Maze metadata linking a switch cell to the gates it toggles.
+///
+/// A single character per cell can't express "this switch opens those
+/// gates", so links are stored alongside the grid as (row, col) pairs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SwitchLink {
+    pub switch: (usize, usize),
+    pub gates: Vec<(usize, usize)>,
+}
+
+// ─── Metadata ───────────────────────────────────────────────────────────────
+
+/// Where things start and aim on a board.
+///
+/// These used to be constants for the classic maze; keeping them with the
+/// grid lets them move when it does (see `Maze::oriented`). A layout can
+/// place them with landmark markers (see `CellType::from_char`); whatever
+/// it leaves out keeps the classic value.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MazeMetadata {
+    /// Pac-Man's starting tile (x, y).
+    pub pacman_spawn: (f64, f64),
+    /// The tile outside the house door (x, y): where leaving ghosts step
+    /// out to, and where the first ghost starts.
+    pub house_exit: (f64, f64),
+    /// Where eaten ghosts revive (col, row).
+    pub house_center: (usize, usize),
+    /// Tiles ghosts wait on inside the house (x, y), in the order they
+    /// fill up.
+    pub house_slots: Vec<(f64, f64)>,
+    /// The bonus fruit's tile (x, y).
+    pub fruit_spawn: (f64, f64),
+    /// Scatter targets (col, row) by `GhostType::code`: Blinky, Pinky,
+    /// Inky, Clyde. They lie outside the maze, past its corners.
+    pub scatter_targets: [(isize, isize); 4],
+}
+
+impl Default for MazeMetadata {
+    fn default() -> Self {
+        MazeMetadata {
+            pacman_spawn: (14.0, 23.0),
+            house_exit: (14.0, 11.0),
+            house_center: (14, 14),
+            house_slots: vec![(12.0, 14.0), (14.0, 14.0), (16.0, 14.0)],
+            fruit_spawn: (14.0, 17.0),
+            scatter_targets: [(25, -3), (2, -3), (27, 31), (0, 31)],
+        }
+    }
+}
+
+impl MazeMetadata {
+    /// The classic values, moved wherever `rows` (cut to `width`) has a
+    /// landmark marker. The house slots, if any are marked, replace the
+    /// classic ones in p, i, c order, and eaten ghosts revive in the middle
+    /// of them.
+    pub fn from_rows(rows: &[&str], width: usize) -> MazeMetadata {
+        let mut meta = MazeMetadata::default();
+        let mut slots = [None; 3];
+        for (row, line) in rows.iter().enumerate() {
+            for (col, ch) in line.chars().take(width).enumerate() {
+                let point = (col as f64, row as f64);
+                let tile = (col as isize, row as isize);
+                match ch {
+                    '@' => meta.pacman_spawn = point,
+                    'F' => meta.fruit_spawn = point,
+                    'b' => meta.house_exit = point,
+                    'p' => slots[0] = Some(point),
+                    'i' => slots[1] = Some(point),
+                    'c' => slots[2] = Some(point),
+                    'B' => meta.scatter_targets[GhostType::Blinky.code() as usize] = tile,
+                    'P' => meta.scatter_targets[GhostType::Pinky.code() as usize] = tile,
+                    'I' => meta.scatter_targets[GhostType::Inky.code() as usize] = tile,
+                    'C' => meta.scatter_targets[GhostType::Clyde.code() as usize] = tile,
+                    _ => {}
+                }
+            }
+        }
+        let slots: Vec<(f64, f64)> = slots.into_iter().flatten().collect();
+        if !slots.is_empty() {
+            let n = slots.len() as f64;
+            let (sum_x, sum_y) = slots
+                .iter()
+                .fold((0.0, 0.0), |(sx, sy), &(x, y)| (sx + x, sy + y));
+            meta.house_center = ((sum_x / n).round() as usize, (sum_y / n).round() as usize);
+            meta.house_slots = slots;
+        }
+        meta
+    }
+}
+
+/// A mirror (left–right) and/or flip (top–bottom) of a `width`×`height`
+/// grid.
+#[derive(Clone, Copy)]
+struct Reflection {
+    mirror: bool,
+    flip: bool,
+    width: f64,
+    height: f64,
+}
+
+impl Reflection {
+    fn point(self, (x, y): (f64, f64)) -> (f64, f64) {
+        (
+            if self.mirror { self.width - 1.0 - x } else { x },
+            if self.flip { self.height - 1.0 - y } else { y },
+        )
+    }
+
+    /// A (row, col) tile.
+    fn tile(self, (row, col): (usize, usize)) -> (usize, usize) {
+        let (x, y) = self.point((col as f64, row as f64));
+        (y as usize, x as usize)
+    }
+
+    fn direction(self, dir: Direction) -> Direction {
+        match dir {
+            Direction::Left | Direction::Right if self.mirror => dir.opposite(),
+            Direction::Up | Direction::Down if self.flip => dir.opposite(),
+            _ => dir,
+        }
+    }
+}
+
 // ─── Maze struct ────────────────────────────────────────────────────────────
 
 /// The game maze: a 2D grid stored as `Vec<Vec<CellType>>`.
@@ -39,11 +379,45 @@ pub const MAZE_HEIGHT: usize = 31;
 /// For a 28×31 grid (~868 cells), the performance difference is negligible.
 /// A flat array with manual index math would be faster for huge grids,
 /// but for Pac-Man's fixed-size maze, clarity wins.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Maze {
     pub cells: Vec<Vec<CellType>>,
     pub width: usize,
     pub height: usize,
+    /// Speed factor on `Slow` (mud) cells — authored per maze.
+    #[serde(default = "default_slow_multiplier")]
+    pub slow_multiplier: f64,
+    /// Seconds between timed-wall toggles — authored per maze.
+    #[serde(default = "default_timed_wall_period")]
+    pub timed_wall_period: f64,
+    /// Which gates each switch toggles.
+    #[serde(default)]
+    pub switch_links: Vec<SwitchLink>,
+    /// Cached junction flags, row-major (`row * width + col`).
+    ///
+    /// Derived from `cells`, so it's not serialized; after deserializing,
+    /// `is_intersection` falls back to computing on the fly.
+    #[serde(skip)]
+    junctions: Vec<bool>,
+    /// `to_bytes()` kept up to date in place, so JS can view it directly in
+    /// WASM memory (see `byte_view`). Derived like `junctions`.
+    #[serde(skip)]
+    bytes: Vec<u8>,
+    /// Tunnel tiles (row, col), where ghosts slow down.
+    ///
+    /// Detected from the layout (see `detect_tunnels`) but may be edited
+    /// with `set_tunnel`, so unlike `junctions` it is serialized.
+    #[serde(default)]
+    pub tunnels: Vec<(usize, usize)>,
+    /// Tiles (row, col) where chasing or scattering ghosts may not turn up.
+    ///
+    /// The arcade has four: the two junctions above the ghost house and
+    /// the two above Pac-Man's start. Custom layouts start with none.
+    #[serde(default)]
+    pub no_up: Vec<(usize, usize)>,
+    /// Spawn points, the house, and the scatter corners.
+    #[serde(default)]
+    pub metadata: MazeMetadata,
 }
 
 impl Maze {
@@ -54,73 +428,275 @@ impl Maze {
     /// The `Vec`s are heap-allocated; when the `Maze` is dropped, Rust
     /// automatically frees them (no garbage collector needed — this is RAII).
     pub fn new() -> Self {
-        // Legend:
-        //   W = Wall
-        //   . = Dot
-        //   o = Power Pellet
-        //   G = Ghost House
-        //   E = Empty (tunnels, ghost house entry)
-        //
-        // This is a simplified classic layout. Each string is one row (28 chars).
-        let layout: Vec<&str> = vec![
-            "WWWWWWWWWWWWWWWWWWWWWWWWWWWW",  // 0
-            "W............WW............W",  // 1
-            "W.WWWW.WWWWW.WW.WWWWW.WWWWW",   // 2  (was: "W.WWWW.WWWWW.WW.WWWWW.WWWW.")
-            "WoWWWW.WWWWW.WW.WWWWW.WWWWoW",  // 3  (was: "WoWWWW.WWWWW.WW.WWWWW.WWWWo.")
-            "W.WWWW.WWWWW.WW.WWWWW.WWWWW",   // 4  (was: same pattern)
-            "W..........................W",  // 5
-            "W.WWWW.WW.WWWWWWWW.WW.WWWWW",   // 6  (was: "W.WWWW.WW.WWWWWWWW.WW.WWWW.")
-            "W.WWWW.WW.WWWWWWWW.WW.WWWWW",   // 7
-            "W......WW....WW....WW......W",  // 8
-            "WWWWWW.WWWWW.WW.WWWWW.WWWWWW",  // 9  (was: "WWWWWW.WWWWWEWWEEWWWWW.WWWWWW")
-            "EEEEWW.WWWWW.WW.WWWWW.WWEEEEE", // 10 — fixed to 28 below
-            "EEEEWW.WW..........WW.WWEEEEE", // 11
-            "EEEEWW.WW.WWWGGWWW.WW.WWEEEEE", // 12
-            "WWWWWW.WW.WEGGGGEW.WW.WWWWWW",  // 13
-            "EEEEEE....WEGGGGEW....EEEEEE",  // 14  ← tunnel row
-            "WWWWWW.WW.WEGGGGEW.WW.WWWWWW",  // 15
-            "EEEEWW.WW.WWWWWWWW.WW.WWEEEEE", // 16
-            "EEEEWW.WW..........WW.WWEEEEE", // 17
-            "EEEEWW.WW.WWWWWWWW.WW.WWEEEEE", // 18
-            "WWWWWW.WW.WWWWWWWW.WW.WWWWWW",  // 19
-            "W............WW............W",  // 20
-            "W.WWWW.WWWWW.WW.WWWWW.WWWWW",   // 21
-            "W.WWWW.WWWWW.WW.WWWWW.WWWWW",   // 22
-            "Wo..WW................WW..oW",  // 23
-            "WWW.WW.WW.WWWWWWWW.WW.WW.WWW",  // 24
-            "WWW.WW.WW.WWWWWWWW.WW.WW.WWW",  // 25
-            "W......WW....WW....WW......W",  // 26
-            "W.WWWWWWWWWW.WW.WWWWWWWWWW.W",  // 27
-            "W.WWWWWWWWWW.WW.WWWWWWWWWW.W",  // 28
-            "W..........................W",  // 29
-            "WWWWWWWWWWWWWWWWWWWWWWWWWWWW",  // 30
-        ];
-
-        let cells: Vec<Vec<CellType>> = layout
+        Maze::builtin(MazeLayout::Classic)
+    }
+
+    /// Build a maze from layout rows using the `CellType::from_char` legend.
+    ///
+    /// Parsing is lenient: rows longer than `width` are truncated and
+    /// shorter rows are padded with `Empty`. `from_str` checks instead.
+    pub fn from_rows(rows: &[&str], width: usize) -> Self {
+        let cells: Vec<Vec<CellType>> = rows
             .iter()
             .map(|row| {
                 let mut row_cells: Vec<CellType> = row
                     .chars()
-                    .take(MAZE_WIDTH) // Ensure exactly 28 columns
-                    .map(|ch| match ch {
-                        'W' => CellType::Wall,
-                        '.' => CellType::Dot,
-                        'o' => CellType::PowerPellet,
-                        'G' => CellType::GhostHouse,
-                        _ => CellType::Empty, // 'E' and anything else
-                    })
+                    .take(width) // Ensure exactly `width` columns
+                    .map(CellType::from_char)
                     .collect();
-                // Pad or trim to exactly MAZE_WIDTH
-                row_cells.resize(MAZE_WIDTH, CellType::Empty);
+                // Pad or trim to exactly `width`
+                row_cells.resize(width, CellType::Empty);
                 row_cells
             })
             .collect();
 
-        Maze {
+        let mut maze = Maze {
+            height: cells.len(),
             cells,
-            width: MAZE_WIDTH,
-            height: MAZE_HEIGHT,
+            width,
+            slow_multiplier: DEFAULT_SLOW_MULTIPLIER,
+            timed_wall_period: DEFAULT_TIMED_WALL_PERIOD,
+            switch_links: Vec::new(),
+            junctions: Vec::new(),
+            bytes: Vec::new(),
+            tunnels: Vec::new(),
+            no_up: Vec::new(),
+            metadata: MazeMetadata::from_rows(rows, width),
+        };
+        maze.rebuild_junctions();
+        maze.bytes = maze.to_bytes();
+        maze.tunnels = maze.detect_tunnels();
+        maze
+    }
+
+    /// Build a maze from newline-separated layout text (as sent from JS),
+    /// as wide as its longest line.
+    pub fn from_layout(layout: &str) -> Self {
+        let rows: Vec<&str> = layout.lines().collect();
+        let width = rows.iter().map(|r| r.chars().count()).max().unwrap_or(0);
+        Maze::from_rows(&rows, width)
+    }
+
+    /// This maze mirrored left to right (`mirror`) and/or turned upside
+    /// down (`flip`), along with everything placed on it: one-way gates,
+    /// switch links, tunnels, no-up tiles and `metadata`.
+    ///
+    /// No-up tiles keep their rule (no turning up) at their new places.
+    pub fn oriented(&self, mirror: bool, flip: bool) -> Maze {
+        let r = Reflection {
+            mirror,
+            flip,
+            width: self.width as f64,
+            height: self.height as f64,
+        };
+        let mut maze = self.clone();
+        for (row, cells) in self.cells.iter().enumerate() {
+            for (col, &cell) in cells.iter().enumerate() {
+                let (to_row, to_col) = r.tile((row, col));
+                maze.cells[to_row][to_col] = match cell {
+                    CellType::OneWay(dir) => CellType::OneWay(r.direction(dir)),
+                    other => other,
+                };
+            }
+        }
+        let tiles = |tiles: &[(usize, usize)]| tiles.iter().map(|&t| r.tile(t)).collect();
+        maze.switch_links = self
+            .switch_links
+            .iter()
+            .map(|link| SwitchLink {
+                switch: r.tile(link.switch),
+                gates: tiles(&link.gates),
+            })
+            .collect();
+        maze.tunnels = tiles(&self.tunnels);
+        maze.no_up = tiles(&self.no_up);
+
+        let meta = &self.metadata;
+        let (center_x, center_y) =
+            r.point((meta.house_center.0 as f64, meta.house_center.1 as f64));
+        maze.metadata = MazeMetadata {
+            pacman_spawn: r.point(meta.pacman_spawn),
+            house_exit: r.point(meta.house_exit),
+            house_center: (center_x as usize, center_y as usize),
+            house_slots: meta.house_slots.iter().map(|&p| r.point(p)).collect(),
+            fruit_spawn: r.point(meta.fruit_spawn),
+            scatter_targets: meta.scatter_targets.map(|(c, row)| {
+                let (x, y) = r.point((c as f64, row as f64));
+                (x as isize, y as isize)
+            }),
+        };
+        maze.rebuild_junctions();
+        maze.bytes = maze.to_bytes();
+        maze
+    }
+
+    /// Change a cell, keeping the junction cache and byte mirror up to date.
+    ///
+    /// Prefer this over writing to `cells` directly whenever the change
+    /// can affect walkability (walls broken, gates toggled, ...).
+    pub fn set_cell(&mut self, row: usize, col: usize, cell: CellType) {
+        self.cells[row][col] = cell;
+        if let Some(byte) = self.bytes.get_mut(row * self.width + col) {
+            *byte = cell.code();
+        }
+        if self.junctions.len() != self.width * self.height {
+            self.rebuild_junctions();
+            return;
+        }
+        // Only this tile and its neighbours can change junction status
+        let around = [(0, 0), (-1, 0), (1, 0), (0, -1), (0, 1)];
+        for (dr, dc) in around {
+            let r = row as isize + dr;
+            let c = col as isize + dc;
+            if r >= 0 && c >= 0 && (r as usize) < self.height && (c as usize) < self.width {
+                let (r, c) = (r as usize, c as usize);
+                self.junctions[r * self.width + c] = self.compute_is_intersection(c, r);
+            }
+        }
+    }
+
+    /// Recompute the whole junction cache from `cells`.
+    pub fn rebuild_junctions(&mut self) {
+        let mut junctions = Vec::with_capacity(self.width * self.height);
+        for row in 0..self.height {
+            for col in 0..self.width {
+                junctions.push(self.compute_is_intersection(col, row));
+            }
+        }
+        self.junctions = junctions;
+    }
+
+    /// Find the tunnel tiles in the layout.
+    ///
+    /// A tunnel starts at an open tile on the left or right edge of a row
+    /// that is open at both ends (so it wraps around), and runs
+    /// inward for as long as the tile is open and walled in above and
+    /// below — the classic side passages, not the corridors they lead to.
+    pub fn detect_tunnels(&self) -> Vec<(usize, usize)> {
+        let walled = |row: isize, col: usize| {
+            row < 0
+                || matches!(
+                    self.get_cell(row as usize, col),
+                    Some(CellType::Wall) | None
+                )
+        };
+        let mut tunnels = Vec::new();
+        for row in 0..self.height {
+            // Only rows that actually wrap around have tunnels
+            let last = self.width.saturating_sub(1) as f64;
+            if !self.is_walkable(0.0, row as f64) || !self.is_walkable(last, row as f64) {
+                continue;
+            }
+            let above = row as isize - 1;
+            let below = row as isize + 1;
+            let mut scan = |cols: &mut dyn Iterator<Item = usize>| {
+                for col in cols {
+                    let open = self.is_walkable(col as f64, row as f64);
+                    if !open || !walled(above, col) || !walled(below, col) {
+                        break;
+                    }
+                    if !tunnels.contains(&(row, col)) {
+                        tunnels.push((row, col));
+                    }
+                }
+            };
+            scan(&mut (0..self.width));
+            scan(&mut (0..self.width).rev());
+        }
+        tunnels
+    }
+
+    /// Mark or unmark (row, col) as a tunnel tile.
+    pub fn set_tunnel(&mut self, row: usize, col: usize, tunnel: bool) {
+        self.tunnels.retain(|&t| t != (row, col));
+        if tunnel {
+            self.tunnels.push((row, col));
+        }
+    }
+
+    /// Whether the entity at (x, y) is in a tunnel. Positions past the
+    /// side edges (mid-wrap) count as tunnel.
+    pub fn is_tunnel(&self, x: f64, y: f64) -> bool {
+        let (col, row) = (x.round(), y.round());
+        if col < 0.0 || col >= self.width as f64 {
+            return true;
+        }
+        row >= 0.0 && self.tunnels.contains(&(row as usize, col as usize))
+    }
+
+    /// Mark or unmark (row, col) as a tile where ghosts can't turn up.
+    pub fn set_no_up(&mut self, row: usize, col: usize, no_up: bool) {
+        self.no_up.retain(|&t| t != (row, col));
+        if no_up {
+            self.no_up.push((row, col));
+        }
+    }
+
+    /// Whether ghosts at tile (col, row) are barred from turning up.
+    pub fn is_no_up(&self, col: usize, row: usize) -> bool {
+        self.no_up.contains(&(row, col))
+    }
+
+    /// Check if (col, row) is a junction: a walkable tile with 3+
+    /// walkable neighbours, i.e. a place where an entity has a choice.
+    pub fn is_intersection(&self, col: usize, row: usize) -> bool {
+        if col >= self.width || row >= self.height {
+            return false;
+        }
+        match self.junctions.get(row * self.width + col) {
+            Some(&flag) if self.junctions.len() == self.width * self.height => flag,
+            _ => self.compute_is_intersection(col, row),
+        }
+    }
+
+    /// The junction map packed into bits, row-major, LSB first.
+    ///
+    /// Bit `i` of the stream (`byte i / 8`, bit `i % 8`) is tile
+    /// `row * width + col`. A 28×31 maze fits in 109 bytes.
+    pub fn junction_bitmap(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; (self.width * self.height).div_ceil(8)];
+        for row in 0..self.height {
+            for col in 0..self.width {
+                if self.is_intersection(col, row) {
+                    let i = row * self.width + col;
+                    bytes[i / 8] |= 1 << (i % 8);
+                }
+            }
+        }
+        bytes
+    }
+
+    fn compute_is_intersection(&self, col: usize, row: usize) -> bool {
+        let (x, y) = (col as f64, row as f64);
+        if !self.is_walkable(x, y) {
+            return false;
+        }
+        let open = [(0.0, -1.0), (0.0, 1.0), (-1.0, 0.0), (1.0, 0.0)]
+            .iter()
+            .filter(|(dx, dy)| self.is_walkable(x + dx, y + dy))
+            .count();
+        open >= 3
+    }
+
+    /// Every cell as a `CellType::code`, row-major (`row * width + col`).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.cells
+            .iter()
+            .flat_map(|row| row.iter().map(|cell| cell.code()))
+            .collect()
+    }
+
+    /// The `to_bytes()` mirror, rebuilt first if it's missing (after
+    /// deserializing). Like the junction cache, it only sees changes made
+    /// through `set_cell`.
+    ///
+    /// `set_cell` updates the mirror in place, so its buffer stays put
+    /// while the maze lives: JS can keep a view over it across ticks.
+    pub fn byte_view(&mut self) -> &[u8] {
+        if self.bytes.len() != self.width * self.height {
+            self.bytes = self.to_bytes();
         }
+        &self.bytes
     }
 
     /// Count remaining dots (regular + power pellets) on the maze.
@@ -153,9 +729,387 @@ impl Maze {
         let cell = self.get_cell(iy as usize, ix as usize);
         !matches!(
             cell,
-            Some(CellType::Wall) | Some(CellType::GhostHouse) | None
+            Some(CellType::Wall)
+                | Some(CellType::GhostHouse)
+                | Some(CellType::TimedWall { open: false })
+                | Some(CellType::Gate { open: false })
+                | Some(CellType::LockedDoor(_))
+                | Some(CellType::Door)
+                | None
         )
     }
+
+    /// `can_move` for ghosts. With `through_door` (leaving the house, or
+    /// returning as eyes) the house door and house floor are passable too.
+    pub fn can_move_ghost(&self, x: f64, y: f64, dir: Direction, through_door: bool) -> bool {
+        let (dx, dy) = dir.to_vector();
+        match self.cell_at(x + dx, y + dy) {
+            Some(CellType::Door | CellType::GhostHouse) if through_door => {
+                self.can_leave(x, y, dir)
+            }
+            _ => self.can_move(x, y, dir),
+        }
+    }
+
+    /// Link the switch at `switch` to `gates`, adding to any existing link.
+    pub fn link_switch(&mut self, switch: (usize, usize), gates: &[(usize, usize)]) {
+        match self.switch_links.iter_mut().find(|l| l.switch == switch) {
+            Some(link) => link.gates.extend_from_slice(gates),
+            None => self.switch_links.push(SwitchLink {
+                switch,
+                gates: gates.to_vec(),
+            }),
+        }
+    }
+
+    /// The gates linked to the switch at (row, col), if any.
+    pub fn linked_gates(&self, row: usize, col: usize) -> &[(usize, usize)] {
+        self.switch_links
+            .iter()
+            .find(|l| l.switch == (row, col))
+            .map(|l| l.gates.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Grid positions (row, col) of every cell equal to `cell`.
+    pub fn positions_of(&self, cell: CellType) -> Vec<(usize, usize)> {
+        let mut found = Vec::new();
+        for (row, cells) in self.cells.iter().enumerate() {
+            for (col, c) in cells.iter().enumerate() {
+                if *c == cell {
+                    found.push((row, col));
+                }
+            }
+        }
+        found
+    }
+
+    /// Grid positions (row, col) of every timed wall, open or closed.
+    pub fn timed_walls(&self) -> Vec<(usize, usize)> {
+        let mut walls = Vec::new();
+        for (row, cells) in self.cells.iter().enumerate() {
+            for (col, cell) in cells.iter().enumerate() {
+                if matches!(cell, CellType::TimedWall { .. }) {
+                    walls.push((row, col));
+                }
+            }
+        }
+        walls
+    }
+
+    /// Check if an entity standing on (x, y) may leave it moving in `dir`.
+    ///
+    /// Only one-way gates restrict leaving: you can't back out against them.
+    pub fn can_leave(&self, x: f64, y: f64, dir: Direction) -> bool {
+        match self.cell_at(x, y) {
+            Some(CellType::OneWay(gate)) => dir != gate.opposite(),
+            _ => true,
+        }
+    }
+
+    /// Check if an entity centred on (x, y) may step one tile in `dir`.
+    ///
+    /// This is the direction-aware version of `is_walkable`: the target must
+    /// be walkable, a one-way gate there must point along `dir`, and the
+    /// current tile must allow leaving in `dir`.
+    pub fn can_move(&self, x: f64, y: f64, dir: Direction) -> bool {
+        let (dx, dy) = dir.to_vector();
+        let (tx, ty) = (x + dx, y + dy);
+
+        if !self.can_leave(x, y, dir) || !self.is_walkable(tx, ty) {
+            return false;
+        }
+        match self.cell_at(tx, ty) {
+            Some(CellType::OneWay(gate)) => gate == dir,
+            _ => true,
+        }
+    }
+
+    /// Find the wall tile an entity on (x, y) heading `dir` could break.
+    ///
+    /// Breaking is only allowed if it keeps the maze sane: the target must
+    /// be an interior wall (never the outer border) and the tile beyond it
+    /// must be walkable, so the hole connects two corridors instead of
+    /// carving a dead-end pocket. Returns the wall's (row, col).
+    pub fn breakable_wall(&self, x: f64, y: f64, dir: Direction) -> Option<(usize, usize)> {
+        let (dx, dy) = dir.to_vector();
+        let col = (x.round() + dx) as isize;
+        let row = (y.round() + dy) as isize;
+
+        let interior =
+            col > 0 && row > 0 && col < self.width as isize - 1 && row < self.height as isize - 1;
+        if !interior {
+            return None;
+        }
+
+        let (row, col) = (row as usize, col as usize);
+        if self.get_cell(row, col) != Some(CellType::Wall) {
+            return None;
+        }
+        if !self.is_walkable(col as f64 + dx, row as f64 + dy) {
+            return None;
+        }
+        Some((row, col))
+    }
+
+    /// First step of the shortest ghost route from `from` to `goal`, both
+    /// (col, row). See `can_move_ghost` for `through_door`.
+    ///
+    /// A breadth-first search over tiles: cheap at 28×31, and unlike greedy
+    /// "closest neighbour to the target" it can't get caught looping around
+    /// a block. Returns `None` if already there or `goal` is unreachable.
+    pub fn route_step(
+        &self,
+        from: (usize, usize),
+        goal: (usize, usize),
+        through_door: bool,
+    ) -> Option<Direction> {
+        if from == goal {
+            return None;
+        }
+        let index = |(col, row): (usize, usize)| row * self.width + col;
+        let mut first_step: Vec<Option<Direction>> = vec![None; self.width * self.height];
+        let mut visited = vec![false; self.width * self.height];
+        let mut queue = std::collections::VecDeque::new();
+        visited[index(from)] = true;
+        queue.push_back(from);
+
+        while let Some((col, row)) = queue.pop_front() {
+            for dir in [
+                Direction::Up,
+                Direction::Left,
+                Direction::Down,
+                Direction::Right,
+            ] {
+                let (x, y) = (col as f64, row as f64);
+                if !self.can_move_ghost(x, y, dir, through_door) {
+                    continue;
+                }
+                // Tunnels wrap around the sides
+                let (dx, dy) = dir.to_vector();
+                let next_col =
+                    (col as isize + dx as isize).rem_euclid(self.width as isize) as usize;
+                let next_row = row as isize + dy as isize;
+                if next_row < 0 || next_row >= self.height as isize {
+                    continue;
+                }
+                let next = (next_col, next_row as usize);
+                if visited[index(next)] {
+                    continue;
+                }
+                visited[index(next)] = true;
+                first_step[index(next)] = first_step[index((col, row))].or(Some(dir));
+                if next == goal {
+                    return first_step[index(next)];
+                }
+                queue.push_back(next);
+            }
+        }
+        None
+    }
+
+    /// Find the other end of the warp pair at (row, col).
+    ///
+    /// Returns `None` if the cell isn't a warp or its partner is missing.
+    pub fn warp_partner(&self, row: usize, col: usize) -> Option<(usize, usize)> {
+        let id = match self.get_cell(row, col)? {
+            CellType::Warp(id) => id,
+            _ => return None,
+        };
+
+        self.cells.iter().enumerate().find_map(|(r, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .find(|&(c, cell)| *cell == CellType::Warp(id) && (r, c) != (row, col))
+                .map(|(c, _)| (r, c))
+        })
+    }
+
+    /// The surface under a fractional coordinate (default outside the grid).
+    pub fn surface_at(&self, x: f64, y: f64) -> Surface {
+        match self.cell_at(x, y) {
+            Some(CellType::Slow) => Surface {
+                speed_multiplier: self.slow_multiplier,
+                ..CellType::Slow.surface()
+            },
+            Some(cell) => cell.surface(),
+            None => Surface::default(),
+        }
+    }
+
+    /// Check if an entity on (x, y) heading in `dir` may change direction.
+    ///
+    /// On a surface that forbids turning (ice), the entity keeps sliding
+    /// until it's blocked — only then may it pick a new direction.
+    pub fn can_steer(&self, x: f64, y: f64, dir: Direction) -> bool {
+        if self.surface_at(x, y).can_turn {
+            return true;
+        }
+        !self.can_move(x.round(), y.round(), dir)
+    }
+
+    /// Look up the cell under a fractional coordinate (rounded to the grid).
+    fn cell_at(&self, x: f64, y: f64) -> Option<CellType> {
+        let ix = x.round() as isize;
+        let iy = y.round() as isize;
+        if ix < 0 || iy < 0 {
+            return None;
+        }
+        self.get_cell(iy as usize, ix as usize)
+    }
+}
+
+fn default_slow_multiplier() -> f64 {
+    DEFAULT_SLOW_MULTIPLIER
+}
+
+fn default_timed_wall_period() -> f64 {
+    DEFAULT_TIMED_WALL_PERIOD
+}
+
+impl Default for Maze {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Written out by hand so `clone_from` copies into the existing buffers
+/// (the derived one reallocates), which lets restarts and level refills
+/// reuse the grid.
+impl Clone for Maze {
+    fn clone(&self) -> Self {
+        Maze {
+            cells: self.cells.clone(),
+            width: self.width,
+            height: self.height,
+            slow_multiplier: self.slow_multiplier,
+            timed_wall_period: self.timed_wall_period,
+            switch_links: self.switch_links.clone(),
+            junctions: self.junctions.clone(),
+            bytes: self.bytes.clone(),
+            tunnels: self.tunnels.clone(),
+            no_up: self.no_up.clone(),
+            metadata: self.metadata.clone(),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.cells.clone_from(&source.cells);
+        self.width = source.width;
+        self.height = source.height;
+        self.slow_multiplier = source.slow_multiplier;
+        self.timed_wall_period = source.timed_wall_period;
+        self.switch_links.clone_from(&source.switch_links);
+        self.junctions.clone_from(&source.junctions);
+        self.bytes.clone_from(&source.bytes);
+        self.tunnels.clone_from(&source.tunnels);
+        self.no_up.clone_from(&source.no_up);
+        self.metadata.clone_from(&source.metadata);
+    }
+}
+
+// ─── Strict parsing ─────────────────────────────────────────────────────────
+
+/// Why layout text isn't a maze. Rows and columns count from 0.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MazeError {
+    /// No rows, or nothing but whitespace.
+    Empty,
+    /// A character that isn't in the `CellType::from_char` legend.
+    BadChar { row: usize, col: usize, ch: char },
+    /// A row that isn't as wide as the first one.
+    WrongWidth {
+        row: usize,
+        width: usize,
+        expected: usize,
+    },
+    /// Not a single ghost house (`G`) tile for the ghosts to start in.
+    NoGhostHouse,
+    /// A landmark marker (see `CellType::from_char`) placed a second time.
+    DuplicateMarker { row: usize, col: usize, ch: char },
+}
+
+impl fmt::Display for MazeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MazeError::Empty => write!(f, "maze layout is empty"),
+            MazeError::BadChar { row, col, ch } => write!(
+                f,
+                "unknown maze character {:?} at row {}, column {}",
+                ch, row, col
+            ),
+            MazeError::WrongWidth {
+                row,
+                width,
+                expected,
+            } => write!(
+                f,
+                "maze row {} is {} tiles wide, but the first row is {}",
+                row, width, expected
+            ),
+            MazeError::NoGhostHouse => write!(f, "maze has no ghost house (G)"),
+            MazeError::DuplicateMarker { row, col, ch } => write!(
+                f,
+                "landmark {:?} at row {}, column {} is already placed",
+                ch, row, col
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MazeError {}
+
+/// Landmark markers, read by `MazeMetadata::from_rows`.
+const LANDMARKS: &str = "@FbpicBPIC";
+
+/// Parse newline-separated layout text strictly: every character must be
+/// in the legend, every row as wide as the first, each landmark placed at
+/// most once, and there must be a ghost house. Unlike `Maze::from_layout`, nothing is padded or guessed.
+impl FromStr for Maze {
+    type Err = MazeError;
+
+    fn from_str(layout: &str) -> Result<Maze, MazeError> {
+        if layout.trim().is_empty() {
+            return Err(MazeError::Empty);
+        }
+        let rows: Vec<&str> = layout.lines().collect();
+        let expected = rows[0].chars().count();
+        for (row, line) in rows.iter().enumerate() {
+            if let Some((col, ch)) = line
+                .chars()
+                .enumerate()
+                .find(|&(_, ch)| CellType::parse_char(ch).is_none())
+            {
+                return Err(MazeError::BadChar { row, col, ch });
+            }
+            let width = line.chars().count();
+            if width != expected {
+                return Err(MazeError::WrongWidth {
+                    row,
+                    width,
+                    expected,
+                });
+            }
+        }
+        let mut placed = Vec::new();
+        for (row, line) in rows.iter().enumerate() {
+            for (col, ch) in line.chars().enumerate() {
+                if !LANDMARKS.contains(ch) {
+                    continue;
+                }
+                if placed.contains(&ch) {
+                    return Err(MazeError::DuplicateMarker { row, col, ch });
+                }
+                placed.push(ch);
+            }
+        }
+        let maze = Maze::from_rows(&rows, expected);
+        if maze.positions_of(CellType::GhostHouse).is_empty() {
+            return Err(MazeError::NoGhostHouse);
+        }
+        Ok(maze)
+    }
 }
 
 // ─── Tests ──────────────────────────────────────────────────────────────────
@@ -163,6 +1117,7 @@ impl Maze {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::{GameMode, GameStateInner};
 
     #[test]
     fn maze_has_correct_dimensions() {
@@ -182,6 +1137,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn maze_bytes_are_row_major_cell_codes() {
+        let maze = Maze::new();
+        let bytes = maze.to_bytes();
+        assert_eq!(bytes.len(), MAZE_WIDTH * MAZE_HEIGHT);
+        assert_eq!(bytes[0], CellType::Wall.code());
+        let (row, col) = maze.positions_of(CellType::PowerPellet)[0];
+        assert_eq!(bytes[row * MAZE_WIDTH + col], 3);
+        assert_eq!(CellType::OneWay(Direction::Left).code(), 18);
+        assert_eq!(CellType::LockedDoor(2).code(), 42);
+    }
+
+    #[test]
+    fn byte_view_tracks_set_cell_in_place() {
+        let mut maze = Maze::new();
+        let ptr = maze.byte_view().as_ptr();
+        maze.set_cell(1, 1, CellType::Empty);
+        assert_eq!(maze.byte_view().as_ptr(), ptr);
+        let expected = maze.to_bytes();
+        assert_eq!(maze.byte_view(), expected.as_slice());
+
+        let json = serde_json::to_string(&maze).unwrap();
+        let mut loaded: Maze = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.byte_view(), expected.as_slice());
+    }
+
+    #[test]
+    fn saved_ids_outside_the_legend_are_refused() {
+        let load = |json: &str| serde_json::from_str::<CellType>(json);
+        assert_eq!(load(r#"{"Warp":9}"#).unwrap(), CellType::Warp(9));
+        assert_eq!(load(r#"{"LockedDoor":2}"#).unwrap().code(), 42);
+        for json in [
+            r#"{"Warp":0}"#,
+            r#"{"Warp":10}"#,
+            r#"{"Warp":255}"#,
+            r#"{"Key":3}"#,
+            r#"{"LockedDoor":230}"#,
+        ] {
+            assert!(load(json).is_err(), "{}", json);
+        }
+    }
+
     #[test]
     fn maze_corners_are_walls() {
         let maze = Maze::new();
@@ -224,9 +1221,308 @@ mod tests {
         assert!(ghost_cells > 0, "Maze should have a ghost house");
     }
 
+    #[test]
+    fn from_rows_pads_short_rows() {
+        let maze = Maze::from_rows(&["WWWW", "W.", "WWWW"], 4);
+        assert_eq!(maze.height, 3);
+        assert_eq!(maze.width, 4);
+        assert_eq!(maze.get_cell(1, 1), Some(CellType::Dot));
+        assert_eq!(maze.get_cell(1, 3), Some(CellType::Empty));
+    }
+
+    #[test]
+    fn landmark_markers_fill_the_metadata() {
+        let maze: Maze = [
+            "WWWWWWWWW",
+            "WB..b..PW",
+            "W.WW-WW.W",
+            "W.WpicW.W",
+            "W.WWWWW.W",
+            "WI..@F.CW",
+            "WWWWWWWWW",
+        ]
+        .join("\n")
+        .parse()
+        .unwrap();
+        let meta = &maze.metadata;
+        assert_eq!(meta.pacman_spawn, (4.0, 5.0));
+        assert_eq!(meta.fruit_spawn, (5.0, 5.0));
+        assert_eq!(meta.house_exit, (4.0, 1.0));
+        assert_eq!(meta.house_slots, vec![(3.0, 3.0), (4.0, 3.0), (5.0, 3.0)]);
+        assert_eq!(meta.house_center, (4, 3));
+        assert_eq!(meta.scatter_targets, [(1, 1), (7, 1), (1, 5), (7, 5)]);
+        assert_eq!(maze.get_cell(3, 4), Some(CellType::GhostHouse));
+        assert_eq!(maze.get_cell(5, 4), Some(CellType::Empty));
+        // No markers, classic landmarks
+        assert_eq!(Maze::new().metadata, MazeMetadata::default());
+
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.load_maze(maze);
+        assert_eq!(state.pacman.position.to_grid(), (4, 5));
+        assert_eq!(state.ghosts[0].position.to_grid(), (4, 1));
+        assert_eq!(state.ghosts[2].position.to_grid(), (4, 3));
+    }
+
+    #[test]
+    fn from_str_checks_the_layout() {
+        let maze: Maze = "WWWWW\nW.G.W\nWWWWW\n".parse().unwrap();
+        assert_eq!((maze.width, maze.height), (5, 3));
+        assert_eq!(maze.get_cell(1, 2), Some(CellType::GhostHouse));
+
+        assert_eq!(Maze::from_str("  \n").unwrap_err(), MazeError::Empty);
+        assert_eq!(
+            Maze::from_str("WWWWW\nW.G?W\nWWWWW").unwrap_err(),
+            MazeError::BadChar {
+                row: 1,
+                col: 3,
+                ch: '?'
+            }
+        );
+        assert_eq!(
+            Maze::from_str("WWWWW\nW.G.W\nWWWW").unwrap_err(),
+            MazeError::WrongWidth {
+                row: 2,
+                width: 4,
+                expected: 5
+            }
+        );
+        assert_eq!(
+            Maze::from_str("WWWWW\nW...W\nWWWWW").unwrap_err(),
+            MazeError::NoGhostHouse
+        );
+        assert_eq!(
+            Maze::from_str("WWWWW\nWB.BW\nWWGWW").unwrap_err(),
+            MazeError::DuplicateMarker {
+                row: 1,
+                col: 3,
+                ch: 'B'
+            }
+        );
+        assert_eq!(
+            MazeError::BadChar {
+                row: 1,
+                col: 3,
+                ch: '?'
+            }
+            .to_string(),
+            "unknown maze character '?' at row 1, column 3"
+        );
+    }
+
+    #[test]
+    fn house_door_only_opens_for_ghosts_allowed_through() {
+        let maze = Maze::new();
+        assert_eq!(CellType::from_char('-'), CellType::Door);
+        assert_eq!(maze.get_cell(12, 13), Some(CellType::Door));
+        assert_eq!(maze.get_cell(12, 14), Some(CellType::Door));
+
+        // Standing above the door at (14, 11)
+        assert!(!maze.is_walkable(14.0, 12.0));
+        assert!(!maze.can_move(14.0, 11.0, Direction::Down));
+        assert!(!maze.can_move_ghost(14.0, 11.0, Direction::Down, false));
+        assert!(maze.can_move_ghost(14.0, 11.0, Direction::Down, true));
+        assert!(maze.can_move_ghost(14.0, 12.0, Direction::Down, true));
+    }
+
+    #[test]
+    fn route_step_finds_the_way_into_the_house() {
+        let maze = Maze::new();
+        // Above the door: straight down, but only for ghosts allowed through
+        assert_eq!(
+            maze.route_step((14, 11), (14, 14), true),
+            Some(Direction::Down)
+        );
+        assert_eq!(maze.route_step((14, 11), (14, 14), false), None);
+        // From the left end of row 11, head right toward the door
+        assert_eq!(
+            maze.route_step((9, 11), (14, 14), true),
+            Some(Direction::Right)
+        );
+        assert_eq!(maze.route_step((14, 14), (14, 14), true), None);
+    }
+
+    #[test]
+    fn classic_tunnels_are_the_side_passages_of_row_14() {
+        let maze = Maze::new();
+        let mut tunnels = maze.tunnels.clone();
+        tunnels.sort();
+        let expected: Vec<(usize, usize)> = (0..6).chain(22..28).map(|col| (14, col)).collect();
+        assert_eq!(tunnels, expected);
+
+        assert!(maze.is_tunnel(2.0, 14.0));
+        assert!(maze.is_tunnel(-0.6, 14.0));
+        assert!(!maze.is_tunnel(6.0, 14.0));
+        assert!(!maze.is_tunnel(1.0, 1.0));
+    }
+
+    #[test]
+    fn tunnels_can_be_marked_by_hand() {
+        let mut maze = Maze::from_rows(&["WWWW", "....", "WWWW"], 4);
+        assert_eq!(maze.tunnels.len(), 4);
+        maze.set_tunnel(1, 1, false);
+        maze.set_tunnel(1, 1, false);
+        assert!(!maze.is_tunnel(1.0, 1.0));
+        maze.set_tunnel(1, 1, true);
+        assert!(maze.is_tunnel(1.0, 1.0));
+    }
+
+    #[test]
+    fn classic_maze_has_four_no_up_tiles() {
+        let maze = Maze::new();
+        assert_eq!(maze.no_up.len(), 4);
+        for &(row, col) in &maze.no_up {
+            // Each one is a real choice with an upward exit to forbid
+            assert!(maze.is_intersection(col, row));
+            assert!(maze.can_move(col as f64, row as f64, Direction::Up));
+        }
+        assert!(Maze::from_rows(&["...."], 4).no_up.is_empty());
+    }
+
     #[test]
     fn get_cell_out_of_bounds_returns_none() {
         let maze = Maze::new();
         assert_eq!(maze.get_cell(100, 100), None);
     }
+
+    #[test]
+    fn one_way_cells_are_parsed_from_arrows() {
+        assert_eq!(CellType::from_char('^'), CellType::OneWay(Direction::Up));
+        assert_eq!(CellType::from_char('v'), CellType::OneWay(Direction::Down));
+        assert_eq!(CellType::from_char('<'), CellType::OneWay(Direction::Left));
+        assert_eq!(CellType::from_char('>'), CellType::OneWay(Direction::Right));
+    }
+
+    #[test]
+    fn one_way_gate_only_passes_in_its_direction() {
+        let mut maze = Maze::new();
+        // Row 5 is an open corridor; put a right-pointing gate at col 5
+        maze.cells[5][5] = CellType::OneWay(Direction::Right);
+
+        assert!(maze.can_move(4.0, 5.0, Direction::Right));
+        assert!(!maze.can_move(6.0, 5.0, Direction::Left));
+        // Standing on the gate: forward is fine, backing out is not
+        assert!(maze.can_move(5.0, 5.0, Direction::Right));
+        assert!(!maze.can_move(5.0, 5.0, Direction::Left));
+    }
+
+    #[test]
+    fn ice_forbids_steering_until_blocked() {
+        let mut maze = Maze::new();
+        assert_eq!(CellType::from_char('_'), CellType::Ice);
+        maze.cells[5][5] = CellType::Ice;
+        maze.cells[5][26] = CellType::Ice;
+
+        assert!(maze.is_walkable(5.0, 5.0));
+        assert!(!maze.can_steer(5.0, 5.0, Direction::Right));
+        // Col 27 is the outer wall, so sliding right stops at col 26
+        assert!(maze.can_steer(26.0, 5.0, Direction::Right));
+        assert!(maze.can_steer(4.0, 5.0, Direction::Right));
+    }
+
+    #[test]
+    fn only_interior_walls_between_corridors_are_breakable() {
+        let mut maze = Maze::new();
+        // Row 20 has a double wall at cols 13–14; thin it to one tile
+        maze.cells[20][14] = CellType::Dot;
+
+        assert_eq!(
+            maze.breakable_wall(12.0, 20.0, Direction::Right),
+            Some((20, 13))
+        );
+        // Outer border
+        assert_eq!(maze.breakable_wall(1.0, 20.0, Direction::Left), None);
+        // Thick wall block with nothing walkable behind it
+        assert_eq!(maze.breakable_wall(1.0, 20.0, Direction::Down), None);
+    }
+
+    #[test]
+    fn timed_walls_block_only_while_closed() {
+        let mut maze = Maze::new();
+        maze.cells[5][5] = CellType::from_char('#');
+        maze.cells[5][6] = CellType::from_char('=');
+
+        assert!(!maze.is_walkable(5.0, 5.0));
+        assert!(maze.is_walkable(6.0, 5.0));
+        assert_eq!(maze.timed_walls(), vec![(5, 5), (5, 6)]);
+    }
+
+    #[test]
+    fn switch_links_accumulate_gates() {
+        let mut maze = Maze::new();
+        assert_eq!(CellType::from_char('s'), CellType::Switch);
+        assert_eq!(CellType::from_char('|'), CellType::Gate { open: false });
+
+        maze.link_switch((5, 1), &[(1, 1)]);
+        maze.link_switch((5, 1), &[(1, 2)]);
+        assert_eq!(maze.linked_gates(5, 1), &[(1, 1), (1, 2)]);
+        assert!(maze.linked_gates(5, 2).is_empty());
+    }
+
+    #[test]
+    fn locked_doors_are_walls_keyed_by_letter() {
+        let mut maze = Maze::new();
+        assert_eq!(CellType::from_char('y'), CellType::Key(1));
+        assert_eq!(CellType::from_char('Y'), CellType::LockedDoor(1));
+
+        maze.cells[5][5] = CellType::LockedDoor(1);
+        maze.cells[5][9] = CellType::LockedDoor(1);
+        assert!(!maze.is_walkable(5.0, 5.0));
+        assert_eq!(
+            maze.positions_of(CellType::LockedDoor(1)),
+            vec![(5, 5), (5, 9)]
+        );
+    }
+
+    #[test]
+    fn junctions_have_three_or_more_exits() {
+        let maze = Maze::new();
+        // (col 6, row 5): corridor crossing with exits up, down, left, right
+        assert!(maze.is_intersection(6, 5));
+        // (col 2, row 5): straight horizontal corridor
+        assert!(!maze.is_intersection(2, 5));
+        // Walls are never junctions
+        assert!(!maze.is_intersection(0, 0));
+    }
+
+    #[test]
+    fn set_cell_keeps_junction_cache_fresh() {
+        let mut maze = Maze::new();
+        // Row 5 corridor with a wall above col 3; opening it makes a T-junction
+        assert!(!maze.is_intersection(3, 5));
+        maze.set_cell(4, 3, CellType::Empty);
+        assert!(maze.is_intersection(3, 5));
+    }
+
+    #[test]
+    fn junction_bitmap_matches_queries() {
+        let maze = Maze::new();
+        let bitmap = maze.junction_bitmap();
+        assert_eq!(bitmap.len(), (MAZE_WIDTH * MAZE_HEIGHT).div_ceil(8));
+        let i = 5 * MAZE_WIDTH + 6;
+        assert_eq!(bitmap[i / 8] & (1 << (i % 8)), 1 << (i % 8));
+    }
+
+    #[test]
+    fn warp_cells_find_their_partner() {
+        let mut maze = Maze::new();
+        assert_eq!(CellType::from_char('3'), CellType::Warp(3));
+        maze.cells[5][1] = CellType::Warp(3);
+        maze.cells[29][26] = CellType::Warp(3);
+
+        assert_eq!(maze.warp_partner(5, 1), Some((29, 26)));
+        assert_eq!(maze.warp_partner(29, 26), Some((5, 1)));
+        assert_eq!(maze.warp_partner(5, 2), None);
+    }
+
+    #[test]
+    fn slow_cells_use_the_maze_multiplier() {
+        let mut maze = Maze::new();
+        assert_eq!(CellType::from_char('~'), CellType::Slow);
+        maze.cells[5][5] = CellType::Slow;
+        maze.slow_multiplier = 0.25;
+
+        assert!(maze.is_walkable(5.0, 5.0));
+        assert_eq!(maze.surface_at(5.0, 5.0).speed_multiplier, 0.25);
+        assert_eq!(maze.surface_at(6.0, 5.0).speed_multiplier, 1.0);
+    }
 }
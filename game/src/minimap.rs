@@ -0,0 +1,119 @@
+// game/src/minimap.rs
+//
+// A downsampled summary of the board for HUD radars.
+//
+// Each minimap cell covers a `MINIMAP_SCALE`×`MINIMAP_SCALE` block of maze
+// tiles (28×31 → 14×16), so JS can draw a radar from one small byte array
+// instead of walking the full maze and entity list every frame.
+
+use crate::maze::CellType;
+use crate::state::GameStateInner;
+
+// ─── Encoding ───────────────────────────────────────────────────────────────
+
+/// Maze tiles per minimap cell along each axis.
+pub const MINIMAP_SCALE: usize = 2;
+
+/// Low nibble: number of dots/power pellets left in the block (0–4).
+pub const MINIMAP_DOTS_MASK: u8 = 0x0F;
+/// The block is mostly wall.
+pub const MINIMAP_WALL: u8 = 0x10;
+/// Pac-Man is in the block.
+pub const MINIMAP_PACMAN: u8 = 0x20;
+/// At least one ghost is in the block.
+pub const MINIMAP_GHOST: u8 = 0x40;
+
+impl GameStateInner {
+    /// Minimap dimensions (columns, rows) for the current maze.
+    pub fn minimap_size(&self) -> (usize, usize) {
+        (
+            self.maze.width.div_ceil(MINIMAP_SCALE),
+            self.maze.height.div_ceil(MINIMAP_SCALE),
+        )
+    }
+
+    /// Build the minimap grid, row-major, one byte per cell.
+    ///
+    /// See the `MINIMAP_*` constants for the bit layout.
+    pub fn minimap(&self) -> Vec<u8> {
+        let (width, height) = self.minimap_size();
+        let mut grid = vec![0u8; width * height];
+
+        for (row, cells) in self.maze.cells.iter().enumerate() {
+            for (col, cell) in cells.iter().enumerate() {
+                let i = (row / MINIMAP_SCALE) * width + col / MINIMAP_SCALE;
+                match cell {
+                    CellType::Dot | CellType::PowerPellet => grid[i] += 1,
+                    // Count walls in the high nibble for now; resolved below
+                    CellType::Wall => grid[i] += 1 << 4,
+                    _ => {}
+                }
+            }
+        }
+
+        // A block is drawn as wall when walls fill more than half of it
+        for (i, byte) in grid.iter_mut().enumerate() {
+            let (col, row) = (i % width, i / width);
+            let tiles = block_len(col, self.maze.width) * block_len(row, self.maze.height);
+            let walls = (*byte >> 4) as usize;
+            *byte &= MINIMAP_DOTS_MASK;
+            if walls * 2 > tiles {
+                *byte |= MINIMAP_WALL;
+            }
+        }
+
+        let mut mark = |col: usize, row: usize, flag: u8| {
+            let (col, row) = (col / MINIMAP_SCALE, row / MINIMAP_SCALE);
+            if col < width && row < height {
+                grid[row * width + col] |= flag;
+            }
+        };
+        let (col, row) = self.pacman.position.to_grid();
+        mark(col, row, MINIMAP_PACMAN);
+        for ghost in &self.ghosts {
+            let (col, row) = ghost.position.to_grid();
+            mark(col, row, MINIMAP_GHOST);
+        }
+
+        grid
+    }
+}
+
+/// Number of maze tiles covered by minimap block `index` along an axis of
+/// `len` tiles (the last block may be partial).
+fn block_len(index: usize, len: usize) -> usize {
+    MINIMAP_SCALE.min(len - index * MINIMAP_SCALE)
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::GameMode;
+
+    #[test]
+    fn classic_minimap_is_14_by_16() {
+        let gs = GameStateInner::new(GameMode::Classic);
+        assert_eq!(gs.minimap_size(), (14, 16));
+        assert_eq!(gs.minimap().len(), 14 * 16);
+    }
+
+    #[test]
+    fn minimap_encodes_walls_dots_and_entities() {
+        let gs = GameStateInner::new(GameMode::Classic);
+        let map = gs.minimap();
+
+        // The top-left corner block is solid wall
+        assert_eq!(map[0] & MINIMAP_WALL, MINIMAP_WALL);
+
+        // Dot counts across the map add up to the dots on the board
+        let dots: usize = map.iter().map(|b| (b & MINIMAP_DOTS_MASK) as usize).sum();
+        assert_eq!(dots, gs.maze.dots_remaining());
+
+        // Pac-Man at (14, 23) → block (7, 11)
+        assert_ne!(map[11 * 14 + 7] & MINIMAP_PACMAN, 0);
+        // Blinky at (14, 11) → block (7, 5)
+        assert_ne!(map[5 * 14 + 7] & MINIMAP_GHOST, 0);
+    }
+}
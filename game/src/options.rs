@@ -0,0 +1,360 @@
+// game/src/options.rs
+//
+// Options for starting a new game, as one object.
+//
+// `GameConfig` holds the rules a player tweaks and keeps between games;
+// `GameOptions` is what an integrator passes once to set a game up — how
+// many lives, which level and maze, how hard. Every field has a default, so
+// JS only sets what it cares about:
+//
+// ```text
+// GameState.with_options({ lives: 5, level: 3, difficulty: "Hard" })
+// ```
+//
+// Options that outlive the start of the game (lives, ghost count, speeds,
+// scoring, seed) are written into the game's `GameConfig`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::assist::Assist;
+use crate::config::{GameConfig, ScoringConfig};
+use crate::entities::{GhostType, MAX_GHOSTS};
+use crate::layouts::MazePolicy;
+use crate::maze::Maze;
+use crate::state::{GameMode, GameStateInner};
+
+// ─── Difficulty ─────────────────────────────────────────────────────────────
+
+/// A quick difficulty setting, applied on top of `ghost_speed`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Difficulty {
+    /// Ghosts at 85% speed
+    Easy,
+    /// The speed tables as they are
+    Normal,
+    /// Ghosts at 110% speed
+    Hard,
+}
+
+impl Difficulty {
+    /// Factor on ghost speeds.
+    pub fn ghost_speed(self) -> f64 {
+        match self {
+            Difficulty::Easy => 0.85,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.1,
+        }
+    }
+}
+
+// ─── Game options ───────────────────────────────────────────────────────────
+
+/// Everything `GameState::with_options` can set up.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameOptions {
+    pub mode: GameMode,
+    /// Lives at the start, including the one in play (1–255).
+    pub lives: u8,
+    /// Level to start on (1 or more).
+    pub level: u32,
+    /// Seed for the game's random choices.
+    pub seed: u32,
+    pub difficulty: Difficulty,
+    /// Layout text for a custom maze (legend as in `CellType::from_char`),
+    /// checked by `Maze::from_str` and `Maze::validate`; `None` plays the
+    /// classic maze.
+    pub maze: Option<String>,
+    /// Which maze each level is played on: `"Generated"` makes a new one
+    /// each level. A custom `maze` needs `"Classic"`, which keeps it.
+    pub maze_policy: MazePolicy,
+    /// Mirror the maze left to right, spawn points and all, every level.
+    pub mirror: bool,
+    /// Turn the maze upside down, spawn points and all, every level.
+    pub flip: bool,
+    /// Number of ghosts (1–8), in the order Blinky, Pinky, Inky, Clyde,
+    /// then the same again.
+    pub ghosts: u8,
+    /// The ghosts' personalities, in roster order (1–8, repeats allowed);
+    /// overrides `ghosts` when not empty.
+    pub roster: Vec<GhostType>,
+    /// Multipliers on the speed tables (greater than 0).
+    pub pacman_speed: f64,
+    pub ghost_speed: f64,
+    /// Points for dots, pellets, ghosts and fruit.
+    pub scoring: ScoringConfig,
+    pub assist: Assist,
+}
+
+impl Default for GameOptions {
+    fn default() -> Self {
+        GameOptions {
+            mode: GameMode::Classic,
+            lives: 3,
+            level: 1,
+            seed: 0,
+            difficulty: Difficulty::Normal,
+            maze: None,
+            maze_policy: MazePolicy::Classic,
+            mirror: false,
+            flip: false,
+            ghosts: 4,
+            roster: Vec::new(),
+            pacman_speed: 1.0,
+            ghost_speed: 1.0,
+            scoring: ScoringConfig::default(),
+            assist: Assist::default(),
+        }
+    }
+}
+
+impl GameOptions {
+    /// Why these options can't start a game, if they can't.
+    pub fn check(&self) -> Result<(), String> {
+        if self.lives == 0 {
+            return Err("lives must be at least 1".to_string());
+        }
+        if self.level == 0 {
+            return Err("level must be at least 1".to_string());
+        }
+        if !(1..=MAX_GHOSTS as u8).contains(&self.ghosts) {
+            return Err(format!(
+                "ghosts must be 1–{}, not {}",
+                MAX_GHOSTS, self.ghosts
+            ));
+        }
+        if self.roster.len() > MAX_GHOSTS {
+            return Err(format!(
+                "roster has {} ghosts; at most {} can play",
+                self.roster.len(),
+                MAX_GHOSTS
+            ));
+        }
+        for (name, speed) in [
+            ("pacman_speed", self.pacman_speed),
+            ("ghost_speed", self.ghost_speed),
+        ] {
+            if !(speed.is_finite() && speed > 0.0) {
+                return Err(format!("{} must be greater than 0, not {}", name, speed));
+            }
+        }
+        if self.maze.is_some() && self.maze_policy != MazePolicy::Classic {
+            return Err(format!(
+                "a custom maze is played every level; maze_policy {:?} can't apply",
+                self.maze_policy
+            ));
+        }
+        self.assist.check()
+    }
+}
+
+impl GameStateInner {
+    /// Start a game set up by `options`.
+    pub fn with_options(options: GameOptions) -> Result<GameStateInner, String> {
+        options.check()?;
+        let config = GameConfig {
+            seed: options.seed,
+            starting_lives: options.lives,
+            scoring: options.scoring.clone(),
+            ghost_count: options.ghosts,
+            ghost_roster: options.roster.clone(),
+            pacman_speed: options.pacman_speed,
+            ghost_speed: options.ghost_speed * options.difficulty.ghost_speed(),
+            maze_policy: options.maze_policy,
+            mirror: options.mirror,
+            flip: options.flip,
+            assist: options.assist.clone(),
+            ..GameConfig::default()
+        };
+
+        let mut state = GameStateInner::with_config(options.mode, config);
+        state.level = options.level;
+        let maze = match &options.maze {
+            Some(layout) => {
+                let maze = layout.parse::<Maze>().map_err(|e| e.to_string())?;
+                let report = maze.validate();
+                if !report.is_valid() {
+                    return Err(report.to_string());
+                }
+                Some(maze.oriented(options.mirror, options.flip))
+            }
+            None => state.maze_for_level(options.level),
+        };
+        match maze {
+            Some(maze) => state.load_maze(maze),
+            None => state.reset_board(),
+        }
+        Ok(state)
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::HouseState;
+    use crate::layouts::MazeLayout;
+    use crate::levels::SpeedTable;
+    use crate::maze::CellType;
+    use crate::state::GamePhase;
+
+    #[test]
+    fn options_set_up_the_game() {
+        let options: GameOptions = serde_json::from_str(
+            r#"{ "lives": 5, "level": 3, "ghosts": 2, "difficulty": "Easy", "seed": 7 }"#,
+        )
+        .unwrap();
+        let state = GameStateInner::with_options(options).unwrap();
+
+        assert_eq!(state.pacman.lives, 5);
+        assert_eq!(state.level, 3);
+        assert_eq!(state.speeds(), SpeedTable::for_level(3));
+        assert_eq!(state.ghosts.len(), 2);
+        assert_eq!(state.config.seed, 7);
+        assert_eq!(state.config.ghost_speed, 0.85);
+    }
+
+    #[test]
+    fn custom_maze_is_loaded() {
+        let options = GameOptions {
+            maze: Some("WWWWWWW\nW@oFb.W\nWWW-WWW\nWWpicWW\nWWWWWWW".to_string()),
+            ..GameOptions::default()
+        };
+        let state = GameStateInner::with_options(options).unwrap();
+        assert_eq!((state.maze.width, state.maze.height), (7, 5));
+        assert_eq!(state.dots_remaining, 2);
+
+        // Ragged rows are refused, not padded, and so are unplayable boards
+        for layout in ["WWWWW\nW.o\nWWGWW", "WWWWW\nW.o.W\nWWGWW"] {
+            let bad = GameOptions {
+                maze: Some(layout.to_string()),
+                ..GameOptions::default()
+            };
+            assert!(GameStateInner::with_options(bad).is_err(), "{}", layout);
+        }
+    }
+
+    #[test]
+    fn mirrored_and_flipped_mazes_move_their_landmarks() {
+        let options = GameOptions {
+            mirror: true,
+            flip: true,
+            ..GameOptions::default()
+        };
+        let mut state = GameStateInner::with_options(options).unwrap();
+        let meta = &state.maze.metadata;
+        // 28×31: x → 27 − x, y → 30 − y
+        assert_eq!(meta.pacman_spawn, (13.0, 7.0));
+        assert_eq!(meta.house_exit, (13.0, 19.0));
+        assert_eq!(meta.scatter_targets[0], (2, 33));
+        assert_eq!(
+            (state.pacman.position.x, state.pacman.position.y),
+            (13.0, 7.0)
+        );
+        assert_eq!(state.ghosts[0].position.y, 19.0);
+        assert_eq!(state.maze.get_cell(18, 13), Some(CellType::Door));
+        assert!(state.maze.is_no_up(15, 19));
+
+        // Ghosts leave the upside-down house downward, and play goes on
+        state.phase = GamePhase::Playing;
+        state.house_dot_timer = 100.0;
+        for _ in 0..120 {
+            state.tick(1.0 / 60.0);
+        }
+        assert_eq!(state.ghosts[1].house, HouseState::Outside);
+        state.reset();
+        assert_eq!(
+            (state.pacman.position.x, state.pacman.position.y),
+            (13.0, 7.0)
+        );
+
+        // The turn carries over to the boards later levels bring
+        let options = GameOptions {
+            flip: true,
+            maze_policy: MazePolicy::Rotate,
+            ..GameOptions::default()
+        };
+        let mut state = GameStateInner::with_options(options).unwrap();
+        state.advance_level();
+        assert_eq!(state.maze.metadata.pacman_spawn, (14.0, 7.0));
+        let arches = Maze::builtin(MazeLayout::Arches).oriented(false, true);
+        assert_eq!(state.maze.cells, arches.cells);
+        state.reset();
+        assert_eq!(state.maze.metadata.pacman_spawn, (14.0, 7.0));
+    }
+
+    #[test]
+    fn a_custom_maze_needs_the_classic_policy() {
+        let layout = "WWWWWWW\nW@oFb.W\nWWW-WWW\nWWpicWW\nWWWWWWW";
+        let options = GameOptions {
+            maze: Some(layout.to_string()),
+            maze_policy: MazePolicy::Rotate,
+            ..GameOptions::default()
+        };
+        assert!(GameStateInner::with_options(options).is_err());
+
+        // Under the classic policy it survives level ups and restarts
+        let options = GameOptions {
+            maze: Some(layout.to_string()),
+            ..GameOptions::default()
+        };
+        let mut state = GameStateInner::with_options(options).unwrap();
+        let custom = state.maze.cells.clone();
+        state.advance_level();
+        assert_eq!(state.maze.cells, custom);
+        state.reset();
+        assert_eq!(state.maze.cells, custom);
+    }
+
+    #[test]
+    fn out_of_range_options_are_rejected() {
+        let bad = [
+            GameOptions {
+                lives: 0,
+                ..GameOptions::default()
+            },
+            GameOptions {
+                ghosts: 9,
+                ..GameOptions::default()
+            },
+            GameOptions {
+                ghost_speed: f64::NAN,
+                ..GameOptions::default()
+            },
+        ];
+        for options in bad {
+            assert!(GameStateInner::with_options(options).is_err());
+        }
+    }
+
+    #[test]
+    fn scoring_and_lives_outlive_a_restart() {
+        let options: GameOptions =
+            serde_json::from_str(r#"{ "lives": 1, "scoring": { "dot": 25 } }"#).unwrap();
+        let mut state = GameStateInner::with_options(options).unwrap();
+        assert_eq!(state.config.scoring.ghost, 200);
+
+        state.phase = GamePhase::Playing;
+        state.pacman.position.x = 13.0;
+        state.tick(1.0 / 60.0);
+        assert_eq!(state.pacman.score, 25);
+
+        state.reset();
+        assert_eq!(state.pacman.lives, 1);
+    }
+
+    #[test]
+    fn huge_point_values_stop_at_the_top_score() {
+        let options: GameOptions =
+            serde_json::from_str(r#"{ "scoring": { "dot": 4294967295 } }"#).unwrap();
+        let mut state = GameStateInner::with_options(options).unwrap();
+        state.phase = GamePhase::Playing;
+        state.pacman.position.x = 13.0;
+        state.tick(1.0 / 60.0);
+        state.pacman.position.x = 12.0;
+        state.tick(1.0 / 60.0);
+        assert_eq!(state.score_breakdown.dots, u32::MAX);
+        assert_eq!(state.pacman.score, u32::MAX);
+    }
+}
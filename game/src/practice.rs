@@ -0,0 +1,189 @@
+// game/src/practice.rs
+//
+// Practice games that start from a chosen situation.
+//
+// Speedrunners drilling a route and AI developers testing a policy need the
+// same mid-game situation again and again, without playing up to it. A
+// `PracticeSetup` says where the game starts — level, positions, which
+// dots are already gone, how far into the scatter/chase schedule — and
+// `GameState.practice()` builds it on top of `ScenarioBuilder`:
+//
+// ```text
+// GameState.practice({
+//   level: 5,
+//   pacman: { col: 6, row: 5, direction: "Right" },
+//   ghosts: [{ ghost: "Blinky", col: 21, row: 5, mode: "Chase" }],
+//   eaten: [[5, 7], [5, 8]],
+//   wave_offset: 27,
+// })
+// ```
+//
+// The game starts in `Ready`, like any other, and plays on normally from
+// there; the next level refills the full board.
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::GameConfig;
+use crate::entities::{Direction, GhostMode, GhostPick, GhostType};
+use crate::scenario::{check_placements, ScenarioBuilder};
+use crate::state::{GameMode, GameStateInner};
+
+// ─── Setup ──────────────────────────────────────────────────────────────────
+
+/// Where Pac-Man starts.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PacManPlacement {
+    pub col: usize,
+    pub row: usize,
+    pub direction: Direction,
+}
+
+/// Where a ghost starts (outside the house), and in which mode.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GhostPlacement {
+    pub ghost: GhostType,
+    pub col: usize,
+    pub row: usize,
+    pub mode: GhostMode,
+    /// Which ghost in the roster to place, for rosters that repeat a
+    /// personality; it must be a `ghost`. Without it, the first `ghost`.
+    #[serde(default)]
+    pub index: Option<usize>,
+}
+
+impl GhostPlacement {
+    pub(crate) fn pick(&self) -> GhostPick {
+        match self.index {
+            Some(i) => GhostPick::Index(i),
+            None => GhostPick::Type(self.ghost),
+        }
+    }
+}
+
+/// A practice game's starting situation. Every field has a default: an
+/// empty setup is a fresh level 1.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PracticeSetup {
+    pub mode: GameMode,
+    /// Level to start on (1 or more).
+    pub level: u32,
+    /// Lives at the start, including the one in play (1–255).
+    pub lives: u8,
+    pub seed: u32,
+    /// Pac-Man's start; `None` keeps his spawn.
+    pub pacman: Option<PacManPlacement>,
+    /// Ghosts left out stay in (or above) the house as usual.
+    pub ghosts: Vec<GhostPlacement>,
+    /// (row, col) tiles whose dot or power pellet is already eaten.
+    pub eaten: Vec<(usize, usize)>,
+    /// Seconds into the level's scatter/chase schedule.
+    pub wave_offset: f64,
+}
+
+impl Default for PracticeSetup {
+    fn default() -> Self {
+        PracticeSetup {
+            mode: GameMode::Classic,
+            level: 1,
+            lives: 3,
+            seed: 0,
+            pacman: None,
+            ghosts: Vec::new(),
+            eaten: Vec::new(),
+            wave_offset: 0.0,
+        }
+    }
+}
+
+impl GameStateInner {
+    /// Start a game in the situation `setup` describes.
+    pub fn practice(setup: PracticeSetup) -> Result<GameStateInner, String> {
+        if setup.level == 0 {
+            return Err("level must be at least 1".to_string());
+        }
+        if setup.lives == 0 {
+            return Err("lives must be at least 1".to_string());
+        }
+        if !(setup.wave_offset.is_finite() && setup.wave_offset >= 0.0) {
+            return Err(format!(
+                "wave_offset must be 0 or more, not {}",
+                setup.wave_offset
+            ));
+        }
+
+        let config = GameConfig {
+            seed: setup.seed,
+            starting_lives: setup.lives,
+            ..GameConfig::default()
+        };
+        let mut builder = ScenarioBuilder::new(setup.mode)
+            .config(config)
+            .level(setup.level)
+            .global_timer(setup.wave_offset)
+            .eaten_at(&setup.eaten);
+        if let Some(pac) = &setup.pacman {
+            builder = builder.pacman_at(pac.row, pac.col, pac.direction);
+        }
+        for ghost in &setup.ghosts {
+            builder = builder.ghost(ghost.pick(), ghost.row, ghost.col, ghost.mode);
+        }
+        let state = builder.build();
+        check_placements(&state, setup.pacman.as_ref(), &setup.ghosts, &setup.eaten)?;
+        Ok(state)
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maze::CellType;
+    use crate::state::GamePhase;
+
+    #[test]
+    fn setup_starts_mid_game() {
+        let setup: PracticeSetup = serde_json::from_str(
+            r#"{
+                "level": 5,
+                "pacman": { "col": 6, "row": 5, "direction": "Right" },
+                "ghosts": [{ "ghost": "Pinky", "col": 21, "row": 5, "mode": "Chase" }],
+                "eaten": [[5, 7], [5, 8]],
+                "wave_offset": 27
+            }"#,
+        )
+        .unwrap();
+        let fresh = GameStateInner::new(GameMode::Classic);
+        let state = GameStateInner::practice(setup).unwrap();
+
+        assert_eq!(state.level, 5);
+        assert_eq!(state.phase, GamePhase::Ready);
+        assert_eq!(state.pacman.position.to_grid(), (6, 5));
+        assert_eq!(state.ghosts[1].position.to_grid(), (21, 5));
+        assert_eq!(state.maze.get_cell(5, 7), Some(CellType::Empty));
+        assert_eq!(state.dots_remaining, fresh.dots_remaining - 2);
+        assert_eq!(state.global_timer, 27.0);
+        // The next level gets a full board
+        assert_eq!(state.level_maze.dots_remaining(), fresh.dots_remaining);
+    }
+
+    #[test]
+    fn bad_setups_are_refused() {
+        let in_wall = PracticeSetup {
+            pacman: Some(PacManPlacement {
+                col: 0,
+                row: 0,
+                direction: Direction::Left,
+            }),
+            ..PracticeSetup::default()
+        };
+        assert!(GameStateInner::practice(in_wall).is_err());
+
+        let off_board = PracticeSetup {
+            eaten: vec![(99, 99)],
+            ..PracticeSetup::default()
+        };
+        assert!(GameStateInner::practice(off_board).is_err());
+    }
+}
@@ -0,0 +1,347 @@
+// game/src/pvp.rs
+//
+// PvP scoring: the ghost player's score, and best-of-N matches.
+//
+// Pac-Man's side of a PvP match has always been scored; the ghost side
+// could only drain lives. `GhostScore` gives Player 2 points of their own,
+// on a scale comparable to Pac-Man's, so a match has a result for both
+// sides:
+//
+// - Holding out: `SURVIVAL_POINTS` for every second of play the board
+//   isn't cleared.
+// - Catching Pac-Man: `CAPTURE_POINTS`.
+// - Pellets protected: `PELLET_PROTECTED_POINTS` for each power pellet
+//   still on the board when Pac-Man is caught.
+//
+// Kept on `GameStateInner::ghost_score` and in the snapshot. Zero outside
+// PvP, and reset with the rest of the game by `reset()`.
+//
+// A `Match` plays up to N rounds of PvP, each a full game. The players swap
+// sides every round; the side that scored more wins it, and the match goes
+// to whoever wins a majority first. Everything runs inside `tick()`:
+//
+// ```text
+// round plays ─► GameOver ─► RoundOver ─► ROUND_BREAK s ─► RoundStarted ─► ...
+//                                  └─ majority reached / last round ─► MatchOver
+// ```
+//
+// Players are numbered 1 and 2 for the whole match; `set_direction_p1/p2`
+// steer whichever side each one is on this round.
+
+use serde::{Deserialize, Serialize};
+
+use crate::entities::Direction;
+use crate::events::GameEvent;
+use crate::maze::CellType;
+use crate::state::{GameMode, GamePhase, GameStateInner};
+
+// ─── Ghost score ────────────────────────────────────────────────────────────
+
+/// Points for each full second of play.
+pub const SURVIVAL_POINTS: u32 = 10;
+
+/// Points for catching Pac-Man.
+pub const CAPTURE_POINTS: u32 = 1000;
+
+/// Points per power pellet left on the board at a capture.
+pub const PELLET_PROTECTED_POINTS: u32 = 50;
+
+/// The ghost player's points, and where they came from.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GhostScore {
+    /// Sum of the rest.
+    pub total: u32,
+    pub survival: u32,
+    pub captures: u32,
+    pub pellets_protected: u32,
+    /// Play time not yet paid out as survival points, in seconds.
+    #[serde(skip)]
+    pub clock: f64,
+}
+
+impl GhostScore {
+    fn add_survival(&mut self, points: u32) {
+        self.survival += points;
+        self.total += points;
+    }
+}
+
+impl GameStateInner {
+    /// Pay the ghost player for `dt` seconds of play.
+    pub(crate) fn update_ghost_score(&mut self, dt: f64) {
+        if self.mode != GameMode::PvP {
+            return;
+        }
+        let score = &mut self.ghost_score;
+        score.clock += dt;
+        let seconds = score.clock.floor();
+        if seconds >= 1.0 {
+            score.clock -= seconds;
+            score.add_survival(seconds as u32 * SURVIVAL_POINTS);
+        }
+    }
+
+    /// Pay the ghost player for catching Pac-Man.
+    pub(crate) fn score_capture(&mut self) {
+        if self.mode != GameMode::PvP {
+            return;
+        }
+        let pellets = self.maze.positions_of(CellType::PowerPellet).len() as u32;
+        let score = &mut self.ghost_score;
+        score.captures += CAPTURE_POINTS;
+        score.pellets_protected += pellets * PELLET_PROTECTED_POINTS;
+        score.total += CAPTURE_POINTS + pellets * PELLET_PROTECTED_POINTS;
+    }
+}
+
+// ─── Matches ────────────────────────────────────────────────────────────────
+
+/// Seconds between the end of one round and the start of the next.
+pub const ROUND_BREAK: f64 = 3.0;
+
+/// How one round of a match went.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RoundResult {
+    pub round: u8,
+    /// The player (1 or 2) who played Pac-Man.
+    pub pacman_player: u8,
+    pub pacman_score: u32,
+    pub ghost_score: u32,
+    /// `None` on a tie.
+    pub winner: Option<u8>,
+}
+
+/// A best-of-N PvP match, stored on `GameStateInner`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Match {
+    /// Rounds at most (N).
+    pub rounds: u8,
+    /// The round being played, from 1.
+    pub round: u8,
+    /// The player (1 or 2) playing Pac-Man this round.
+    pub pacman_player: u8,
+    /// Rounds won by players 1 and 2.
+    pub wins: [u8; 2],
+    /// Points scored by players 1 and 2 over the whole match, on either
+    /// side.
+    pub totals: [u32; 2],
+    pub results: Vec<RoundResult>,
+    /// Seconds left before the next round starts (0 while playing).
+    pub break_timer: f64,
+    pub decided: bool,
+    /// The match winner once decided; `None` on a draw.
+    pub winner: Option<u8>,
+}
+
+impl Match {
+    pub fn new(rounds: u8) -> Match {
+        Match {
+            rounds,
+            round: 1,
+            pacman_player: 1,
+            wins: [0; 2],
+            totals: [0; 2],
+            results: Vec::new(),
+            break_timer: 0.0,
+            decided: false,
+            winner: None,
+        }
+    }
+
+    /// The player (1 or 2) on the ghost side this round.
+    pub fn ghost_player(&self) -> u8 {
+        3 - self.pacman_player
+    }
+
+    /// Record the round just played, deciding the match if it's over.
+    fn record(&mut self, pacman_score: u32, ghost_score: u32) {
+        let (pac, ghost) = (self.pacman_player, self.ghost_player());
+        let winner = match pacman_score.cmp(&ghost_score) {
+            std::cmp::Ordering::Greater => Some(pac),
+            std::cmp::Ordering::Less => Some(ghost),
+            std::cmp::Ordering::Equal => None,
+        };
+        self.totals[pac as usize - 1] += pacman_score;
+        self.totals[ghost as usize - 1] += ghost_score;
+        if let Some(w) = winner {
+            self.wins[w as usize - 1] += 1;
+        }
+        self.results.push(RoundResult {
+            round: self.round,
+            pacman_player: pac,
+            pacman_score,
+            ghost_score,
+            winner,
+        });
+
+        let majority = self.rounds / 2 + 1;
+        if self.wins.iter().any(|&w| w >= majority) || self.round >= self.rounds {
+            let by = |[a, b]: [u32; 2]| match a.cmp(&b) {
+                std::cmp::Ordering::Greater => Some(1),
+                std::cmp::Ordering::Less => Some(2),
+                std::cmp::Ordering::Equal => None,
+            };
+            let wins = self.wins.map(u32::from);
+            self.decided = true;
+            self.winner = by(wins).or_else(|| by(self.totals));
+        }
+    }
+}
+
+impl GameStateInner {
+    /// Start a PvP match of at most `rounds` rounds (1–255). Player 1
+    /// plays Pac-Man first.
+    pub fn new_match(rounds: u8) -> Result<GameStateInner, String> {
+        if rounds == 0 {
+            return Err("a match needs at least 1 round".to_string());
+        }
+        let mut state = GameStateInner::new(GameMode::PvP);
+        state.pvp_match = Some(Match::new(rounds));
+        Ok(state)
+    }
+
+    /// Queue player 1's or 2's next direction, for whichever side they
+    /// play: in a match round where player 2 is Pac-Man, the players swap.
+    pub fn set_player_direction(&mut self, player: u8, direction: Direction) {
+        let swapped = self
+            .pvp_match
+            .as_ref()
+            .is_some_and(|m| m.pacman_player == 2);
+        if (player == 1) != swapped {
+            self.set_direction(direction);
+        } else {
+            self.set_player2_direction(direction);
+        }
+    }
+
+    /// Close a round once its game is over, and start the next one after
+    /// `ROUND_BREAK`.
+    pub(crate) fn update_match(&mut self, dt: f64) {
+        let pacman_score = self.pacman.score;
+        let ghost_score = self.ghost_score.total;
+        let Some(m) = self.pvp_match.as_mut() else {
+            return;
+        };
+        if m.decided {
+            return;
+        }
+        if m.break_timer > 0.0 {
+            m.break_timer -= dt;
+            if m.break_timer <= 0.0 {
+                self.next_round();
+            }
+            return;
+        }
+        if self.phase != GamePhase::GameOver || m.results.len() >= m.round as usize {
+            return;
+        }
+
+        let round = m.round;
+        m.record(pacman_score, ghost_score);
+        let winner = m.results.last().and_then(|r| r.winner);
+        self.events.push(GameEvent::RoundOver { round, winner });
+        if m.decided {
+            self.events.push(GameEvent::MatchOver { winner: m.winner });
+        } else {
+            m.break_timer = ROUND_BREAK;
+        }
+    }
+
+    /// A fresh game for the next round, with the players' sides swapped.
+    fn next_round(&mut self) {
+        let Some(mut m) = self.pvp_match.take() else {
+            return;
+        };
+        m.break_timer = 0.0;
+        m.round += 1;
+        m.pacman_player = m.ghost_player();
+        self.reset();
+        self.events.push(GameEvent::RoundStarted {
+            round: m.round,
+            pacman_player: m.pacman_player,
+        });
+        self.pvp_match = Some(m);
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::GamePhase;
+
+    #[test]
+    fn ghost_player_scores_for_time_and_captures() {
+        let mut state = GameStateInner::new(GameMode::PvP);
+        state.phase = GamePhase::Playing;
+        for _ in 0..90 {
+            state.tick(1.0 / 60.0);
+        }
+        assert_eq!(state.ghost_score.survival, SURVIVAL_POINTS);
+
+        state.ghosts[0].position = state.pacman.position.clone();
+        state.tick(1.0 / 60.0);
+        assert_eq!(state.phase, GamePhase::Dying);
+        let score = &state.ghost_score;
+        assert_eq!(score.captures, CAPTURE_POINTS);
+        assert_eq!(score.pellets_protected, 4 * PELLET_PROTECTED_POINTS);
+        assert_eq!(
+            score.total,
+            score.survival + score.captures + score.pellets_protected
+        );
+
+        state.reset();
+        assert_eq!(state.ghost_score, GhostScore::default());
+    }
+
+    #[test]
+    fn no_ghost_score_outside_pvp() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        for _ in 0..120 {
+            state.tick(1.0 / 60.0);
+        }
+        state.ghosts[0].position = state.pacman.position.clone();
+        state.tick(1.0 / 60.0);
+        assert_eq!(state.ghost_score.total, 0);
+    }
+
+    #[test]
+    fn match_swaps_sides_and_ends_on_a_majority() {
+        let mut state = GameStateInner::new_match(3).unwrap();
+
+        // Round 1: player 1 is Pac-Man and outscores the ghosts
+        state.pacman.score = 5000;
+        state.phase = GamePhase::GameOver;
+        state.tick(1.0 / 60.0);
+        assert!(state.events.contains(&GameEvent::RoundOver {
+            round: 1,
+            winner: Some(1),
+        }));
+        state.tick(ROUND_BREAK);
+        let m = state.pvp_match.as_ref().unwrap();
+        assert_eq!((m.round, m.pacman_player), (2, 2));
+        assert_eq!(state.pacman.score, 0);
+
+        // Player 1 now steers the ghost
+        state.set_player_direction(1, Direction::Down);
+        assert_eq!(state.ghosts[0].next_direction, Direction::Down);
+
+        // Round 2: player 1 wins again on the ghost side
+        state.ghost_score.total = 100;
+        state.phase = GamePhase::GameOver;
+        state.tick(1.0 / 60.0);
+        assert!(state
+            .events
+            .contains(&GameEvent::MatchOver { winner: Some(1) }));
+        let m = state.pvp_match.as_ref().unwrap();
+        assert_eq!(m.wins, [2, 0]);
+        assert_eq!(m.totals, [5100, 0]);
+
+        state.tick(ROUND_BREAK);
+        assert_eq!(state.pvp_match.as_ref().unwrap().round, 2);
+        assert!(GameStateInner::new_match(0).is_err());
+    }
+}
@@ -0,0 +1,382 @@
+// game/src/render.rs
+//
+// Draw commands for the current frame.
+//
+// `render_commands()` lists every sprite to draw, back to front, as
+// (sprite, x, y, frame, flip). A frontend (canvas, WebGL, terminal) only
+// needs a sprite sheet and a loop over the list: which sprites are visible
+// in each phase, which way they face and which animation frame they show
+// are all decided here.
+//
+// Walls aren't sprites; draw them from `wall_paths()`.
+//
+// Positions are tile centers, in tiles. Sprites face right (Pac-Man also
+// has an upward variant); `flip` mirrors them for the other directions.
+//
+// # Score popups
+// Eating a ghost or a fruit leaves its points on the board for a moment.
+// The popups live in `GameStateInner::popups` and age with the game.
+
+use serde::{Deserialize, Serialize};
+
+use crate::animation::MOUTH_FRAMES;
+use crate::entities::{Direction, GhostLook};
+use crate::events::GameEvent;
+use crate::maze::CellType;
+use crate::state::{GamePhase, GameStateInner};
+
+// ─── Sprites ────────────────────────────────────────────────────────────────
+
+/// What a command draws, and what its `frame` means.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Sprite {
+    Dot,
+    /// Only listed while `pellets_visible`
+    PowerPellet,
+    WallBreaker,
+    /// frame: key id
+    Key,
+    /// frame: `FruitKind::code`
+    Fruit,
+    /// Facing right; frame: mouth (`0..MOUTH_FRAMES`)
+    PacMan,
+    /// Facing up; frame: mouth
+    PacManUp,
+    /// frame: `Animation::death_frame`
+    PacManDying,
+    /// Facing right; frame: `GhostType::code * 2 + wiggle`
+    Ghost,
+    /// frame: `flash * 2 + wiggle` (flash 1 = white)
+    FrightenedGhost,
+    /// Eyes heading home; frame: `Direction::code`
+    Eyes,
+    /// Intermission actor; frame: `ActorKind::code`
+    Actor,
+    /// frame: the points shown
+    ScorePopup,
+    TimeFreeze,
+    SpeedBoost,
+}
+
+impl Sprite {
+    /// Stable numeric code for flat JS arrays, in declaration order.
+    pub fn code(self) -> u8 {
+        match self {
+            Sprite::Dot => 0,
+            Sprite::PowerPellet => 1,
+            Sprite::WallBreaker => 2,
+            Sprite::Key => 3,
+            Sprite::Fruit => 4,
+            Sprite::PacMan => 5,
+            Sprite::PacManUp => 6,
+            Sprite::PacManDying => 7,
+            Sprite::Ghost => 8,
+            Sprite::FrightenedGhost => 9,
+            Sprite::Eyes => 10,
+            Sprite::Actor => 11,
+            Sprite::ScorePopup => 12,
+            Sprite::TimeFreeze => 13,
+            Sprite::SpeedBoost => 14,
+        }
+    }
+}
+
+/// How to mirror a sprite: Left is a horizontally flipped right-facing
+/// sprite, Down a vertically flipped `PacManUp`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Flip {
+    None,
+    Horizontal,
+    Vertical,
+}
+
+impl Flip {
+    /// Stable numeric code: None 0, Horizontal 1, Vertical 2.
+    pub fn code(self) -> u8 {
+        match self {
+            Flip::None => 0,
+            Flip::Horizontal => 1,
+            Flip::Vertical => 2,
+        }
+    }
+}
+
+/// One sprite to draw.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RenderCommand {
+    pub sprite: Sprite,
+    pub x: f64,
+    pub y: f64,
+    pub frame: u32,
+    pub flip: Flip,
+}
+
+// ─── Score popups ───────────────────────────────────────────────────────────
+
+/// Seconds a ghost's points stay on screen.
+pub const GHOST_POPUP_DURATION: f64 = 1.0;
+/// Seconds a fruit's points stay on screen.
+pub const FRUIT_POPUP_DURATION: f64 = 2.0;
+
+/// Points shown where something was eaten.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScorePopup {
+    pub points: u32,
+    pub x: f64,
+    pub y: f64,
+    /// Seconds left on screen.
+    pub remaining: f64,
+}
+
+// ─── Building the list ──────────────────────────────────────────────────────
+
+impl GameStateInner {
+    /// Age the score popups and add one for everything eaten this tick.
+    pub(crate) fn update_popups(&mut self, dt: f64) {
+        self.popups.retain_mut(|p| {
+            p.remaining -= dt;
+            p.remaining > 0.0
+        });
+        for event in &self.events {
+            let (points, (x, y), remaining) = match *event {
+                GameEvent::GhostEaten { index, points, .. } => {
+                    let Some(eaten) = self.ghosts.get(index) else {
+                        continue;
+                    };
+                    let at = (eaten.position.x.round(), eaten.position.y.round());
+                    (points, at, GHOST_POPUP_DURATION)
+                }
+                GameEvent::FruitEaten { points, .. } => {
+                    (points, self.maze.metadata.fruit_spawn, FRUIT_POPUP_DURATION)
+                }
+                _ => continue,
+            };
+            self.popups.push(ScorePopup {
+                points,
+                x,
+                y,
+                remaining,
+            });
+        }
+    }
+
+    /// Everything to draw this frame, back to front.
+    pub fn render_commands(&self) -> Vec<RenderCommand> {
+        let mut out = Vec::new();
+        let mut draw = |sprite, x, y, frame: u32, flip| {
+            out.push(RenderCommand {
+                sprite,
+                x,
+                y,
+                frame,
+                flip,
+            })
+        };
+        let anim = &self.animation;
+
+        if let GamePhase::Intermission(_) = self.phase {
+            for actor in &self.cutscene {
+                let flip = horizontal_flip(actor.direction);
+                draw(
+                    Sprite::Actor,
+                    actor.x,
+                    actor.y,
+                    actor.kind.code() as u32,
+                    flip,
+                );
+            }
+            return out;
+        }
+
+        // Board items
+        for (row, cells) in self.maze.cells.iter().enumerate() {
+            for (col, cell) in cells.iter().enumerate() {
+                let (sprite, frame) = match *cell {
+                    CellType::Dot => (Sprite::Dot, 0),
+                    CellType::PowerPellet if self.pellets_visible => (Sprite::PowerPellet, 0),
+                    CellType::WallBreaker => (Sprite::WallBreaker, 0),
+                    CellType::TimeFreeze => (Sprite::TimeFreeze, 0),
+                    CellType::SpeedBoost => (Sprite::SpeedBoost, 0),
+                    CellType::Key(id) => (Sprite::Key, id as u32),
+                    _ => continue,
+                };
+                draw(sprite, col as f64, row as f64, frame, Flip::None);
+            }
+        }
+        if let Some(fruit) = &self.fruit {
+            let (x, y) = (fruit.position.x, fruit.position.y);
+            draw(Sprite::Fruit, x, y, fruit.kind.code() as u32, Flip::None);
+        }
+
+        // Pac-Man (both, in Co-op, leaving out a player with no lives left)
+        let p1 = Some(&self.pacman).filter(|_| self.player_one_in_play());
+        let p2 = self.pacman2.as_ref().filter(|p| p.lives > 0);
+        for pac in p1.into_iter().chain(p2) {
+            let (x, y) = (pac.position.x, pac.position.y);
+            match self.phase {
+                GamePhase::Dying => draw(
+                    Sprite::PacManDying,
+                    x,
+                    y,
+                    anim.death_frame as u32,
+                    Flip::None,
+                ),
+                GamePhase::GameOver => {}
+                _ => {
+                    // Closed mouth while waiting to start
+                    let mouth = if self.phase == GamePhase::Ready {
+                        0
+                    } else {
+                        (anim.mouth_frame % MOUTH_FRAMES) as u32
+                    };
+                    let (sprite, flip) = match pac.direction {
+                        Direction::Right => (Sprite::PacMan, Flip::None),
+                        Direction::Left => (Sprite::PacMan, Flip::Horizontal),
+                        Direction::Up => (Sprite::PacManUp, Flip::None),
+                        Direction::Down => (Sprite::PacManUp, Flip::Vertical),
+                    };
+                    draw(sprite, x, y, mouth, flip);
+                }
+            }
+        }
+
+        // Ghosts vanish once Pac-Man is caught or the board is cleared
+        let ghosts_shown = !matches!(
+            self.phase,
+            GamePhase::Dying | GamePhase::GameOver | GamePhase::LevelComplete
+        );
+        for ghost in self.ghosts.iter().filter(|_| ghosts_shown) {
+            let (x, y) = (ghost.position.x, ghost.position.y);
+            let wiggle = anim.wiggle_frame as u32;
+            match ghost.look {
+                GhostLook::Normal => {
+                    let frame = ghost.ghost_type.code() as u32 * 2 + wiggle;
+                    draw(Sprite::Ghost, x, y, frame, horizontal_flip(ghost.direction))
+                }
+                GhostLook::Frightened => {
+                    let frame = ghost.flash_white as u32 * 2 + wiggle;
+                    draw(Sprite::FrightenedGhost, x, y, frame, Flip::None)
+                }
+                GhostLook::Eyes => draw(
+                    Sprite::Eyes,
+                    x,
+                    y,
+                    ghost.direction.code() as u32,
+                    Flip::None,
+                ),
+            }
+        }
+
+        for popup in &self.popups {
+            draw(
+                Sprite::ScorePopup,
+                popup.x,
+                popup.y,
+                popup.points,
+                Flip::None,
+            );
+        }
+        out
+    }
+
+    /// `render_commands()` flattened for JS as
+    /// `[sprite, x, y, frame, flip, ...]` (codes as in `Sprite::code` and
+    /// `Flip::code`).
+    pub fn render_command_data(&self) -> Vec<f64> {
+        self.render_commands()
+            .iter()
+            .flat_map(|c| {
+                [
+                    c.sprite.code() as f64,
+                    c.x,
+                    c.y,
+                    c.frame as f64,
+                    c.flip.code() as f64,
+                ]
+            })
+            .collect()
+    }
+}
+
+/// Right-facing sprites mirrored for left; up and down keep their look.
+fn horizontal_flip(direction: Direction) -> Flip {
+    if direction == Direction::Left {
+        Flip::Horizontal
+    } else {
+        Flip::None
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GameConfig;
+    use crate::entities::{GhostMode, GhostType, HouseState};
+    use crate::state::GameMode;
+
+    fn count(commands: &[RenderCommand], sprite: Sprite) -> usize {
+        commands.iter().filter(|c| c.sprite == sprite).count()
+    }
+
+    #[test]
+    fn ready_screen_draws_board_pacman_and_ghosts() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        let commands = state.render_commands();
+        let items = count(&commands, Sprite::Dot) + count(&commands, Sprite::PowerPellet);
+        assert_eq!(items, state.dots_remaining);
+        assert_eq!(count(&commands, Sprite::Ghost), 4);
+        // Dots are drawn under the entities
+        assert_eq!(commands[0].sprite, Sprite::Dot);
+        let pac = commands
+            .iter()
+            .find(|c| c.sprite == Sprite::PacMan)
+            .unwrap();
+        assert_eq!((pac.x, pac.y, pac.flip), (14.0, 23.0, Flip::Horizontal));
+
+        state.pellets_visible = false;
+        assert_eq!(count(&state.render_commands(), Sprite::PowerPellet), 0);
+        let dots = count(&commands, Sprite::Dot);
+        assert_eq!(state.render_command_data().len(), 5 * (dots + 1 + 4));
+    }
+
+    #[test]
+    fn eating_a_ghost_shows_its_points_for_a_while() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        state.ghosts[0].mode = GhostMode::Frightened;
+        state.ghosts[0].position = state.pacman.position.clone();
+        state.tick(1.0 / 60.0);
+
+        let popup = state
+            .render_commands()
+            .into_iter()
+            .find(|c| c.sprite == Sprite::ScorePopup)
+            .unwrap();
+        assert_eq!(popup.frame, 200);
+
+        for _ in 0..70 {
+            state.tick(1.0 / 60.0);
+        }
+        assert!(state.popups.is_empty());
+    }
+
+    #[test]
+    fn popup_marks_the_ghost_eaten_not_its_twin() {
+        let config = GameConfig {
+            ghost_roster: vec![GhostType::Clyde, GhostType::Clyde],
+            ..GameConfig::default()
+        };
+        let mut state = GameStateInner::with_config(GameMode::Classic, config);
+        state.phase = GamePhase::Playing;
+        let ghost = &mut state.ghosts[1];
+        ghost.mode = GhostMode::Frightened;
+        ghost.house = HouseState::Outside;
+        ghost.position = state.pacman.position.clone();
+        state.tick(1.0 / 60.0);
+
+        let popup = &state.popups[0];
+        assert_eq!((popup.x, popup.y), (14.0, 23.0));
+    }
+}
@@ -0,0 +1,74 @@
+// game/src/rng.rs
+//
+// A tiny deterministic random number generator.
+//
+// Given the same seed, the simulation must make the same "random"
+// choices on every run and every platform — that's what makes replays,
+// golden traces, and shared challenge seeds possible. So instead of a
+// platform RNG we carry our own 32-bit state inside `GameStateInner`.
+
+use serde::{Deserialize, Serialize};
+
+// ─── Rng ────────────────────────────────────────────────────────────────────
+
+/// Mulberry32: a small, fast generator with a single `u32` of state.
+///
+/// # Why 32-bit state?
+/// The whole game state is serialized to JS, where numbers above 2^53
+/// can't be represented exactly. A `u32` round-trips losslessly, so a
+/// saved game resumes with exactly the same random sequence.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Rng {
+    state: u32,
+}
+
+impl Rng {
+    pub fn new(seed: u32) -> Self {
+        Rng { state: seed }
+    }
+
+    /// Next pseudo-random `u32`.
+    pub fn next_u32(&mut self) -> u32 {
+        self.state = self.state.wrapping_add(0x6D2B_79F5);
+        let mut z = self.state;
+        z = (z ^ (z >> 15)).wrapping_mul(z | 1);
+        z ^= z.wrapping_add((z ^ (z >> 7)).wrapping_mul(z | 61));
+        z ^ (z >> 14)
+    }
+
+    /// Uniform-ish value in `0..n` (`n` must be non-zero).
+    pub fn below(&mut self, n: u32) -> u32 {
+        self.next_u32() % n
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u32(), b.next_u32());
+        }
+    }
+
+    #[test]
+    fn different_seeds_diverge() {
+        let mut a = Rng::new(1);
+        let mut b = Rng::new(2);
+        assert_ne!(a.next_u32(), b.next_u32());
+    }
+
+    #[test]
+    fn below_stays_in_range() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!(rng.below(28) < 28);
+        }
+    }
+}
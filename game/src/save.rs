@@ -0,0 +1,212 @@
+// game/src/save.rs
+//
+// Saving a game to JSON and restoring it.
+//
+// A save is the whole `GameStateInner` — timers, ghost modes, RNG state,
+// campaign progress — so a restored game continues exactly where it left
+// off. The snapshot alone isn't quite enough: it leaves out `level_maze`
+// (the board as the level started), which the next level is refilled from,
+// so a save stores that next to it.
+//
+// Saving during a demo saves the real game underneath, not the demo.
+//
+// # Versions and migrations
+// Every save carries `version: SAVE_VERSION`. Loading goes through the
+// raw JSON first: `MIGRATIONS[v]` upgrades a version-`v` save to `v + 1`,
+// and they run in order until the save is current.
+//
+// Adding a field doesn't need a migration: the saved structs fill missing
+// fields with defaults (`#[serde(default)]`). Bump the version and add a
+// migration for anything else — a rename, a changed unit, a field whose
+// default would be wrong for old games.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::maze::Maze;
+use crate::state::GameStateInner;
+
+// ─── Save format ────────────────────────────────────────────────────────────
+
+/// The version `to_json()` writes. Saves without a version are version 0.
+pub const SAVE_VERSION: u64 = 1;
+
+/// Upgrades from each older version to the next (see the module docs).
+const MIGRATIONS: [fn(&mut Value); SAVE_VERSION as usize] = [from_unversioned];
+
+/// Version 0 saves (written before versioning) have the current layout,
+/// minus the version field.
+fn from_unversioned(_save: &mut Value) {}
+
+#[derive(Serialize)]
+struct SaveRef<'a> {
+    version: u64,
+    state: &'a GameStateInner,
+    level_maze: &'a Maze,
+}
+
+#[derive(Deserialize)]
+struct SaveData {
+    state: GameStateInner,
+    level_maze: Maze,
+}
+
+// ─── Errors ─────────────────────────────────────────────────────────────────
+
+/// Why a save couldn't be loaded.
+#[derive(Debug)]
+pub enum SaveError {
+    /// Not JSON, or JSON that doesn't hold a game.
+    Malformed(serde_json::Error),
+    /// Valid JSON, but not a save object (or its version isn't a number).
+    NotASave,
+    /// Written by a newer build than this one.
+    TooNew { version: u64 },
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Malformed(e) => write!(f, "malformed save: {}", e),
+            SaveError::NotASave => write!(f, "not a saved game"),
+            SaveError::TooNew { version } => write!(
+                f,
+                "save version {} is newer than this build supports ({})",
+                version, SAVE_VERSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+// ─── Saving and loading ─────────────────────────────────────────────────────
+
+impl GameStateInner {
+    /// Serialize the game for storage (e.g. in localStorage).
+    pub fn to_json(&self) -> String {
+        let real = self.demo_backup.as_deref().unwrap_or(self);
+        let save = SaveRef {
+            version: SAVE_VERSION,
+            state: real,
+            level_maze: &real.level_maze,
+        };
+        // Plain data all the way down; no error path to surface.
+        serde_json::to_string(&save).expect("GameStateInner is always serializable")
+    }
+
+    /// Restore a game saved with `to_json()` by this or an older build.
+    pub fn from_json(json: &str) -> Result<GameStateInner, SaveError> {
+        let mut save: Value = serde_json::from_str(json).map_err(SaveError::Malformed)?;
+        if !save.is_object() {
+            return Err(SaveError::NotASave);
+        }
+        let version = match save.get("version") {
+            None => 0,
+            Some(v) => v.as_u64().ok_or(SaveError::NotASave)?,
+        };
+        if version > SAVE_VERSION {
+            return Err(SaveError::TooNew { version });
+        }
+        for migrate in &MIGRATIONS[version as usize..] {
+            migrate(&mut save);
+        }
+
+        let SaveData {
+            mut state,
+            level_maze,
+        } = serde_json::from_value(save).map_err(SaveError::Malformed)?;
+        state.level_maze = level_maze;
+        // Derived caches aren't saved
+        state.maze.rebuild_junctions();
+        state.level_maze.rebuild_junctions();
+        for ghost in &mut state.ghosts {
+            ghost.pattern = ghost.ghost_type.pattern();
+        }
+        Ok(state)
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{GhostMode, GhostPattern};
+    use crate::maze::CellType;
+    use crate::state::{GameMode, GamePhase};
+
+    #[test]
+    fn save_restores_timers_modes_and_board() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        for _ in 0..90 {
+            state.tick(1.0 / 60.0);
+        }
+        state.ghosts[2].mode = GhostMode::Frightened;
+        state.frightened_timer = 3.25;
+        state.level_maze.set_cell(1, 1, CellType::Slow);
+
+        let mut loaded = GameStateInner::from_json(&state.to_json()).unwrap();
+        assert_eq!(loaded.global_timer, state.global_timer);
+        assert_eq!(loaded.frightened_timer, 3.25);
+        assert_eq!(loaded.ghosts[2].mode, GhostMode::Frightened);
+        assert_eq!(loaded.pacman.position.x, state.pacman.position.x);
+        assert_eq!(loaded.maze.to_bytes(), state.maze.to_bytes());
+        assert_eq!(loaded.level_maze.get_cell(1, 1), Some(CellType::Slow));
+
+        // Both copies play on identically
+        state.tick(0.5);
+        loaded.tick(0.5);
+        assert_eq!(loaded.to_json(), state.to_json());
+    }
+
+    #[test]
+    fn saving_a_demo_saves_the_real_game() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.start_demo();
+        state.tick(0.5);
+        let loaded = GameStateInner::from_json(&state.to_json()).unwrap();
+        assert_eq!(loaded.phase, GamePhase::Ready);
+        assert_eq!(loaded.pacman.score, 0);
+    }
+
+    #[test]
+    fn old_saves_missing_newer_fields_still_load() {
+        let state = GameStateInner::new(GameMode::Classic);
+        let mut old: Value = serde_json::from_str(&state.to_json()).unwrap();
+        let save = old.as_object_mut().unwrap();
+        save.remove("version");
+        let game = save["state"].as_object_mut().unwrap();
+        game.remove("sound_loop");
+        game.remove("eat_stall");
+        let pinky = game["ghosts"][1].as_object_mut().unwrap();
+        pinky.remove("pattern");
+        pinky.remove("house");
+
+        let loaded = GameStateInner::from_json(&old.to_string()).unwrap();
+        assert_eq!(loaded.sound_loop, None);
+        assert_eq!(loaded.ghosts[1].pattern, GhostPattern::Dots);
+        assert_eq!(loaded.pacman.lives, 3);
+        assert_eq!(loaded.time_scale, 1.0);
+    }
+
+    #[test]
+    fn bad_saves_are_typed_errors() {
+        let too_new = format!("{{\"version\": {}}}", SAVE_VERSION + 1);
+        assert!(matches!(
+            GameStateInner::from_json(&too_new),
+            Err(SaveError::TooNew { .. })
+        ));
+        assert!(matches!(
+            GameStateInner::from_json("[1, 2]"),
+            Err(SaveError::NotASave)
+        ));
+        assert!(matches!(
+            GameStateInner::from_json("{\"state\": 3}"),
+            Err(SaveError::Malformed(_))
+        ));
+    }
+}
@@ -0,0 +1,560 @@
+// game/src/scenario.rs
+//
+// Building a game state from an explicit setup.
+//
+// Unit tests, tutorial steps, and puzzle challenges ("eat all four ghosts
+// from here") all need a board in a specific situation. `ScenarioBuilder`
+// describes that situation declaratively and produces a consistent
+// `GameStateInner` — derived fields like `dots_remaining` are computed
+// for you instead of being poked by hand.
+//
+// A `Scenario` is the same thing as JSON, for setups that live outside the
+// code: puzzles, bug repro cases, challenges shared between players.
+// `GameState.from_scenario(json)` checks it and runs it through the
+// builder:
+//
+// ```text
+// {
+//   "maze": ["WWWWWWW", "W@oFb.W", "WWW-WWW", "WWpicWW", "WWWWWWW"],
+//   "pacman": { "col": 1, "row": 1, "direction": "Right" },
+//   "ghosts": [{ "ghost": "Blinky", "col": 5, "row": 1, "mode": "Chase" }],
+//   "phase": "Playing",
+//   "score": 4200
+// }
+// ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::GameConfig;
+use crate::entities::{Direction, GhostLook, GhostMode, GhostPick, HouseState, Position};
+use crate::maze::{CellType, Maze, MazeError};
+use crate::practice::{GhostPlacement, PacManPlacement};
+use crate::state::{GameMode, GamePhase, GameStateInner};
+
+// ─── Specs ──────────────────────────────────────────────────────────────────
+
+/// Placement for one ghost; ghosts without a spec keep their spawn.
+#[derive(Clone, Debug)]
+struct GhostSpec {
+    ghost: GhostPick,
+    position: Position,
+    direction: Direction,
+    mode: GhostMode,
+}
+
+// ─── Builder ────────────────────────────────────────────────────────────────
+
+/// Declarative setup for a `GameStateInner`.
+///
+/// Every tile is (row, col), as in `Maze::get_cell`. Tiles off the board
+/// are skipped.
+///
+/// ```text
+/// let state = ScenarioBuilder::new(GameMode::Classic)
+///     .pacman_at(5, 6, Direction::Right)
+///     .ghost(GhostType::Blinky, 5, 8, GhostMode::Frightened)
+///     .only_dots_at(&[(5, 9)])
+///     .phase(GamePhase::Playing)
+///     .build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScenarioBuilder {
+    mode: GameMode,
+    config: GameConfig,
+    maze: Option<Maze>,
+    pacman: Option<(Position, Direction)>,
+    ghosts: Vec<GhostSpec>,
+    dots: Option<Vec<(usize, usize)>>,
+    pellets: Vec<(usize, usize)>,
+    eaten: Vec<(usize, usize)>,
+    score: u32,
+    lives: Option<u8>,
+    level: u32,
+    phase: GamePhase,
+    global_timer: f64,
+    frightened_timer: f64,
+}
+
+impl ScenarioBuilder {
+    /// Start from a fresh game: classic maze, spawn positions, `Ready`.
+    pub fn new(mode: GameMode) -> Self {
+        ScenarioBuilder {
+            mode,
+            config: GameConfig::default(),
+            maze: None,
+            pacman: None,
+            ghosts: Vec::new(),
+            dots: None,
+            pellets: Vec::new(),
+            eaten: Vec::new(),
+            score: 0,
+            lives: None,
+            level: 1,
+            phase: GamePhase::Ready,
+            global_timer: 0.0,
+            frightened_timer: 0.0,
+        }
+    }
+
+    pub fn config(mut self, config: GameConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Play on `maze` instead of the classic layout.
+    pub fn maze(mut self, maze: Maze) -> Self {
+        self.maze = Some(maze);
+        self
+    }
+
+    /// Place Pac-Man on tile (row, col), heading `direction`.
+    pub fn pacman_at(mut self, row: usize, col: usize, direction: Direction) -> Self {
+        self.pacman = Some((Position::new(col as f64, row as f64), direction));
+        self
+    }
+
+    /// Place a ghost on tile (row, col) in `mode`, heading up. Pick it by
+    /// `GhostType` (the first with that personality) or roster index.
+    pub fn ghost(
+        mut self,
+        ghost: impl Into<GhostPick>,
+        row: usize,
+        col: usize,
+        mode: GhostMode,
+    ) -> Self {
+        let ghost = ghost.into();
+        self.ghosts.retain(|g| g.ghost != ghost);
+        self.ghosts.push(GhostSpec {
+            ghost,
+            position: Position::new(col as f64, row as f64),
+            direction: Direction::Up,
+            mode,
+        });
+        self
+    }
+
+    /// Remove every dot and power pellet except dots at these (row, col)
+    /// tiles. Combine with `pellet_at` for pellets.
+    pub fn only_dots_at(mut self, dots: &[(usize, usize)]) -> Self {
+        self.dots = Some(dots.to_vec());
+        self
+    }
+
+    /// Put a power pellet on tile (row, col).
+    pub fn pellet_at(mut self, row: usize, col: usize) -> Self {
+        self.pellets.push((row, col));
+        self
+    }
+
+    /// Clear the dots and power pellets on these (row, col) tiles, as if
+    /// Pac-Man had already eaten them.
+    pub fn eaten_at(mut self, tiles: &[(usize, usize)]) -> Self {
+        self.eaten.extend_from_slice(tiles);
+        self
+    }
+
+    pub fn score(mut self, score: u32) -> Self {
+        self.score = score;
+        self
+    }
+
+    pub fn lives(mut self, lives: u8) -> Self {
+        self.lives = Some(lives);
+        self
+    }
+
+    pub fn level(mut self, level: u32) -> Self {
+        self.level = level;
+        self
+    }
+
+    pub fn phase(mut self, phase: GamePhase) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    pub fn global_timer(mut self, seconds: f64) -> Self {
+        self.global_timer = seconds;
+        self
+    }
+
+    pub fn frightened_timer(mut self, seconds: f64) -> Self {
+        self.frightened_timer = seconds;
+        self
+    }
+
+    /// Produce the game state.
+    ///
+    /// The builder's starting score and phase count as the "previous"
+    /// values, so the first tick doesn't report them as changes.
+    pub fn build(self) -> GameStateInner {
+        let mut state = GameStateInner::with_config(self.mode, self.config);
+        if let Some(maze) = self.maze {
+            state.load_maze(maze);
+        }
+
+        if let Some(dots) = &self.dots {
+            for row in 0..state.maze.height {
+                for col in 0..state.maze.width {
+                    if matches!(
+                        state.maze.get_cell(row, col),
+                        Some(CellType::Dot | CellType::PowerPellet)
+                    ) {
+                        state.maze.set_cell(row, col, CellType::Empty);
+                    }
+                }
+            }
+            for &(row, col) in dots {
+                if state.maze.get_cell(row, col).is_some() {
+                    state.maze.set_cell(row, col, CellType::Dot);
+                }
+            }
+        }
+        for &(row, col) in &self.pellets {
+            if state.maze.get_cell(row, col).is_some() {
+                state.maze.set_cell(row, col, CellType::PowerPellet);
+            }
+        }
+        // Later levels refill this board, not the classic one
+        state.level_maze = state.maze.clone();
+        // ...with what was eaten back in place
+        for &(row, col) in &self.eaten {
+            if matches!(
+                state.maze.get_cell(row, col),
+                Some(CellType::Dot | CellType::PowerPellet)
+            ) {
+                state.maze.set_cell(row, col, CellType::Empty);
+                state.dots_eaten += 1;
+            }
+        }
+        state.dots_remaining = state.maze.dots_remaining();
+
+        if let Some((position, direction)) = self.pacman {
+            state.pacman.position = position;
+            state.pacman.direction = direction;
+            state.pacman.next_direction = direction;
+        }
+        for spec in self.ghosts {
+            if let Some(ghost) = spec.ghost.find(&state.ghosts).map(|i| &mut state.ghosts[i]) {
+                ghost.position = spec.position;
+                ghost.direction = spec.direction;
+                ghost.next_direction = spec.direction;
+                ghost.mode = spec.mode;
+                ghost.look = GhostLook::for_mode(spec.mode);
+                ghost.house = HouseState::Outside;
+            }
+        }
+
+        state.pacman.score = self.score;
+        if let Some(lives) = self.lives {
+            state.pacman.lives = lives;
+        }
+        state.level = self.level;
+        state.phase = self.phase;
+        state.global_timer = self.global_timer;
+        state.frightened_timer = self.frightened_timer;
+        state.last_score = self.score;
+        state.last_phase = self.phase;
+        state
+    }
+}
+
+// ─── JSON scenarios ─────────────────────────────────────────────────────────
+
+/// A situation to start from, as JSON. Every field has a default: `{}` is
+/// a fresh Classic game.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Scenario {
+    pub mode: GameMode,
+    pub config: GameConfig,
+    /// The board, one string per row in the `CellType::from_char` legend,
+    /// checked by `Maze::from_str` and `Maze::validate`. Empty plays the
+    /// classic maze.
+    pub maze: Vec<String>,
+    /// Pac-Man's start; `None` keeps his spawn.
+    pub pacman: Option<PacManPlacement>,
+    /// Ghosts left out stay in (or above) the house as usual.
+    pub ghosts: Vec<GhostPlacement>,
+    /// (row, col) tiles whose dot or power pellet is already eaten.
+    pub eaten: Vec<(usize, usize)>,
+    pub score: u32,
+    /// Lives, including the one in play; `None` keeps the config's.
+    pub lives: Option<u8>,
+    pub level: u32,
+    pub phase: GamePhase,
+    /// Seconds into the level's scatter/chase schedule.
+    pub global_timer: f64,
+    /// Seconds of fright left.
+    pub frightened_timer: f64,
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Scenario {
+            mode: GameMode::Classic,
+            config: GameConfig::default(),
+            maze: Vec::new(),
+            pacman: None,
+            ghosts: Vec::new(),
+            eaten: Vec::new(),
+            score: 0,
+            lives: None,
+            level: 1,
+            phase: GamePhase::Ready,
+            global_timer: 0.0,
+            frightened_timer: 0.0,
+        }
+    }
+}
+
+impl Scenario {
+    /// The game this scenario describes, or why it can't be built.
+    pub fn build(&self) -> Result<GameStateInner, String> {
+        if self.level == 0 {
+            return Err("level must be at least 1".to_string());
+        }
+        if self.lives == Some(0) {
+            return Err("lives must be at least 1".to_string());
+        }
+        for (name, seconds) in [
+            ("global_timer", self.global_timer),
+            ("frightened_timer", self.frightened_timer),
+        ] {
+            if !(seconds.is_finite() && seconds >= 0.0) {
+                return Err(format!("{} must be 0 or more, not {}", name, seconds));
+            }
+        }
+        self.config.assist.check()?;
+
+        let mut builder = ScenarioBuilder::new(self.mode)
+            .config(self.config.clone())
+            .eaten_at(&self.eaten)
+            .score(self.score)
+            .level(self.level)
+            .phase(self.phase)
+            .global_timer(self.global_timer)
+            .frightened_timer(self.frightened_timer);
+        if !self.maze.is_empty() {
+            let maze: Maze = self
+                .maze
+                .join("\n")
+                .parse()
+                .map_err(|e: MazeError| e.to_string())?;
+            let report = maze.validate();
+            if !report.is_valid() {
+                return Err(report.to_string());
+            }
+            builder = builder.maze(maze);
+        }
+        if let Some(lives) = self.lives {
+            builder = builder.lives(lives);
+        }
+        if let Some(pac) = &self.pacman {
+            builder = builder.pacman_at(pac.row, pac.col, pac.direction);
+        }
+        for ghost in &self.ghosts {
+            builder = builder.ghost(ghost.pick(), ghost.row, ghost.col, ghost.mode);
+        }
+        let state = builder.build();
+        check_placements(&state, self.pacman.as_ref(), &self.ghosts, &self.eaten)?;
+        Ok(state)
+    }
+}
+
+/// Check that everyone placed on `state` can move from where they stand,
+/// and that every eaten tile is on the board.
+pub(crate) fn check_placements(
+    state: &GameStateInner,
+    pacman: Option<&PacManPlacement>,
+    ghosts: &[GhostPlacement],
+    eaten: &[(usize, usize)],
+) -> Result<(), String> {
+    for ghost in ghosts {
+        if let Some(i) = ghost.index {
+            match state.ghosts.get(i) {
+                Some(g) if g.ghost_type == ghost.ghost => {}
+                Some(g) => {
+                    return Err(format!(
+                        "ghost {} is {:?}, not {:?}",
+                        i, g.ghost_type, ghost.ghost
+                    ))
+                }
+                None => return Err(format!("there's no ghost {} in this game", i)),
+            }
+        }
+    }
+    let placed = pacman
+        .iter()
+        .map(|p| ("Pac-Man".to_string(), p.col, p.row))
+        .chain(
+            ghosts
+                .iter()
+                .map(|g| (format!("{:?}", g.ghost), g.col, g.row)),
+        );
+    for (who, col, row) in placed {
+        // `is_walkable` counts columns past the edge as tunnel
+        if col >= state.maze.width || row >= state.maze.height {
+            return Err(format!("{} is off the board at ({}, {})", who, col, row));
+        }
+        if !state.maze.is_walkable(col as f64, row as f64) {
+            return Err(format!(
+                "{} can't start in a wall at ({}, {})",
+                who, col, row
+            ));
+        }
+    }
+    if let Some(&(row, col)) = eaten
+        .iter()
+        .find(|&&(row, col)| state.maze.get_cell(row, col).is_none())
+    {
+        return Err(format!("eaten tile ({}, {}) is off the board", row, col));
+    }
+    Ok(())
+}
+
+impl GameStateInner {
+    /// Build the situation a `Scenario` JSON string describes.
+    pub fn from_scenario(json: &str) -> Result<GameStateInner, String> {
+        let scenario: Scenario =
+            serde_json::from_str(json).map_err(|e| format!("malformed scenario: {}", e))?;
+        scenario.build()
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::GhostType;
+    use crate::events::GameEvent;
+
+    #[test]
+    fn builder_applies_every_field() {
+        let state = ScenarioBuilder::new(GameMode::Classic)
+            .pacman_at(5, 6, Direction::Right)
+            .ghost(GhostType::Inky, 5, 9, GhostMode::Frightened)
+            .only_dots_at(&[(5, 8), (5, 9), (99, 99)])
+            .pellet_at(1, 1)
+            .pellet_at(1, 99)
+            .score(1230)
+            .lives(1)
+            .level(4)
+            .phase(GamePhase::Playing)
+            .frightened_timer(3.0)
+            .build();
+
+        assert_eq!(state.pacman.position.to_grid(), (6, 5));
+        assert_eq!(state.pacman.direction, Direction::Right);
+        assert_eq!(state.ghosts[2].position.to_grid(), (9, 5));
+        assert_eq!(state.ghosts[2].look, GhostLook::Frightened);
+        assert_eq!(state.dots_remaining, 3);
+        assert_eq!(state.maze.get_cell(1, 1), Some(CellType::PowerPellet));
+        assert_eq!(state.pacman.score, 1230);
+        assert_eq!(state.pacman.lives, 1);
+        assert_eq!(state.level, 4);
+        assert_eq!(state.phase, GamePhase::Playing);
+        assert_eq!(state.frightened_timer, 3.0);
+    }
+
+    #[test]
+    fn puzzle_setup_plays_out() {
+        // Pac-Man next to a frightened Blinky: one tick later it's eaten
+        let mut state = ScenarioBuilder::new(GameMode::Classic)
+            .pacman_at(5, 6, Direction::Right)
+            .ghost(GhostType::Blinky, 5, 6, GhostMode::Frightened)
+            .only_dots_at(&[(5, 20)])
+            .phase(GamePhase::Playing)
+            .frightened_timer(5.0)
+            .build();
+        state.tick(0.016);
+
+        assert_eq!(state.ghosts[0].mode, GhostMode::Eaten);
+        assert_eq!(state.pacman.score, 200);
+        assert!(!state
+            .events
+            .iter()
+            .any(|e| matches!(e, GameEvent::PhaseChanged { .. })));
+    }
+
+    #[test]
+    fn scenario_json_builds_the_situation() {
+        let mut state = GameStateInner::from_scenario(
+            r#"{
+                "maze": [
+                    "WWWWWWWWW",
+                    "W@.obF..W",
+                    "W.WW-WW.W",
+                    "W.WpicW.W",
+                    "W.WWWWW.W",
+                    "W.......W",
+                    "WWWWWWWWW"
+                ],
+                "pacman": { "col": 1, "row": 1, "direction": "Right" },
+                "ghosts": [{ "ghost": "Blinky", "col": 7, "row": 5, "mode": "Scatter" }],
+                "eaten": [[1, 2]],
+                "score": 4200,
+                "lives": 2,
+                "level": 3,
+                "phase": "Playing",
+                "frightened_timer": 1.5
+            }"#,
+        )
+        .unwrap();
+        assert_eq!((state.maze.width, state.maze.height), (9, 7));
+        assert_eq!(state.dots_remaining, 16);
+        assert_eq!(state.pacman.position.to_grid(), (1, 1));
+        assert_eq!(state.ghosts[0].position.to_grid(), (7, 5));
+        assert_eq!(state.ghosts[0].mode, GhostMode::Scatter);
+        assert_eq!((state.pacman.score, state.pacman.lives), (4200, 2));
+        assert_eq!((state.level, state.phase), (3, GamePhase::Playing));
+        assert_eq!(state.frightened_timer, 1.5);
+
+        state.tick(0.016);
+        assert_eq!(state.phase, GamePhase::Playing);
+        assert_eq!(
+            GameStateInner::from_scenario("{}").unwrap().maze.cells,
+            Maze::new().cells
+        );
+    }
+
+    #[test]
+    fn repeated_personalities_are_placed_by_index() {
+        let state = GameStateInner::from_scenario(
+            r#"{
+                "config": { "ghost_roster": ["Clyde", "Clyde"] },
+                "ghosts": [
+                    { "ghost": "Clyde", "col": 6, "row": 5, "mode": "Chase" },
+                    { "ghost": "Clyde", "col": 21, "row": 5, "mode": "Chase", "index": 1 }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(state.ghosts[0].position.to_grid(), (6, 5));
+        assert_eq!(state.ghosts[1].position.to_grid(), (21, 5));
+        assert_eq!(state.ghosts[1].house, HouseState::Outside);
+
+        let wrong = r#"{ "ghosts": [{ "ghost": "Clyde", "col": 6, "row": 5, "mode": "Chase", "index": 0 }] }"#;
+        assert!(GameStateInner::from_scenario(wrong).is_err());
+    }
+
+    #[test]
+    fn bad_scenarios_are_refused() {
+        for json in [
+            "not json",
+            r#"{ "level": 0 }"#,
+            r#"{ "lives": 0 }"#,
+            r#"{ "frightened_timer": -1 }"#,
+            r#"{ "config": { "assist": { "game_speed": 0 } } }"#,
+            r#"{ "config": { "assist": { "game_speed": -1 } } }"#,
+            r#"{ "pacman": { "col": 0, "row": 0, "direction": "Left" } }"#,
+            r#"{ "pacman": { "col": 500, "row": 3, "direction": "Left" } }"#,
+            r#"{ "eaten": [[99, 99]] }"#,
+            r#"{ "maze": ["WWW", "W.W", "WWW"] }"#,
+            r#"{ "maze": ["WWW", "W.", "WWW"] }"#,
+        ] {
+            assert!(GameStateInner::from_scenario(json).is_err(), "{}", json);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+// game/src/snapshot.rs
+//
+// Full-state snapshots with parts left out.
+//
+// `to_js()` serializes everything, including the ~868-cell maze, on every
+// call. A caller that already has the static parts can ask for less:
+//
+// ```text
+// game.to_js_with({ includeMaze: false, includeTimers: false })
+// ```
+//
+// The result is the `to_js()` object minus the excluded keys. Everything
+// else keeps its name and shape, so code reading the full snapshot works
+// unchanged on a trimmed one.
+//
+// `SnapshotView` borrows from the state and skips fields rather than
+// copying the state and deleting them. It lists every serialized field of
+// `GameStateInner`; the tests check it still matches.
+
+use serde::{Deserialize, Serialize};
+
+use crate::animation::Animation;
+use crate::campaign::Campaign;
+use crate::config::GameConfig;
+use crate::daily::Daily;
+use crate::entities::{Fruit, Ghost, PacMan};
+use crate::events::{GameEvent, SoundCue};
+use crate::intermission::Actor;
+use crate::maze::Maze;
+use crate::pvp::{GhostScore, Match};
+use crate::render::ScorePopup;
+use crate::rng::Rng;
+use crate::state::{GameMode, GamePhase, GameStateInner, ScoreBreakdown, WarpCooldown};
+use crate::stats::Stats;
+use crate::survival::Survival;
+use crate::tutorial::Tutorial;
+use crate::two_player::PlayerBoard;
+use crate::watchdog::Watchdog;
+
+// ─── Options ────────────────────────────────────────────────────────────────
+
+/// Which optional parts a snapshot includes. Everything is included by
+/// default; JS passes only what it wants left out.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SnapshotOptions {
+    /// `maze`
+    pub include_maze: bool,
+    /// `ghosts`
+    pub include_ghosts: bool,
+    /// The clocks that run every tick: `global_timer`, `frightened_timer`,
+    /// `freeze_timer`, `phase_timer`, `blink_clock`, `house_dot_timer`, `eat_stall`,
+    /// `timed_wall_timer` and `warp_cooldowns`.
+    pub include_timers: bool,
+}
+
+impl Default for SnapshotOptions {
+    fn default() -> Self {
+        SnapshotOptions {
+            include_maze: true,
+            include_ghosts: true,
+            include_timers: true,
+        }
+    }
+}
+
+// ─── Snapshot view ──────────────────────────────────────────────────────────
+
+/// `GameStateInner` as `to_js()` serializes it, minus what the options
+/// leave out. Built by `GameStateInner::snapshot`.
+#[derive(Debug, Serialize)]
+pub struct SnapshotView<'a> {
+    pub mode: GameMode,
+    pub config: &'a GameConfig,
+    pub phase: GamePhase,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maze: Option<&'a Maze>,
+    pub pacman: &'a PacMan,
+    pub pacman2: Option<&'a PacMan>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ghosts: Option<&'a [Ghost]>,
+    pub dots_remaining: usize,
+    pub level: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub global_timer: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frightened_timer: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub freeze_timer: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase_timer: Option<f64>,
+    pub countdown: u8,
+    pub pellets_visible: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blink_clock: Option<f64>,
+    pub cutscene: &'a [Actor],
+    pub animation: &'a Animation,
+    pub popups: &'a [ScorePopup],
+    pub visibility: &'a [bool],
+    pub global_dot_counter: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub house_dot_timer: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eat_stall: Option<f64>,
+    pub waka_alt: bool,
+    pub sound_loop: Option<SoundCue>,
+    pub fruit: Option<&'a Fruit>,
+    pub dots_eaten: u32,
+    pub extra_life_awarded: bool,
+    pub high_score: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warp_cooldowns: Option<&'a [WarpCooldown]>,
+    pub events: &'a [GameEvent],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timed_wall_timer: Option<f64>,
+    pub active_switch: Option<(usize, usize)>,
+    pub campaign: Option<&'a Campaign>,
+    pub pvp_match: Option<&'a Match>,
+    pub active_player: u8,
+    pub waiting_player: Option<&'a PlayerBoard>,
+    pub urgency_bonus: f64,
+    pub score_breakdown: &'a ScoreBreakdown,
+    pub stats: &'a Stats,
+    pub ghost_score: &'a GhostScore,
+    pub daily: Option<&'a Daily>,
+    pub survival: Option<&'a Survival>,
+    pub tutorial: Option<&'a Tutorial>,
+    pub rng: &'a Rng,
+    pub last_score: u32,
+    pub last_phase: GamePhase,
+    pub watchdog: &'a Watchdog,
+}
+
+impl GameStateInner {
+    /// A snapshot of the state with the parts `options` exclude left out.
+    pub fn snapshot(&self, options: SnapshotOptions) -> SnapshotView<'_> {
+        let timer = |value: f64| options.include_timers.then_some(value);
+        SnapshotView {
+            mode: self.mode,
+            config: &self.config,
+            phase: self.phase,
+            maze: options.include_maze.then_some(&self.maze),
+            pacman: &self.pacman,
+            pacman2: self.pacman2.as_ref(),
+            ghosts: options.include_ghosts.then_some(&self.ghosts[..]),
+            dots_remaining: self.dots_remaining,
+            level: self.level,
+            global_timer: timer(self.global_timer),
+            frightened_timer: timer(self.frightened_timer),
+            freeze_timer: timer(self.freeze_timer),
+            phase_timer: timer(self.phase_timer),
+            countdown: self.countdown,
+            pellets_visible: self.pellets_visible,
+            blink_clock: timer(self.blink_clock),
+            cutscene: &self.cutscene,
+            animation: &self.animation,
+            popups: &self.popups,
+            visibility: &self.visibility,
+            global_dot_counter: self.global_dot_counter,
+            house_dot_timer: timer(self.house_dot_timer),
+            eat_stall: timer(self.eat_stall),
+            waka_alt: self.waka_alt,
+            sound_loop: self.sound_loop,
+            fruit: self.fruit.as_ref(),
+            dots_eaten: self.dots_eaten,
+            extra_life_awarded: self.extra_life_awarded,
+            high_score: self.high_score,
+            warp_cooldowns: options.include_timers.then_some(&self.warp_cooldowns[..]),
+            events: &self.events,
+            timed_wall_timer: timer(self.timed_wall_timer),
+            active_switch: self.active_switch,
+            campaign: self.campaign.as_ref(),
+            pvp_match: self.pvp_match.as_ref(),
+            active_player: self.active_player,
+            waiting_player: self.waiting_player.as_deref(),
+            urgency_bonus: self.urgency_bonus,
+            score_breakdown: &self.score_breakdown,
+            stats: &self.stats,
+            ghost_score: &self.ghost_score,
+            daily: self.daily.as_ref(),
+            survival: self.survival.as_ref(),
+            tutorial: self.tutorial.as_ref(),
+            rng: &self.rng,
+            last_score: self.last_score,
+            last_phase: self.last_phase,
+            watchdog: &self.watchdog,
+        }
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn playing_state() -> GameStateInner {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        for _ in 0..30 {
+            state.tick(1.0 / 60.0);
+        }
+        state
+    }
+
+    #[test]
+    fn default_snapshot_matches_the_full_state() {
+        let state = playing_state();
+        assert_eq!(
+            serde_json::to_value(state.snapshot(SnapshotOptions::default())).unwrap(),
+            serde_json::to_value(&state).unwrap()
+        );
+    }
+
+    #[test]
+    fn excluded_parts_are_left_out() {
+        let state = playing_state();
+        let options: SnapshotOptions =
+            serde_json::from_str(r#"{ "includeMaze": false, "includeTimers": false }"#).unwrap();
+        let trimmed = serde_json::to_value(state.snapshot(options)).unwrap();
+        let trimmed = trimmed.as_object().unwrap();
+
+        assert!(!trimmed.contains_key("maze"));
+        assert!(!trimmed.contains_key("phase_timer"));
+        assert!(!trimmed.contains_key("warp_cooldowns"));
+        assert!(trimmed.contains_key("ghosts"));
+        assert_eq!(trimmed["level"], 1);
+    }
+}
@@ -20,8 +20,37 @@
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
-use crate::entities::{Direction, Ghost, GhostMode, PacMan};
-use crate::maze::{CellType, Maze};
+use crate::animation::Animation;
+use crate::assist::Assist;
+use crate::boost::BOOST_SECONDS;
+use crate::campaign::Campaign;
+use crate::chaos::starting_ghosts;
+use crate::config::{config_from_string, config_to_string, CollisionMode, GameConfig};
+use crate::coop::{nearest, player_two};
+use crate::daily::Daily;
+use crate::entities::{
+    Direction, Fruit, Ghost, GhostLook, GhostMode, GhostType, HouseState, PacMan,
+};
+use crate::events::{GameEvent, SoundCue, MAX_PENDING_EVENTS};
+use crate::intermission::{intermission_act, Actor};
+use crate::levels::{FrightSchedule, SpeedTable};
+use crate::maze::{CellType, Maze, MazeError, MazeMetadata};
+use crate::options::GameOptions;
+use crate::practice::PracticeSetup;
+use crate::pvp::{GhostScore, Match};
+use crate::render::ScorePopup;
+use crate::rng::Rng;
+use crate::snapshot::SnapshotOptions;
+use crate::stats::Stats;
+use crate::survival::Survival;
+use crate::tutorial::Tutorial;
+use crate::two_player::PlayerBoard;
+use crate::typings::{
+    JsAssist, JsCampaignSummary, JsEventCallback, JsFrameSnapshot, JsGameEvents, JsGameOptions,
+    JsGameSnapshot, JsGhostTargets, JsHud, JsMazeReport, JsPartialSnapshot, JsPracticeSetup,
+    JsSnapshotOptions, JsStats,
+};
+use crate::watchdog::Watchdog;
 
 // ─── Game Mode ──────────────────────────────────────────────────────────────
 
@@ -29,10 +58,18 @@ use crate::maze::{CellType, Maze};
 ///
 /// - `Classic`: Single-player. Ghosts use AI (Blinky chases, Pinky ambushes, etc.)
 /// - `PvP`: Local 1v1. Player 1 is Pac-Man, Player 2 controls the ghosts.
+/// - `Coop`: Local co-op. Two Pac-Men against AI ghosts (see `coop.rs`).
+/// - `Survival`: Endless single-player on one regrowing board (see
+///   `survival.rs`).
+///
+/// Exported to JS as a numeric enum, matching `mode_code()`.
+#[wasm_bindgen]
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum GameMode {
-    Classic,
-    PvP,
+    Classic = 0,
+    PvP = 1,
+    Coop = 2,
+    Survival = 3,
 }
 
 // ─── Game Phase ─────────────────────────────────────────────────────────────
@@ -40,20 +77,231 @@ pub enum GameMode {
 /// The lifecycle phase of the game.
 ///
 /// ```text
-/// Ready → Playing ←→ Paused
-///           ↓
-///        GameOver
+/// Demo ⇄ Ready → Playing ←→ UserPaused
+///          │  ↑
+///          │  Respawning ← Dying ←─ caught ─┐
+///          ├──────────────────────────────────┘
+///          │           Dying → GameOver (no lives left)
+///          └─ cleared → LevelComplete → [Intermission] → Ready (next level)
 /// ```
+///
+/// `Dying`, `Respawning`, `LevelComplete`, and `Intermission` last a fixed
+/// time (see the `*_DURATION` constants) and advance on their own. `Ready`
+/// waits for the first input, then counts down `READY_DURATION`.
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum GamePhase {
-    /// Waiting for the player to press start (the "READY!" screen)
+    /// The "READY!" screen: waiting for the first input, then counting down
     Ready,
     /// Active gameplay
     Playing,
-    /// Paused (only in single-player Classic mode typically)
-    Paused,
+    /// Paused by the player
+    UserPaused,
     /// Game over — all lives lost
     GameOver,
+    /// Pac-Man was caught; the death animation is playing
+    Dying,
+    /// Entities are back on their spawns; play resumes shortly
+    Respawning,
+    /// Every dot is eaten; the board flashes before the next level
+    LevelComplete,
+    /// Cutscene between levels: act 1, 2, or 3 (see `intermission.rs`)
+    Intermission(u8),
+    /// Attract mode: an autopilot plays on a throwaway copy of the game
+    /// (see `demo.rs`)
+    Demo,
+}
+
+/// `GamePhase` without the intermission act, exported to JS as a numeric
+/// enum (`Phase.Playing === 1`). Values match `phase_code()`.
+///
+/// wasm-bindgen only exports fieldless enums, hence the separate type.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Ready = 0,
+    Playing = 1,
+    Paused = 2,
+    GameOver = 3,
+    Dying = 4,
+    Respawning = 5,
+    LevelComplete = 6,
+    Intermission = 7,
+    Demo = 8,
+}
+
+impl GamePhase {
+    /// This phase as a `Phase` (dropping the intermission act).
+    pub fn id(self) -> Phase {
+        match self {
+            GamePhase::Ready => Phase::Ready,
+            GamePhase::Playing => Phase::Playing,
+            GamePhase::UserPaused => Phase::Paused,
+            GamePhase::GameOver => Phase::GameOver,
+            GamePhase::Dying => Phase::Dying,
+            GamePhase::Respawning => Phase::Respawning,
+            GamePhase::LevelComplete => Phase::LevelComplete,
+            GamePhase::Intermission(_) => Phase::Intermission,
+            GamePhase::Demo => Phase::Demo,
+        }
+    }
+}
+
+// ─── Change flags ───────────────────────────────────────────────────────────
+
+/// Bits of the mask `tick()` returns, exported to JS as a numeric enum:
+/// `if (game.tick(dt) & Change.Maze) redrawMaze()`. A clear bit means that
+/// part of the state reads the same as before the tick.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change {
+    /// Pac-Man's score
+    Score = 1,
+    /// Maze cells, including dots eaten and a refilled board
+    Maze = 2,
+    /// The game phase
+    Phase = 4,
+    /// `entity_positions()`, `entity_codes()` or the fruit
+    Entities = 8,
+    /// `drain_events()` has events waiting
+    Events = 16,
+}
+
+// ─── Phase timers ───────────────────────────────────────────────────────────
+
+/// Length of the "READY!" countdown after the first input, in seconds.
+pub const READY_DURATION: f64 = 2.0;
+/// Seconds of death animation before respawning.
+pub const DYING_DURATION: f64 = 1.5;
+/// Seconds between respawning and play resuming (counted down like
+/// `READY_DURATION`).
+pub const RESPAWN_DURATION: f64 = 2.0;
+/// Seconds the cleared board is shown before the next level.
+pub const LEVEL_COMPLETE_DURATION: f64 = 2.0;
+/// Length of an intermission cutscene, in seconds.
+pub const INTERMISSION_DURATION: f64 = 5.0;
+/// Levels followed by an intermission, as in the arcade game.
+pub const INTERMISSION_AFTER_LEVELS: [u32; 3] = [2, 5, 9];
+
+// ─── Pausing ────────────────────────────────────────────────────────────────
+
+/// Why gameplay isn't running right now.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum PauseReason {
+    /// Paused by the player; `resume()` continues
+    User,
+    /// On the READY screen or counting down
+    Ready,
+    /// Death animation or respawn countdown
+    Death,
+    /// Level cleared, or an intermission between levels
+    Level,
+    GameOver,
+}
+
+impl PauseReason {
+    /// The name passed to JS.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PauseReason::User => "user",
+            PauseReason::Ready => "ready",
+            PauseReason::Death => "death",
+            PauseReason::Level => "level",
+            PauseReason::GameOver => "game_over",
+        }
+    }
+}
+
+// ─── Animation ──────────────────────────────────────────────────────────────
+
+/// Power pellets are drawn for this long, then hidden for as long, in
+/// seconds (ten arcade frames).
+pub const PELLET_BLINK_PERIOD: f64 = 10.0 / 60.0;
+
+// ─── Sub-stepping ───────────────────────────────────────────────────────────
+
+/// Longest slice of time simulated at once, in seconds. Longer frames are
+/// split so fast entities can't jump past each other between checks.
+pub const MAX_STEP: f64 = 1.0 / 60.0;
+
+/// Most real seconds of play one tick simulates. A longer gap (a tab left
+/// in the background) still runs the phase timers, but only this much of
+/// it is played, so catching up can't run an unbounded number of
+/// `MAX_STEP` slices.
+pub const MAX_FRAME: f64 = 1.0;
+
+// ─── Time scale ─────────────────────────────────────────────────────────────
+
+/// Fastest `set_time_scale` runs the game. Each tick is still split into
+/// `MAX_STEP` slices, so the cost of a tick grows with the scale.
+pub const MAX_TIME_SCALE: f64 = 16.0;
+
+// ─── Vector input ───────────────────────────────────────────────────────────
+
+/// Shortest swipe/stick vector `set_direction_vector` acts on, in the
+/// caller's units (stick axes run -1 to 1; normalize swipes to match).
+pub const INPUT_DEADZONE: f64 = 0.25;
+
+// ─── Eating stalls ──────────────────────────────────────────────────────────
+
+/// Seconds Pac-Man pauses after eating a dot (one arcade frame)...
+pub const DOT_STALL: f64 = 1.0 / 60.0;
+/// ...and after eating a power pellet (three frames).
+pub const PELLET_STALL: f64 = 3.0 / 60.0;
+
+// ─── Warp cooldowns ─────────────────────────────────────────────────────────
+
+/// How long a warp pair stays inactive after Pac-Man uses it, in seconds.
+///
+/// Without this, Pac-Man could bounce between warp ends forever and the
+/// PvP ghost player would never be able to corner him.
+pub const WARP_COOLDOWN: f64 = 4.0;
+
+/// A warp pair that was used recently and can't be taken again yet.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WarpCooldown {
+    /// The warp pair id (matches `CellType::Warp(id)`).
+    pub id: u8,
+    /// Seconds until the pair can be used again.
+    pub remaining: f64,
+}
+
+// ─── Score breakdown ────────────────────────────────────────────────────────
+
+/// Where Pac-Man's points came from, for the end-of-level tally.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreBreakdown {
+    pub dots: u32,
+    pub power_pellets: u32,
+    pub ghosts: u32,
+    pub fruit: u32,
+    /// Urgency-mode time bonuses banked at level completion.
+    pub time_bonus: u32,
+}
+
+// ─── Frame snapshot ─────────────────────────────────────────────────────────
+
+/// What the renderer needs every frame, for `to_js_frame()`.
+///
+/// Leaves the maze out: JS fetches it once with `get_maze_bytes()` and
+/// keeps it current from `CellChanged` events, refetching only after
+/// `LevelAdvanced`. The fog mask is left out too: it only moves with the
+/// players and the board, so read `visibility()` after a tick that reports
+/// `Change::Entities` or `Change::Maze`. Borrows from the state, so
+/// building one copies nothing.
+#[derive(Debug, Serialize)]
+pub struct FrameSnapshot<'a> {
+    pub mode: GameMode,
+    pub phase: GamePhase,
+    pub pacman: &'a PacMan,
+    pub pacman2: Option<&'a PacMan>,
+    pub ghosts: &'a [Ghost],
+    pub fruit: Option<&'a Fruit>,
+    pub dots_remaining: usize,
+    pub level: u32,
+    pub countdown: u8,
+    pub pellets_visible: bool,
+    pub animation: &'a Animation,
+    pub events: &'a [GameEvent],
 }
 
 // ─── Inner Game State (pure Rust) ───────────────────────────────────────────
@@ -65,36 +313,283 @@ pub enum GamePhase {
 /// When dropped, all nested data is freed automatically (RAII — no GC needed).
 /// There's no shared ownership or reference counting because only one
 /// `GameStateInner` exists at a time.
+///
+/// Fields missing from saved data take their values from a new classic
+/// game, so saves from before a field existed still load (see `save.rs`).
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct GameStateInner {
     pub mode: GameMode,
+    pub config: GameConfig,
     pub phase: GamePhase,
     pub maze: Maze,
     pub pacman: PacMan,
+    /// The second Pac-Man, in Co-op only.
+    pub pacman2: Option<PacMan>,
     pub ghosts: Vec<Ghost>,
     pub dots_remaining: usize,
     pub level: u32,
     pub global_timer: f64,
     pub frightened_timer: f64,
+    /// Seconds the ghosts stay frozen by a time freeze (see `freeze.rs`).
+    pub freeze_timer: f64,
+    /// Seconds left in a timed phase (`Dying`, `Respawning`, ...). In
+    /// `Ready`, non-zero once the countdown has started.
+    pub phase_timer: f64,
+    /// Last whole second reported with a `Countdown` event (0 = none).
+    pub countdown: u8,
+    /// Whether power pellets are drawn this frame (they blink during play).
+    pub pellets_visible: bool,
+    /// Time into the current pellet blink cycle, in seconds.
+    pub blink_clock: f64,
+    /// Actors of the intermission cutscene being played (empty otherwise).
+    pub cutscene: Vec<Actor>,
+    /// Sprite frames for this tick (see `animation.rs`).
+    pub animation: Animation,
+    /// Points still shown where a ghost or fruit was eaten.
+    pub popups: Vec<ScorePopup>,
+    /// Dark mazes: which tiles Pac-Man can see, row-major (see `fog.rs`).
+    /// Empty when the whole maze is lit.
+    pub visibility: Vec<bool>,
+    /// The real game, set aside while a demo plays (see `demo.rs`).
+    #[serde(skip)]
+    pub demo_backup: Option<Box<GameStateInner>>,
+    /// Shared ghost-release counter, active after a death (see `house.rs`).
+    pub global_dot_counter: Option<u32>,
+    /// Seconds since Pac-Man last ate a dot, for the release fallback.
+    pub house_dot_timer: f64,
+    /// Seconds Pac-Man still stands still after eating (see `DOT_STALL`).
+    pub eat_stall: f64,
+    /// Whether the next dot plays `WakaAlt` rather than `Waka`.
+    pub waka_alt: bool,
+    /// The sound loop JS was last told to start, if still playing.
+    pub sound_loop: Option<SoundCue>,
+    /// The bonus fruit on the board, if any (see `fruit.rs`).
+    pub fruit: Option<Fruit>,
+    /// Dots and pellets eaten this level, for fruit spawns.
+    pub dots_eaten: u32,
+    /// Whether the bonus life has been given (it's awarded once per game).
+    pub extra_life_awarded: bool,
+    /// Best score so far, kept across `reset()` (see `hud.rs`).
+    pub high_score: u32,
+    /// Warp pairs currently on cooldown (only active ones are listed).
+    pub warp_cooldowns: Vec<WarpCooldown>,
+    /// Events produced by the most recent `tick()`.
+    pub events: Vec<GameEvent>,
+    /// Events from every tick since the last `drain_events()`.
+    #[serde(skip)]
+    pub pending_events: Vec<GameEvent>,
+    /// Seconds since the timed walls last toggled.
+    pub timed_wall_timer: f64,
+    /// The switch Pac-Man is standing on, so it only fires once per visit.
+    pub active_switch: Option<(usize, usize)>,
+    /// Campaign progress when playing a sequence of mazes.
+    pub campaign: Option<Campaign>,
+    /// The best-of-N match this PvP game is a round of, if any.
+    pub pvp_match: Option<Match>,
+    /// Who is at the controls (1 or 2) in alternating two-player.
+    pub active_player: u8,
+    /// The other player's game in alternating two-player.
+    pub waiting_player: Option<Box<PlayerBoard>>,
+    /// Urgency-mode bonus still up for grabs this level (for the HUD).
+    pub urgency_bonus: f64,
+    pub score_breakdown: ScoreBreakdown,
+    /// Tallies for the stats screen (see `stats.rs`).
+    pub stats: Stats,
+    /// What the seed decided, in a daily challenge.
+    pub daily: Option<Daily>,
+    /// The run's clocks, in Survival only.
+    pub survival: Option<Survival>,
+    /// Objectives and hints, in the tutorial only.
+    pub tutorial: Option<Tutorial>,
+    /// The ghost player's points in PvP.
+    pub ghost_score: GhostScore,
+    /// Deterministic RNG seeded from `config.seed`.
+    pub rng: Rng,
+    /// Score and phase as of the end of the last tick, used to emit
+    /// `ScoreChanged` / `PhaseChanged` no matter what caused the change.
+    pub last_score: u32,
+    pub last_phase: GamePhase,
+    /// Progress tracking for stuck-entity recovery.
+    pub watchdog: Watchdog,
+    /// Game seconds per real second (see `set_time_scale`). A playback
+    /// setting rather than part of the game, so saves leave it out.
+    #[serde(skip)]
+    pub time_scale: f64,
+    /// The board as it was when the level started, used to refill it for
+    /// the next level. Not serialized: it's a copy of a known layout.
+    #[serde(skip)]
+    pub level_maze: Maze,
+    /// `entity_positions()` and `entity_codes()` as the current tick
+    /// began. Kept between ticks so `tick` can reuse the storage.
+    #[serde(skip)]
+    entities_before: (Vec<f64>, Vec<u8>),
+}
+
+impl Default for GameStateInner {
+    fn default() -> Self {
+        Self::new(GameMode::Classic)
+    }
 }
 
 impl GameStateInner {
     /// Create a new game with the given mode.
     pub fn new(mode: GameMode) -> Self {
-        let maze = Maze::new();
+        Self::with_config(mode, GameConfig::default())
+    }
+
+    /// Create a new game with the given mode and rule settings.
+    pub fn with_config(mode: GameMode, config: GameConfig) -> Self {
+        let maze = config
+            .maze_policy
+            .maze(1, config.seed)
+            .unwrap_or_default()
+            .oriented(config.mirror, config.flip);
         let dots = maze.dots_remaining();
+        let ghosts = starting_ghosts(&config, &maze.metadata);
+        let mut pacman = PacMan::new();
+        pacman.lives = config
+            .starting_lives
+            .saturating_add(config.assist.extra_lives);
 
-        GameStateInner {
+        let mut state = GameStateInner {
             mode,
+            level_maze: maze.clone(),
+            urgency_bonus: config.urgency_bonus as f64,
+            rng: Rng::new(config.seed),
+            last_score: 0,
+            last_phase: GamePhase::Ready,
+            watchdog: Watchdog::default(),
+            time_scale: 1.0,
+            animation: Animation::default(),
+            popups: Vec::new(),
+            visibility: Vec::new(),
+            config,
             phase: GamePhase::Ready,
             maze,
-            pacman: PacMan::new(),
-            ghosts: Ghost::create_all(),
+            pacman2: (mode == GameMode::Coop).then(|| player_two(pacman.lives)),
+            pacman,
+            ghosts,
             dots_remaining: dots,
             level: 1,
             global_timer: 0.0,
             frightened_timer: 0.0,
+            freeze_timer: 0.0,
+            phase_timer: 0.0,
+            countdown: 0,
+            pellets_visible: true,
+            blink_clock: 0.0,
+            cutscene: Vec::new(),
+            demo_backup: None,
+            global_dot_counter: None,
+            house_dot_timer: 0.0,
+            eat_stall: 0.0,
+            waka_alt: false,
+            sound_loop: None,
+            fruit: None,
+            dots_eaten: 0,
+            extra_life_awarded: false,
+            high_score: 0,
+            warp_cooldowns: Vec::new(),
+            events: Vec::new(),
+            pending_events: Vec::new(),
+            entities_before: (Vec::new(), Vec::new()),
+            timed_wall_timer: 0.0,
+            active_switch: None,
+            campaign: None,
+            pvp_match: None,
+            active_player: 1,
+            waiting_player: None,
+            score_breakdown: ScoreBreakdown::default(),
+            stats: Stats::default(),
+            ghost_score: GhostScore::default(),
+            daily: None,
+            survival: (mode == GameMode::Survival).then(Survival::new),
+            tutorial: None,
+        };
+        state.respawn_players();
+        state.update_fog();
+        state
+    }
+
+    /// Swap in a new maze and reset everything tied to the board.
+    ///
+    /// Entities go back to their spawn points and timers restart, but
+    /// Pac-Man's lives, score, and inventory are untouched.
+    pub fn load_maze(&mut self, maze: Maze) {
+        self.level_maze = maze.clone();
+        self.maze = maze;
+        self.reset_board();
+    }
+
+    /// Start a brand-new game in place: first maze, level 1, full lives,
+    /// zero score, and a freshly seeded RNG. Mode, config, and campaign
+    /// layouts are kept; a PvP match starts over from round 1.
+    ///
+    /// The board is restored into the existing maze buffers (`clone_from`
+    /// reuses their allocations), so restarting doesn't churn the heap.
+    pub fn reset(&mut self) {
+        if let Some(campaign) = &mut self.campaign {
+            campaign.current = 0;
+            campaign.results.clear();
+            campaign.maze_start_score = 0;
+            self.level_maze.clone_from(&campaign.mazes[0]);
+        } else if let Some(maze) = self.maze_for_level(1) {
+            self.level_maze = maze;
+        }
+        if let Some(m) = &mut self.pvp_match {
+            *m = Match::new(m.rounds);
+        }
+        self.maze.clone_from(&self.level_maze);
+        self.reset_board();
+
+        self.pacman = PacMan::new();
+        self.pacman.lives = self
+            .config
+            .starting_lives
+            .saturating_add(self.config.assist.extra_lives);
+        self.pacman2 = (self.mode == GameMode::Coop).then(|| player_two(self.pacman.lives));
+        self.respawn_players();
+        self.level = 1;
+        self.extra_life_awarded = false;
+        self.score_breakdown = ScoreBreakdown::default();
+        self.stats = Stats::default();
+        self.ghost_score = GhostScore::default();
+        self.survival = (self.mode == GameMode::Survival).then(Survival::new);
+        if let Some(tutorial) = &mut self.tutorial {
+            *tutorial = Tutorial::new();
         }
+        self.rng = Rng::new(self.config.seed);
+        self.cutscene.clear();
+        self.demo_backup = None;
+        self.reset_players();
+    }
+
+    /// Reset everything tied to the board in `maze`.
+    pub(crate) fn reset_board(&mut self) {
+        self.dots_remaining = self.maze.dots_remaining();
+        self.respawn_players();
+        self.ghosts = starting_ghosts(&self.config, &self.maze.metadata);
+        self.phase = GamePhase::Ready;
+        self.phase_timer = 0.0;
+        self.countdown = 0;
+        self.pellets_visible = true;
+        self.blink_clock = 0.0;
+        self.global_dot_counter = None;
+        self.house_dot_timer = 0.0;
+        self.eat_stall = 0.0;
+        self.fruit = None;
+        self.dots_eaten = 0;
+        self.global_timer = 0.0;
+        self.frightened_timer = 0.0;
+        self.freeze_timer = 0.0;
+        self.timed_wall_timer = 0.0;
+        self.warp_cooldowns.clear();
+        self.active_switch = None;
+        self.watchdog = Watchdog::default();
+        self.events.clear();
+        self.urgency_bonus = self.config.urgency_bonus as f64;
+        self.update_fog();
     }
 
     /// Get the mode as a string.
@@ -102,19 +597,93 @@ impl GameStateInner {
         match self.mode {
             GameMode::Classic => "classic",
             GameMode::PvP => "pvp",
+            GameMode::Coop => "coop",
+            GameMode::Survival => "survival",
         }
     }
 
     /// Get the phase as a string.
     pub fn phase_str(&self) -> &'static str {
-        match self.phase {
-            GamePhase::Ready => "ready",
-            GamePhase::Playing => "playing",
-            GamePhase::Paused => "paused",
-            GamePhase::GameOver => "gameover",
+        phase_name(self.phase)
+    }
+
+    /// Get the mode as a stable numeric code: `0` classic, `1` pvp,
+    /// `2` co-op, `3` survival.
+    pub fn mode_code(&self) -> u8 {
+        self.mode as u8
+    }
+
+    /// Get the phase as a stable numeric code:
+    /// `0` ready, `1` playing, `2` paused, `3` gameover, `4` dying,
+    /// `5` respawning, `6` level complete, `7` intermission, `8` demo.
+    pub fn phase_code(&self) -> u8 {
+        self.phase.id() as u8
+    }
+
+    /// The per-frame view of this state (see `FrameSnapshot`).
+    pub fn frame(&self) -> FrameSnapshot<'_> {
+        FrameSnapshot {
+            mode: self.mode,
+            phase: self.phase,
+            pacman: &self.pacman,
+            pacman2: self.pacman2.as_ref(),
+            ghosts: &self.ghosts,
+            fruit: self.fruit.as_ref(),
+            dots_remaining: self.dots_remaining,
+            level: self.level,
+            countdown: self.countdown,
+            pellets_visible: self.pellets_visible,
+            animation: &self.animation,
+            events: &self.events,
         }
     }
 
+    /// Entity positions flattened for per-frame rendering:
+    /// `[pac_x, pac_y, g0_x, g0_y, ...]`, ghosts in `ghosts` order, then
+    /// player two's Pac-Man in Co-op.
+    pub fn entity_positions(&self) -> Vec<f64> {
+        self.entity_position_values().collect()
+    }
+
+    fn entity_position_values(&self) -> impl Iterator<Item = f64> + '_ {
+        let pac = &self.pacman.position;
+        let ghosts = self.ghosts.iter().map(|g| &g.position);
+        let pac2 = self.pacman2.iter().map(|p| &p.position);
+        std::iter::once(pac)
+            .chain(ghosts)
+            .chain(pac2)
+            .flat_map(|p| [p.x, p.y])
+    }
+
+    /// Codes parallel to `entity_positions()`:
+    /// `[pac_dir, g0_mode, g0_dir, g1_mode, g1_dir, ..., pac2_dir]` (see
+    /// `Direction::code` and `GhostMode::code`).
+    pub fn entity_codes(&self) -> Vec<u8> {
+        self.entity_code_values().collect()
+    }
+
+    fn entity_code_values(&self) -> impl Iterator<Item = u8> + '_ {
+        let ghosts = self
+            .ghosts
+            .iter()
+            .flat_map(|g| [g.mode.code(), g.direction.code()]);
+        let pac2 = self.pacman2.iter().map(|p| p.direction.code());
+        std::iter::once(self.pacman.direction.code())
+            .chain(ghosts)
+            .chain(pac2)
+    }
+
+    /// Milliseconds of power-pellet time left (0 when none is active).
+    pub fn frightened_remaining_ms(&self) -> f64 {
+        self.frightened_timer * 1000.0
+    }
+
+    /// Whether any ghost is currently frightened (and can be eaten).
+    /// Ghosts eaten or still inside the house don't keep this true.
+    pub fn is_any_ghost_frightened(&self) -> bool {
+        self.ghosts.iter().any(|g| g.mode == GhostMode::Frightened)
+    }
+
     /// Check if the game is over (no lives remaining).
     pub fn is_game_over(&self) -> bool {
         self.pacman.lives == 0
@@ -125,27 +694,349 @@ impl GameStateInner {
         self.dots_remaining == 0
     }
 
-    /// Advance the game state by `dt` seconds.
-    pub fn tick(&mut self, dt: f64) {
-        if self.phase != GamePhase::Playing {
+    /// Pause the game. Only live gameplay can be paused by the player;
+    /// returns whether the game is now paused.
+    pub fn pause(&mut self) -> bool {
+        if self.phase == GamePhase::Playing {
+            self.phase = GamePhase::UserPaused;
+        }
+        self.phase == GamePhase::UserPaused
+    }
+
+    /// Resume after `pause()`. Waits the player didn't start (dying, level
+    /// transitions, ...) can't be skipped; returns whether play resumed.
+    pub fn resume(&mut self) -> bool {
+        if self.phase != GamePhase::UserPaused {
+            return false;
+        }
+        self.phase = GamePhase::Playing;
+        true
+    }
+
+    /// Why gameplay is on hold, or `None` while it's running (`Playing`
+    /// or a demo).
+    pub fn pause_reason(&self) -> Option<PauseReason> {
+        match self.phase {
+            GamePhase::Playing | GamePhase::Demo => None,
+            GamePhase::UserPaused => Some(PauseReason::User),
+            GamePhase::Ready => Some(PauseReason::Ready),
+            GamePhase::Dying | GamePhase::Respawning => Some(PauseReason::Death),
+            GamePhase::LevelComplete | GamePhase::Intermission(_) => Some(PauseReason::Level),
+            GamePhase::GameOver => Some(PauseReason::GameOver),
+        }
+    }
+
+    /// Queue Pac-Man's next direction; the first input starts the game.
+    pub fn set_direction(&mut self, direction: Direction) {
+        self.pacman.buffer_turn(direction);
+        self.start_on_input();
+    }
+
+    /// The ghost Player 2 drives in PvP: the first in the roster (Blinky,
+    /// unless the roster says otherwise). `None` outside PvP.
+    pub fn player_ghost(&self) -> Option<usize> {
+        (self.mode == GameMode::PvP && !self.ghosts.is_empty()).then_some(0)
+    }
+
+    /// Queue Player 2's next direction: the second Pac-Man's in Co-op, the
+    /// PvP ghost's (see `player_ghost`) otherwise.
+    pub fn set_player2_direction(&mut self, direction: Direction) {
+        if let Some(p2) = &mut self.pacman2 {
+            p2.buffer_turn(direction);
+        } else if let Some(ghost) = self.ghosts.first_mut() {
+            ghost.turns().push(direction);
+        }
+        self.start_on_input();
+    }
+
+    /// Start the READY countdown; play begins when it runs out. Input
+    /// during a demo just ends the demo.
+    fn start_on_input(&mut self) {
+        if self.phase == GamePhase::Demo {
+            self.stop_demo();
+        } else if self.phase == GamePhase::Ready && self.phase_timer <= 0.0 {
+            self.phase_timer = READY_DURATION;
+        }
+    }
+
+    /// Slow the game down (below 1) or speed it up (above 1); every timer
+    /// and speed follows. 0 freezes it. Clamped to `0..=MAX_TIME_SCALE`;
+    /// NaN is ignored.
+    pub fn set_time_scale(&mut self, scale: f64) {
+        if !scale.is_nan() {
+            self.time_scale = scale.clamp(0.0, MAX_TIME_SCALE);
+        }
+    }
+
+    /// Advance the game state by `dt` real seconds (scaled by `time_scale`).
+    /// At most `MAX_FRAME` of it is played; a negative or non-finite `dt`
+    /// is ignored.
+    ///
+    /// Returns a mask of `Change` bits for what this tick changed.
+    pub fn tick(&mut self, dt: f64) -> u8 {
+        if !(dt.is_finite() && dt >= 0.0) {
+            return 0;
+        }
+        let scale = self.time_scale * self.config.assist.game_speed;
+        let played = dt.min(MAX_FRAME) * scale;
+        let dt = dt * scale;
+        let pac_before = self.pacman.position.clone();
+        let mut before = std::mem::take(&mut self.entities_before);
+        before.0.clear();
+        before.0.extend(self.entity_position_values());
+        before.1.clear();
+        before.1.extend(self.entity_code_values());
+        let fruit_before = self.fruit.is_some();
+        let dots_before = self.dots_remaining;
+        self.events.clear();
+        self.update_phase_timer(dt);
+        let steps = (played / MAX_STEP).ceil().max(1.0);
+        for _ in 0..steps as usize {
+            // A death or cleared board ends the frame early
+            if !matches!(self.phase, GamePhase::Playing | GamePhase::Demo) {
+                break;
+            }
+            if self.phase == GamePhase::Demo || self.config.assist.autopilot {
+                self.autopilot_steer();
+            }
+            self.step_playing(played / steps);
+        }
+        self.update_demo(dt);
+        self.update_match(dt);
+        self.update_tutorial();
+        self.update_stats();
+        self.update_cutscene();
+        self.update_animation(dt, &pac_before);
+        self.update_popups(dt);
+        self.update_fog();
+        self.check_extra_life();
+        let p2_score = self.pacman2.as_ref().map_or(0, |p| p.score);
+        self.high_score = self.high_score.max(self.pacman.score).max(p2_score);
+        self.update_sound_loops();
+        self.emit_change_events();
+        self.queue_events();
+
+        let mut changes = 0;
+        for event in &self.events {
+            changes |= match event {
+                GameEvent::ScoreChanged { .. } => Change::Score as u8,
+                GameEvent::PhaseChanged { .. } => Change::Phase as u8,
+                GameEvent::CellChanged { .. }
+                | GameEvent::DoorOpened { .. }
+                | GameEvent::DotEaten { .. }
+                | GameEvent::PowerPelletEaten { .. }
+                | GameEvent::LevelAdvanced { .. }
+                | GameEvent::PlayerChanged { .. } => Change::Maze as u8,
+                _ => 0,
+            };
+        }
+        if self.dots_remaining != dots_before {
+            changes |= Change::Maze as u8;
+        }
+        if !self.entity_position_values().eq(before.0.iter().copied())
+            || !self.entity_code_values().eq(before.1.iter().copied())
+            || self.fruit.is_some() != fruit_before
+        {
+            changes |= Change::Entities as u8;
+        }
+        self.entities_before = before;
+        if !self.pending_events.is_empty() {
+            changes |= Change::Events as u8;
+        }
+        changes
+    }
+
+    /// Copy this tick's events to the queue `drain_events()` empties.
+    pub(crate) fn queue_events(&mut self) {
+        self.pending_events.extend(self.events.iter().cloned());
+        let overflow = self.pending_events.len().saturating_sub(MAX_PENDING_EVENTS);
+        self.pending_events.drain(..overflow);
+    }
+
+    /// Take every event queued since the last call, oldest first. Unlike
+    /// `events`, nothing is lost when several ticks run between reads.
+    pub fn drain_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.pending_events)
+    }
+
+    /// Enter a phase that ends by itself after `duration` seconds.
+    fn enter_timed_phase(&mut self, phase: GamePhase, duration: f64) {
+        self.phase = phase;
+        self.phase_timer = duration;
+    }
+
+    /// Count down the current timed phase and move on when it runs out.
+    fn update_phase_timer(&mut self, dt: f64) {
+        let timed = matches!(
+            self.phase,
+            GamePhase::Ready
+                | GamePhase::Dying
+                | GamePhase::Respawning
+                | GamePhase::LevelComplete
+                | GamePhase::Intermission(_)
+        );
+        if !timed || self.phase_timer <= 0.0 {
             return;
         }
+        self.phase_timer -= dt;
+        if self.phase_timer > 0.0 {
+            let counting = matches!(self.phase, GamePhase::Ready | GamePhase::Respawning);
+            let seconds = self.phase_timer.ceil() as u8;
+            if counting && seconds != self.countdown {
+                self.countdown = seconds;
+                self.events.push(GameEvent::Countdown { seconds });
+            }
+            return;
+        }
+        self.phase_timer = 0.0;
+        self.countdown = 0;
+
+        match self.phase {
+            GamePhase::Dying if !self.players_left() && !self.player_waiting() => {
+                self.phase = GamePhase::GameOver
+            }
+            GamePhase::Dying => {
+                self.switch_players();
+                self.respawn_players();
+                self.ghosts = starting_ghosts(&self.config, &self.maze.metadata);
+                self.frightened_timer = 0.0;
+                self.freeze_timer = 0.0;
+                self.global_dot_counter = Some(0);
+                self.house_dot_timer = 0.0;
+                self.eat_stall = 0.0;
+                self.fruit = None;
+                self.watchdog = Watchdog::default();
+                self.enter_timed_phase(GamePhase::Respawning, RESPAWN_DURATION);
+            }
+            GamePhase::Ready | GamePhase::Respawning => self.phase = GamePhase::Playing,
+            // Campaigns wait here for `transition_to_next_maze()`
+            GamePhase::LevelComplete if self.campaign.is_some() => {}
+            GamePhase::LevelComplete => match intermission_act(self.level) {
+                Some(act) => {
+                    self.enter_timed_phase(GamePhase::Intermission(act), INTERMISSION_DURATION)
+                }
+                None => self.advance_level(),
+            },
+            GamePhase::Intermission(_) => self.advance_level(),
+            _ => {}
+        }
+    }
 
+    /// One frame of active gameplay: timers, movement, collisions.
+    fn step_playing(&mut self, dt: f64) {
         self.update_timers(dt);
+        self.update_urgency(dt);
+        self.update_ghost_score(dt);
+        self.update_survival(dt);
+        self.update_timed_walls(dt);
+        self.update_boosts(dt);
+        self.stats.add_time(self.level, dt);
+
+        // Eating costs Pac-Man a few frames, letting chasing ghosts gain
+        let moving = (dt - self.eat_stall).max(0.0);
+        self.eat_stall = (self.eat_stall - dt).max(0.0);
+
+        let pac_tile = self.pacman.position.to_grid();
+        let p2_tile = self.pacman2.as_ref().map(|p| p.position.to_grid());
+        let ghost_tiles: Vec<_> = self.ghosts.iter().map(|g| g.position.to_grid()).collect();
+        let p1_in_play = self.player_one_in_play();
+
+        self.expire_inputs(dt);
+        if p1_in_play {
+            let from = self.pacman.position.clone();
+            self.update_pacman(self.pacman_dist(moving));
+            self.stats.add_distance(&from, &self.pacman.position);
+        }
+        self.update_warps(dt);
+        self.update_switches();
+        self.with_player_two(|s| {
+            s.update_pacman(s.pacman_dist(moving));
+            s.update_warps(0.0);
+        });
+        if !self.ghosts_frozen() {
+            self.update_house(dt);
+            self.update_ghosts(dt);
+            self.update_ghost_warps(&ghost_tiles);
+        }
+        self.update_freeze(dt);
+        if p1_in_play {
+            self.check_collisions(pac_tile, &ghost_tiles);
+        }
+        if let Some(p2_tile) = p2_tile {
+            if matches!(self.phase, GamePhase::Playing | GamePhase::Demo) {
+                self.with_player_two(|s| s.check_collisions(p2_tile, &ghost_tiles));
+            }
+        }
+        self.update_fruit(dt);
+        self.with_player_two(|s| s.update_fruit(0.0));
+        self.update_watchdog(dt);
+        self.update_ghost_looks();
+        self.update_pellet_blink(dt);
+    }
+
+    /// How far Pac-Man moves in `moving` seconds at his current speed.
+    fn pacman_dist(&self, moving: f64) -> f64 {
+        let speeds = self.speeds();
+        let pac_percent = if self.frightened_timer > 0.0 {
+            speeds.pacman_frightened
+        } else {
+            speeds.pacman
+        };
+        let pac_surface = self
+            .maze
+            .surface_at(self.pacman.position.x, self.pacman.position.y);
+        SpeedTable::speed(pac_percent)
+            * self.config.pacman_speed
+            * self.pacman.boost_factor()
+            * pac_surface.speed_multiplier
+            * moving
+    }
+
+    /// Keep each ghost's render look in step with its mode.
+    fn update_ghost_looks(&mut self) {
+        let fright = FrightSchedule::for_level(self.level);
+        let flashing = fright.is_flashing(self.frightened_timer);
+        let white = fright.is_white(self.frightened_timer);
+        for ghost in &mut self.ghosts {
+            let frightened = ghost.mode == GhostMode::Frightened;
+            ghost.look = GhostLook::for_mode(ghost.mode);
+            ghost.frightened_flashing = flashing && frightened;
+            ghost.flash_white = white && frightened;
+        }
+    }
 
-        const PAC_SPEED: f64 = 11.0; // Tiles per second
-        let pac_dist = PAC_SPEED * dt;
+    /// Advance the power-pellet blink while the board is live.
+    fn update_pellet_blink(&mut self, dt: f64) {
+        self.blink_clock = (self.blink_clock + dt) % (2.0 * PELLET_BLINK_PERIOD);
+        self.pellets_visible = self.blink_clock < PELLET_BLINK_PERIOD;
+    }
 
-        self.update_pacman(pac_dist);
-        self.update_ghosts(dt);
-        self.check_collisions();
+    /// Emit `ScoreChanged` / `PhaseChanged` for anything that changed
+    /// since the end of the previous tick (including changes made by
+    /// input between ticks).
+    fn emit_change_events(&mut self) {
+        if self.pacman.score != self.last_score {
+            self.last_score = self.pacman.score;
+            self.events.push(GameEvent::ScoreChanged {
+                score: self.pacman.score,
+            });
+        }
+        if self.phase != self.last_phase {
+            self.events.push(GameEvent::PhaseChanged {
+                from: self.last_phase,
+                to: self.phase,
+            });
+            self.last_phase = self.phase;
+        }
     }
 
     fn update_timers(&mut self, dt: f64) {
         let old_frightened = self.frightened_timer > 0.0;
 
         if self.frightened_timer > 0.0 {
-            self.frightened_timer -= dt;
+            if !self.config.assist.infinite_frightened {
+                self.frightened_timer -= dt;
+            }
             if self.frightened_timer <= 0.0 {
                 self.frightened_timer = 0.0;
             }
@@ -153,30 +1044,38 @@ impl GameStateInner {
             self.global_timer += dt;
         }
 
-        let cycle_time = self.global_timer % 27.0;
-        let global_mode = if cycle_time < 7.0 {
-            GhostMode::Scatter
-        } else {
-            GhostMode::Chase
-        };
+        let global_mode = self.wave_mode();
 
         let mut toggle_reverse = false;
+        // Cruise Elroy: Blinky ignores scatter waves
+        let elroy = self.elroy_stage() > 0;
+        let mode_for = |ghost: &Ghost| {
+            if let Some(mode) = ghost.pinned_mode {
+                mode
+            } else if elroy && ghost.ghost_type == GhostType::Blinky {
+                GhostMode::Chase
+            } else {
+                global_mode
+            }
+        };
 
         // If we just recovered from frightened, we restore modes
         if old_frightened && self.frightened_timer <= 0.0 {
             for ghost in &mut self.ghosts {
                 if ghost.mode == GhostMode::Frightened {
-                    ghost.mode = global_mode;
+                    ghost.mode = mode_for(ghost);
                 }
             }
         } else if self.frightened_timer <= 0.0 {
             // Apply global mode changes
             for ghost in &mut self.ghosts {
+                let wanted = mode_for(ghost);
                 if (ghost.mode == GhostMode::Chase || ghost.mode == GhostMode::Scatter)
-                    && ghost.mode != global_mode
+                    && ghost.mode != wanted
                 {
-                    ghost.mode = global_mode;
-                    toggle_reverse = true;
+                    // Only a wave change reverses everyone, not Elroy kicking in
+                    toggle_reverse |= wanted == global_mode;
+                    ghost.mode = wanted;
                 }
             }
         }
@@ -192,34 +1091,37 @@ impl GameStateInner {
 
     fn get_ghost_target(
         ghost: &Ghost,
+        meta: &MazeMetadata,
         pac_pos: &crate::entities::Position,
         pac_dir: Direction,
         blinky_pos: &crate::entities::Position,
-        global_timer: f64,
+        roll: u32,
+        overflow_bug: bool,
     ) -> (isize, isize) {
-        use crate::entities::GhostType;
+        // The tile `n` ahead of Pac-Man (up-and-left with the overflow bug)
+        let ahead = |n: isize| {
+            let (c, r) = pac_pos.to_grid();
+            let (dx, dy) = pac_dir.to_vector();
+            let bug = if overflow_bug && pac_dir == Direction::Up {
+                n
+            } else {
+                0
+            };
+            (
+                c as isize + dx as isize * n - bug,
+                r as isize + dy as isize * n,
+            )
+        };
         match ghost.mode {
-            GhostMode::Scatter => match ghost.ghost_type {
-                GhostType::Blinky => (25, -3),
-                GhostType::Pinky => (2, -3),
-                GhostType::Inky => (27, 31),
-                GhostType::Clyde => (0, 31),
-            },
+            GhostMode::Scatter => meta.scatter_targets[ghost.ghost_type.code() as usize],
             GhostMode::Chase => match ghost.ghost_type {
                 GhostType::Blinky => {
                     let (c, r) = pac_pos.to_grid();
                     (c as isize, r as isize)
                 }
-                GhostType::Pinky => {
-                    let (c, r) = pac_pos.to_grid();
-                    let (dx, dy) = pac_dir.to_vector();
-                    (c as isize + dx as isize * 4, r as isize + dy as isize * 4)
-                }
+                GhostType::Pinky => ahead(4),
                 GhostType::Inky => {
-                    let (pc, pr) = pac_pos.to_grid();
-                    let (dx, dy) = pac_dir.to_vector();
-                    let pivot_c = pc as isize + dx as isize * 2;
-                    let pivot_r = pr as isize + dy as isize * 2;
+                    let (pivot_c, pivot_r) = ahead(2);
                     let (bc, br) = blinky_pos.to_grid();
                     let vec_c = pivot_c - bc as isize;
                     let vec_r = pivot_r - br as isize;
@@ -233,78 +1135,144 @@ impl GameStateInner {
                     if dist_sq > 64 {
                         (c as isize, r as isize)
                     } else {
-                        (0, 31)
+                        meta.scatter_targets[GhostType::Clyde.code() as usize]
                     }
                 }
             },
             GhostMode::Frightened => {
-                // Pseudo-random wander
-                let seed = global_timer * 10.0 + ghost.position.x * 3.0;
-                ((seed as isize) % 28, (seed as isize * 7) % 31)
+                // Random wander: `roll` comes from the seeded game RNG
+                ((roll % 28) as isize, ((roll / 28) % 31) as isize)
             }
             GhostMode::Eaten => {
-                (14, 11) // House entrance
+                let (col, row) = meta.house_center;
+                (col as isize, row as isize)
             }
         }
     }
 
+    /// The tile each ghost is steering toward, as `(col, row)`, in `ghosts`
+    /// order — the classic target markers for a debug overlay. `None` for
+    /// ghosts that don't chase a tile: frightened ones (they pick a random
+    /// tile at each junction), ones in or leaving the house, and the
+    /// player-controlled ghost. May lie outside the maze, like the scatter
+    /// corners.
+    pub fn ghost_targets(&self) -> Vec<Option<(isize, isize)>> {
+        let blinky = self
+            .ghosts
+            .iter()
+            .find(|g| g.ghost_type == GhostType::Blinky)
+            .unwrap_or(&self.ghosts[0]);
+        let player = self.player_ghost();
+        let targets = self.chase_targets();
+        self.ghosts
+            .iter()
+            .enumerate()
+            .map(|(i, ghost)| {
+                let is_player = player == Some(i) && ghost.mode != GhostMode::Eaten;
+                if ghost.house != HouseState::Outside
+                    || ghost.mode == GhostMode::Frightened
+                    || is_player
+                {
+                    return None;
+                }
+                let (pac_pos, pac_dir) = nearest(&targets, &ghost.position);
+                Some(Self::get_ghost_target(
+                    ghost,
+                    &self.maze.metadata,
+                    pac_pos,
+                    *pac_dir,
+                    &blinky.position,
+                    0,
+                    self.config.uses_overflow_bug(),
+                ))
+            })
+            .collect()
+    }
+
     fn update_ghosts(&mut self, dt: f64) {
         // Different speeds depending on mode
-        let base_speed = 9.0;
-
-        let pac_pos = self.pacman.position.clone();
-        let pac_dir = self.pacman.direction;
-        let mut blinky_pos = self.ghosts[0].position.clone();
-        for g in &self.ghosts {
-            if g.ghost_type == crate::entities::GhostType::Blinky {
-                blinky_pos = g.position.clone();
+        let speeds = self.speeds();
+        let base_speed = SpeedTable::speed(speeds.ghost);
+        let elroy_speed = SpeedTable::speed(speeds.elroy(self.elroy_stage()));
+        let wave_mode = self.wave_mode();
+
+        let targets = self.chase_targets();
+        // Inky pivots on the first Blinky (or whoever leads the roster)
+        let blinky_pos = self
+            .ghosts
+            .iter()
+            .find(|g| g.ghost_type == GhostType::Blinky)
+            .unwrap_or(&self.ghosts[0])
+            .position
+            .clone();
+        let player = self.player_ghost();
+        // Same for every ghost: worked out once, not per ghost
+        let scale = self.config.ghost_speed
+            * self.config.assist.ghost_speed()
+            * self.survival_ghost_speed();
+        let classic_rules = self.config.classic_rules();
+        let overflow_bug = self.config.uses_overflow_bug();
+
+        for (i, ghost) in self.ghosts.iter_mut().enumerate() {
+            if ghost.house != HouseState::Outside || ghost.frozen {
+                continue; // Moved by `update_house`, or held by the debug console
             }
-        }
-
-        for ghost in &mut self.ghosts {
             let speed = match ghost.mode {
-                GhostMode::Frightened => base_speed * 0.5,
+                GhostMode::Frightened => SpeedTable::speed(speeds.ghost_frightened),
                 GhostMode::Eaten => base_speed * 2.0,
+                _ if ghost.ghost_type == GhostType::Blinky => elroy_speed,
                 _ => base_speed,
             };
-            let dist = speed * dt;
-
-            // If Eaten and reaches house, revive
-            if ghost.mode == GhostMode::Eaten {
-                let (c, r) = ghost.position.to_grid();
-                if c == 14 && r == 11 {
-                    ghost.mode = if self.frightened_timer <= 0.0 {
-                        if (self.global_timer % 27.0) < 7.0 {
-                            GhostMode::Scatter
-                        } else {
-                            GhostMode::Chase
-                        }
-                    } else {
-                        GhostMode::Chase // Or wait in house
-                    };
-                }
+            // Tunnels slow every ghost except eyes heading home
+            let in_tunnel =
+                classic_rules && self.maze.is_tunnel(ghost.position.x, ghost.position.y);
+            let speed = if in_tunnel && ghost.mode != GhostMode::Eaten {
+                speed.min(SpeedTable::speed(speeds.ghost_tunnel))
+            } else {
+                speed
+            };
+            let surface = self.maze.surface_at(ghost.position.x, ghost.position.y);
+            let dist = speed * scale * surface.speed_multiplier * dt;
+
+            // If Eaten and reaches house, revive and head back out
+            if ghost.mode == GhostMode::Eaten
+                && ghost.position.to_grid() == self.maze.metadata.house_center
+            {
+                ghost.house = HouseState::LeavingHouse;
+                ghost.mode = if self.frightened_timer <= 0.0 {
+                    wave_mode
+                } else {
+                    GhostMode::Chase // Or wait in house
+                };
+                continue;
             }
 
-            let is_player = self.mode == GameMode::PvP
-                && ghost.ghost_type == crate::entities::GhostType::Blinky
+            let is_player = player == Some(i)
                 && ghost.mode != GhostMode::Eaten
                 && ghost.mode != GhostMode::Frightened;
 
-            if is_player && ghost.next_direction != ghost.direction {
+            if is_player
+                && ghost.next_direction != ghost.direction
+                && self
+                    .maze
+                    .can_steer(ghost.position.x, ghost.position.y, ghost.direction)
+            {
                 if ghost.next_direction == ghost.direction.opposite() {
-                    ghost.direction = ghost.next_direction;
+                    if self
+                        .maze
+                        .can_leave(ghost.position.x, ghost.position.y, ghost.next_direction)
+                    {
+                        ghost.direction = ghost.next_direction;
+                    }
                 } else {
                     let cx = ghost.position.x.round();
                     let cy = ghost.position.y.round();
                     let is_near_center = (ghost.position.x - cx).abs() <= dist
                         && (ghost.position.y - cy).abs() <= dist;
-                    if is_near_center {
-                        let (dx, dy) = ghost.next_direction.to_vector();
-                        if self.maze.is_walkable(cx + dx, cy + dy) {
-                            ghost.position.x = cx;
-                            ghost.position.y = cy;
-                            ghost.direction = ghost.next_direction;
-                        }
+                    if is_near_center && self.maze.can_move(cx, cy, ghost.next_direction) {
+                        ghost.position.x = cx;
+                        ghost.position.y = cy;
+                        ghost.direction = ghost.next_direction;
                     }
                 }
             }
@@ -324,25 +1292,30 @@ impl GameStateInner {
             };
 
             if is_player {
-                let next_cx = cx + dx;
-                let next_cy = cy + dy;
                 if (crossed_center
                     || (dx > 0.0 && new_x > cx)
                     || (dx < 0.0 && new_x < cx)
                     || (dy > 0.0 && new_y > cy)
                     || (dy < 0.0 && new_y < cy))
-                    && !self.maze.is_walkable(next_cx, next_cy)
+                    && !self.maze.can_move(cx, cy, ghost.direction)
                 {
                     new_x = cx;
                     new_y = cy;
                 }
+            } else if crossed_center && !self.maze.can_steer(cx, cy, ghost.direction) {
+                // Sliding on ice: snap to center and keep going straight
+                new_x = cx;
+                new_y = cy;
             } else if crossed_center {
+                let (pac_pos, pac_dir) = nearest(&targets, &ghost.position);
                 let target = Self::get_ghost_target(
                     ghost,
-                    &pac_pos,
-                    pac_dir,
+                    &self.maze.metadata,
+                    pac_pos,
+                    *pac_dir,
                     &blinky_pos,
-                    self.global_timer,
+                    self.rng.next_u32(),
+                    overflow_bug,
                 );
 
                 let possible_dirs = [
@@ -355,16 +1328,24 @@ impl GameStateInner {
                 let mut min_dist_sq = f64::MAX;
 
                 let mut options = 0;
+                // Frightened and eaten ghosts ignore the no-up zones
+                let no_up = matches!(ghost.mode, GhostMode::Chase | GhostMode::Scatter)
+                    && classic_rules
+                    && self.maze.is_no_up(cx as usize, cy as usize);
 
                 for &dir in &possible_dirs {
                     if dir == ghost.direction.opposite() {
                         continue; // No reversing
                     }
+                    if no_up && dir == Direction::Up {
+                        continue;
+                    }
                     let (tdx, tdy) = dir.to_vector();
                     let tx = cx + tdx;
                     let ty = cy + tdy;
 
-                    if self.maze.is_walkable(tx, ty) || ghost.mode == GhostMode::Eaten {
+                    let through_door = ghost.mode == GhostMode::Eaten;
+                    if self.maze.can_move_ghost(cx, cy, dir, through_door) {
                         options += 1;
                         let dist_sq =
                             (tx - target.0 as f64).powi(2) + (ty - target.1 as f64).powi(2);
@@ -379,6 +1360,19 @@ impl GameStateInner {
                     best_dir = ghost.direction.opposite(); // Fallback if dead end
                 }
 
+                // Eyes don't guess: they follow the shortest route home
+                // (which may mean turning around). Greedy choice above is
+                // only the fallback for mazes where the house is unreachable.
+                if ghost.mode == GhostMode::Eaten {
+                    let here = (cx as usize, cy as usize);
+                    if let Some(dir) =
+                        self.maze
+                            .route_step(here, self.maze.metadata.house_center, true)
+                    {
+                        best_dir = dir;
+                    }
+                }
+
                 // Snap to center and switch to new direction
                 new_x = cx;
                 new_y = cy;
@@ -398,33 +1392,59 @@ impl GameStateInner {
         }
     }
 
+    /// Age the buffered turns of Pac-Man and the PvP ghost, drop those
+    /// older than `config.input_window`, and line up the next of each.
+    fn expire_inputs(&mut self, dt: f64) {
+        let window = self.config.input_window;
+        self.pacman.turns().age(dt, window);
+        if let Some(pac) = &mut self.pacman2 {
+            pac.turns().age(dt, window);
+        }
+        if let Some(i) = self.player_ghost() {
+            if let Some(ghost) = self.ghosts.get_mut(i) {
+                ghost.turns().age(dt, window);
+            }
+        }
+    }
+
     fn update_pacman(&mut self, dist: f64) {
         let pac = &mut self.pacman;
 
-        // 1. Can we turn to `next_direction`?
-        if pac.next_direction != pac.direction {
-            // Immediate reverse is always allowed
+        // 1. Can we turn to `next_direction`? (not while sliding on ice)
+        if pac.next_direction != pac.direction
+            && self
+                .maze
+                .can_steer(pac.position.x, pac.position.y, pac.direction)
+        {
+            // Immediate reverse is always allowed (unless backing out of a one-way gate)
             if pac.next_direction == pac.direction.opposite() {
-                pac.direction = pac.next_direction;
+                if self
+                    .maze
+                    .can_leave(pac.position.x, pac.position.y, pac.next_direction)
+                {
+                    pac.direction = pac.next_direction;
+                }
             } else {
                 // To turn 90 degrees, we must be close to the tile center
                 let cx = pac.position.x.round();
                 let cy = pac.position.y.round();
 
-                // Are we close enough to the center to turn?
+                // Are we close enough to the center to turn? Within the
+                // cornering window we turn early and cut the corner below.
+                let window = dist.max(self.config.cornering_window);
                 let is_near_center =
-                    (pac.position.x - cx).abs() <= dist && (pac.position.y - cy).abs() <= dist;
+                    (pac.position.x - cx).abs() <= window && (pac.position.y - cy).abs() <= window;
 
                 if is_near_center {
                     // Peek at the tile in the next_direction
-                    let (dx, dy) = pac.next_direction.to_vector();
-                    let target_x = cx + dx;
-                    let target_y = cy + dy;
-
-                    if self.maze.is_walkable(target_x, target_y) {
-                        // Snap to center and turn
-                        pac.position.x = cx;
-                        pac.position.y = cy;
+                    if self.maze.can_move(cx, cy, pac.next_direction) {
+                        // Snap to center when it's in reach this step
+                        if (pac.position.x - cx).abs() <= dist
+                            && (pac.position.y - cy).abs() <= dist
+                        {
+                            pac.position.x = cx;
+                            pac.position.y = cy;
+                        }
                         pac.direction = pac.next_direction;
                     }
                 }
@@ -436,12 +1456,18 @@ impl GameStateInner {
         let mut new_x = pac.position.x + dx * dist;
         let mut new_y = pac.position.y + dy * dist;
 
-        // 3. Wall collision: if we passed the center and the next tile is a wall, clamp to center
         let cx = pac.position.x.round();
         let cy = pac.position.y.round();
 
-        let next_cx = cx + dx;
-        let next_cy = cy + dy;
+        // After an early turn, drift back onto the lane while moving along
+        // it: the diagonal corner cut
+        if dx != 0.0 {
+            new_y += (cy - pac.position.y).clamp(-dist, dist);
+        } else {
+            new_x += (cx - pac.position.x).clamp(-dist, dist);
+        }
+
+        // 3. Wall collision: if we passed the center and the next tile is a wall, clamp to center
 
         // Check if we crossed the center boundary towards a wall
         let crossed_center = match pac.direction {
@@ -456,11 +1482,28 @@ impl GameStateInner {
             || (dx < 0.0 && new_x < cx)
             || (dy > 0.0 && new_y > cy)
             || (dy < 0.0 && new_y < cy))
-            && !self.maze.is_walkable(next_cx, next_cy)
+            && !self.maze.can_move(cx, cy, pac.direction)
         {
-            // Clamp to center
-            new_x = cx;
-            new_y = cy;
+            let broken = if pac.wall_breaks > 0 {
+                self.maze.breakable_wall(cx, cy, pac.direction)
+            } else {
+                None
+            };
+
+            if let Some((row, col)) = broken {
+                // Smash through: the wall is gone for the rest of the level
+                pac.wall_breaks -= 1;
+                self.maze.set_cell(row, col, CellType::Empty);
+                self.events.push(GameEvent::CellChanged {
+                    row,
+                    col,
+                    cell: CellType::Empty,
+                });
+            } else {
+                // Clamp to center
+                new_x = cx;
+                new_y = cy;
+            }
         }
 
         pac.position.x = new_x;
@@ -475,70 +1518,301 @@ impl GameStateInner {
         }
     }
 
-    fn check_collisions(&mut self) {
-        // Collect dot/pellet collisions
-        let (col, row) = self.pacman.position.to_grid();
-        if let Some(cell) = self.maze.get_cell(row, col) {
-            match cell {
-                CellType::Dot => {
-                    self.pacman.score += 10;
-                    self.dots_remaining -= 1;
-                    self.maze.cells[row][col] = CellType::Empty;
-                }
-                CellType::PowerPellet => {
-                    self.pacman.score += 50;
-                    self.dots_remaining -= 1;
-                    self.maze.cells[row][col] = CellType::Empty;
-                    // Frighten ghosts
-                    for ghost in &mut self.ghosts {
-                        if ghost.mode != GhostMode::Eaten {
-                            ghost.mode = GhostMode::Frightened;
-                            // Reversing direction when frightened is classic behavior
-                            ghost.direction = ghost.direction.opposite();
-                        }
-                    }
-                }
-                _ => {}
-            }
-        }
+    /// Change a maze cell and record it in the event stream.
+    pub(crate) fn set_cell(&mut self, row: usize, col: usize, cell: CellType) {
+        self.maze.set_cell(row, col, cell);
+        self.events.push(GameEvent::CellChanged { row, col, cell });
+    }
 
-        // Ghost collisions
-        for ghost in &mut self.ghosts {
-            let dx = self.pacman.position.x - ghost.position.x;
-            let dy = self.pacman.position.y - ghost.position.y;
-            let dist_sq = dx * dx + dy * dy;
+    /// Open/close every timed wall once per `maze.timed_wall_period`.
+    ///
+    /// A wall never closes on top of an entity: if anyone is standing on a
+    /// wall that would close, the toggle is postponed to the next tick.
+    fn update_timed_walls(&mut self, dt: f64) {
+        self.timed_wall_timer += dt;
+        if self.timed_wall_timer < self.maze.timed_wall_period {
+            return;
+        }
 
-            if dist_sq < 0.25 {
-                // Collision distance (radius 0.5)
-                match ghost.mode {
-                    GhostMode::Frightened => {
-                        self.pacman.score += 200; // Base score for eating ghost
-                        ghost.mode = GhostMode::Eaten;
-                    }
-                    GhostMode::Chase | GhostMode::Scatter => {
-                        if self.pacman.lives > 0 {
-                            self.pacman.lives -= 1;
-                        }
-                        if self.pacman.lives == 0 {
-                            self.phase = GamePhase::GameOver;
-                        } else {
-                            self.phase = GamePhase::Paused; // Wait for respawn
-                        }
-                    }
-                    GhostMode::Eaten => {}
-                }
-            }
+        let walls = self.maze.timed_walls();
+        let blocked = walls.iter().any(|&(row, col)| {
+            self.maze.cells[row][col] == CellType::TimedWall { open: true }
+                && self.is_occupied(row, col)
+        });
+        if blocked {
+            return;
         }
 
-        if self.dots_remaining == 0 {
-            self.phase = GamePhase::Paused; // Level complete wait state
+        self.timed_wall_timer -= self.maze.timed_wall_period;
+        for (row, col) in walls {
+            if let CellType::TimedWall { open } = self.maze.cells[row][col] {
+                self.set_cell(row, col, CellType::TimedWall { open: !open });
+            }
         }
     }
-}
-
-// ─── WASM-exposed wrapper ───────────────────────────────────────────────────
 
-/// Opaque wrapper exposed to JavaScript via wasm-bindgen.
+    /// Toggle linked gates when Pac-Man steps onto a switch.
+    ///
+    /// The switch fires once when entered; standing on it does nothing
+    /// more until Pac-Man leaves and comes back. Gates with an entity
+    /// inside them stay open rather than closing on top of it.
+    fn update_switches(&mut self) {
+        let (col, row) = self.pacman.position.to_grid();
+        if self.maze.get_cell(row, col) != Some(CellType::Switch) {
+            self.active_switch = None;
+            return;
+        }
+        if self.active_switch == Some((row, col)) {
+            return;
+        }
+        self.active_switch = Some((row, col));
+
+        let gates = self.maze.linked_gates(row, col).to_vec();
+        for (g_row, g_col) in gates {
+            if let Some(CellType::Gate { open }) = self.maze.get_cell(g_row, g_col) {
+                if open && self.is_occupied(g_row, g_col) {
+                    continue;
+                }
+                self.set_cell(g_row, g_col, CellType::Gate { open: !open });
+            }
+        }
+    }
+
+    /// Add a key to Pac-Man's inventory and open every door it fits.
+    fn collect_key(&mut self, key: u8) {
+        if !self.pacman.keys.contains(&key) {
+            self.pacman.keys.push(key);
+        }
+        for (row, col) in self.maze.positions_of(CellType::LockedDoor(key)) {
+            self.set_cell(row, col, CellType::Empty);
+            self.events.push(GameEvent::DoorOpened { row, col, key });
+        }
+    }
+
+    /// Check whether Pac-Man or any ghost is on the tile at (row, col).
+    fn is_occupied(&self, row: usize, col: usize) -> bool {
+        std::iter::once(&self.pacman.position)
+            .chain(self.ghosts.iter().map(|g| &g.position))
+            .any(|p| p.to_grid() == (col, row))
+    }
+
+    /// Tick down warp cooldowns and teleport Pac-Man if he steps on a ready warp.
+    fn update_warps(&mut self, dt: f64) {
+        for cooldown in &mut self.warp_cooldowns {
+            cooldown.remaining -= dt;
+        }
+        self.warp_cooldowns.retain(|c| c.remaining > 0.0);
+
+        let (col, row) = self.pacman.position.to_grid();
+        let id = match self.maze.get_cell(row, col) {
+            Some(CellType::Warp(id)) => id,
+            _ => return,
+        };
+        if self.warp_cooldowns.iter().any(|c| c.id == id) {
+            return;
+        }
+
+        if let Some((to_row, to_col)) = self.maze.warp_partner(row, col) {
+            self.pacman.position.x = to_col as f64;
+            self.pacman.position.y = to_row as f64;
+            self.warp_cooldowns.push(WarpCooldown {
+                id,
+                remaining: WARP_COOLDOWN,
+            });
+        }
+    }
+
+    /// Carry ghosts that just stepped onto a warp to its partner, keeping
+    /// their direction. `before` holds their tiles at the start of the
+    /// step: a ghost only warps on the step it arrives, so it can walk off
+    /// the far end without bouncing back. Eyes ignore warps so they can't
+    /// shuttle back and forth on the way home.
+    fn update_ghost_warps(&mut self, before: &[(usize, usize)]) {
+        for (ghost, &was) in self.ghosts.iter_mut().zip(before) {
+            if ghost.house != HouseState::Outside || ghost.mode == GhostMode::Eaten {
+                continue;
+            }
+            let (col, row) = ghost.position.to_grid();
+            if (col, row) == was {
+                continue;
+            }
+            if let Some((to_row, to_col)) = self.maze.warp_partner(row, col) {
+                ghost.position.x = to_col as f64;
+                ghost.position.y = to_row as f64;
+            }
+        }
+    }
+
+    /// Eat what Pac-Man is on and resolve ghost contact. `pac_before` and
+    /// `ghosts_before` are the tiles at the start of the step, used to
+    /// catch tile swaps in `CollisionMode::Tile`.
+    fn check_collisions(&mut self, pac_before: (usize, usize), ghosts_before: &[(usize, usize)]) {
+        // Collect dot/pellet collisions
+        let (col, row) = self.pacman.position.to_grid();
+        if let Some(cell) = self.maze.get_cell(row, col) {
+            match cell {
+                CellType::Dot => {
+                    let points = self.config.scoring.dot;
+                    self.pacman.score = self.pacman.score.saturating_add(points);
+                    self.score_breakdown.dots = self.score_breakdown.dots.saturating_add(points);
+                    self.dots_remaining -= 1;
+                    self.set_cell(row, col, CellType::Empty);
+                    self.eat_stall = DOT_STALL;
+                    self.count_house_dot();
+                    self.count_fruit_dot();
+                    self.events.push(GameEvent::DotEaten { row, col });
+                    let waka = if self.waka_alt {
+                        SoundCue::WakaAlt
+                    } else {
+                        SoundCue::Waka
+                    };
+                    self.waka_alt = !self.waka_alt;
+                    self.events.push(GameEvent::sound(waka));
+                }
+                CellType::WallBreaker => {
+                    self.pacman.wall_breaks = self.pacman.wall_breaks.saturating_add(1);
+                    self.set_cell(row, col, CellType::Empty);
+                }
+                CellType::Key(key) => {
+                    self.set_cell(row, col, CellType::Empty);
+                    self.collect_key(key);
+                }
+                CellType::TimeFreeze => {
+                    self.set_cell(row, col, CellType::Empty);
+                    self.freeze_ghosts(row, col);
+                }
+                CellType::SpeedBoost => {
+                    self.set_cell(row, col, CellType::Empty);
+                    self.pacman.boost = BOOST_SECONDS;
+                }
+                CellType::PowerPellet => {
+                    let points = self.config.scoring.power_pellet;
+                    self.pacman.score = self.pacman.score.saturating_add(points);
+                    self.score_breakdown.power_pellets =
+                        self.score_breakdown.power_pellets.saturating_add(points);
+                    self.dots_remaining -= 1;
+                    self.set_cell(row, col, CellType::Empty);
+                    self.eat_stall = PELLET_STALL;
+                    self.count_house_dot();
+                    self.count_fruit_dot();
+                    self.events.push(GameEvent::PowerPelletEaten { row, col });
+                    self.events.push(GameEvent::sound(SoundCue::PowerPellet));
+                    let fright = FrightSchedule::for_level(self.level);
+                    self.frightened_timer = fright.seconds;
+                    // Frighten ghosts (on late levels they only turn around)
+                    for ghost in &mut self.ghosts {
+                        if ghost.mode != GhostMode::Eaten {
+                            if fright.seconds > 0.0 {
+                                ghost.mode = GhostMode::Frightened;
+                            }
+                            // Reversing direction when frightened is classic behavior
+                            ghost.direction = ghost.direction.opposite();
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Ghost collisions
+        let pac_tile = self.pacman.position.to_grid();
+        let mut caught = false;
+        for (i, ghost) in self.ghosts.iter_mut().enumerate() {
+            let touching = match self.config.collision_mode() {
+                CollisionMode::Distance => {
+                    let dx = self.pacman.position.x - ghost.position.x;
+                    let dy = self.pacman.position.y - ghost.position.y;
+                    // Collision distance (radius 0.5)
+                    dx * dx + dy * dy < 0.25
+                }
+                CollisionMode::Tile => {
+                    let tile = ghost.position.to_grid();
+                    let swapped = ghosts_before.get(i) == Some(&pac_tile) && tile == pac_before;
+                    tile == pac_tile || swapped
+                }
+            };
+
+            if touching {
+                match ghost.mode {
+                    GhostMode::Frightened => {
+                        let points = self.config.scoring.ghost;
+                        self.pacman.score = self.pacman.score.saturating_add(points);
+                        self.score_breakdown.ghosts =
+                            self.score_breakdown.ghosts.saturating_add(points);
+                        ghost.mode = GhostMode::Eaten;
+                        self.events.push(GameEvent::GhostEaten {
+                            ghost: ghost.ghost_type,
+                            index: i,
+                            points,
+                        });
+                        self.events.push(GameEvent::sound(SoundCue::GhostEaten));
+                    }
+                    // Only one death per tick, even if two ghosts touch him
+                    GhostMode::Chase | GhostMode::Scatter
+                        if matches!(self.phase, GamePhase::Playing | GamePhase::Demo)
+                            && !self.config.assist.invincible =>
+                    {
+                        if self.pacman.lives > 0 {
+                            self.pacman.lives -= 1;
+                        }
+                        self.events.push(GameEvent::LifeLost {
+                            lives: self.pacman.lives,
+                        });
+                        self.events.push(GameEvent::sound(SoundCue::Death));
+                        caught = true;
+                        self.phase = GamePhase::Dying;
+                        self.phase_timer = DYING_DURATION;
+                    }
+                    GhostMode::Chase | GhostMode::Scatter | GhostMode::Eaten => {}
+                }
+            }
+        }
+
+        if caught {
+            self.score_capture();
+        }
+
+        // Survival has no levels: the board refills instead
+        if self.dots_remaining == 0 && self.mode != GameMode::Survival {
+            self.events
+                .push(GameEvent::LevelComplete { level: self.level });
+            self.bank_urgency_bonus();
+            self.enter_timed_phase(GamePhase::LevelComplete, LEVEL_COMPLETE_DURATION);
+        }
+    }
+
+    /// Drain the urgency-mode bonus while the level is being played.
+    fn update_urgency(&mut self, dt: f64) {
+        let decay = self.config.urgency_decay_per_second * dt;
+        self.urgency_bonus = (self.urgency_bonus - decay).max(0.0);
+    }
+
+    /// Award the bonus life the first time the score reaches the threshold.
+    fn check_extra_life(&mut self) {
+        let threshold = self.config.extra_life_score;
+        if threshold == 0 || self.extra_life_awarded || self.pacman.score < threshold {
+            return;
+        }
+        self.extra_life_awarded = true;
+        self.pacman.lives = self.pacman.lives.saturating_add(1);
+        self.events.push(GameEvent::ExtraLife {
+            lives: self.pacman.lives,
+        });
+        self.events.push(GameEvent::sound(SoundCue::ExtraLife));
+    }
+
+    /// Add whatever urgency bonus is left to the score (level complete).
+    fn bank_urgency_bonus(&mut self) {
+        let bonus = self.urgency_bonus.floor() as u32;
+        self.pacman.score = self.pacman.score.saturating_add(bonus);
+        self.score_breakdown.time_bonus = self.score_breakdown.time_bonus.saturating_add(bonus);
+        self.urgency_bonus = 0.0;
+    }
+}
+
+// ─── WASM-exposed wrapper ───────────────────────────────────────────────────
+
+/// Opaque wrapper exposed to JavaScript via wasm-bindgen.
 ///
 /// # Why a wrapper?
 /// wasm-bindgen requires `#[wasm_bindgen]` on the struct itself, but it
@@ -555,6 +1829,71 @@ impl GameStateInner {
 #[wasm_bindgen]
 pub struct GameState {
     inner: GameStateInner,
+    // Push-style listeners, invoked at the end of each `tick()`
+    on_score_change: Option<js_sys::Function>,
+    on_phase_change: Option<js_sys::Function>,
+    on_sound_event: Option<js_sys::Function>,
+    on_event: Option<js_sys::Function>,
+    #[cfg(feature = "framebuffer")]
+    framebuffer: crate::framebuffer::Framebuffer,
+}
+
+impl GameState {
+    fn from_inner(inner: GameStateInner) -> GameState {
+        GameState {
+            inner,
+            on_score_change: None,
+            on_phase_change: None,
+            on_sound_event: None,
+            on_event: None,
+            #[cfg(feature = "framebuffer")]
+            framebuffer: Default::default(),
+        }
+    }
+
+    /// Invoke registered callbacks for this tick's events.
+    ///
+    /// Exceptions thrown by a callback are swallowed: a buggy listener
+    /// must not break the game loop.
+    fn dispatch_callbacks(&self) {
+        for event in &self.inner.events {
+            if let Some(callback) = &self.on_event {
+                if let Ok(arg) = serde_wasm_bindgen::to_value(event) {
+                    let _ = callback.call1(&JsValue::NULL, &arg);
+                }
+            }
+            // JS values are only made when someone is listening
+            let (callback, arg) = match event {
+                GameEvent::ScoreChanged { score } => match &self.on_score_change {
+                    Some(callback) => (callback, JsValue::from(*score)),
+                    None => continue,
+                },
+                GameEvent::PhaseChanged { to, .. } => match &self.on_phase_change {
+                    Some(callback) => (callback, JsValue::from(phase_name(*to))),
+                    None => continue,
+                },
+                GameEvent::Sound {
+                    cue,
+                    action,
+                    channel,
+                    priority,
+                } => {
+                    if let Some(callback) = &self.on_sound_event {
+                        let args = js_sys::Array::of4(
+                            &JsValue::from(cue.as_str()),
+                            &JsValue::from(channel.as_str()),
+                            &JsValue::from(*priority),
+                            &JsValue::from(action.as_str()),
+                        );
+                        let _ = callback.apply(&JsValue::NULL, &args);
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            let _ = callback.call1(&JsValue::NULL, &arg);
+        }
+    }
 }
 
 #[wasm_bindgen]
@@ -562,69 +1901,317 @@ impl GameState {
     /// Create a new game state.
     ///
     /// # Arguments
-    /// * `mode` — `"classic"` or `"pvp"` (case-insensitive)
+    /// * `mode` — `"classic"`, `"pvp"`, `"coop"` or `"survival"`
+    ///   (case-insensitive)
+    ///
+    /// # Errors
+    /// Throws on an unknown mode. Like every fallible method here, this
+    /// returns an error instead of panicking: a Rust panic aborts the whole
+    /// WASM instance, while a thrown `Error` can be caught in JS.
     ///
     /// # Why `&str` and not `GameMode`?
-    /// wasm-bindgen cannot pass Rust enums directly across the WASM boundary.
-    /// We accept a string and parse it inside Rust, keeping the type-safe
-    /// `GameMode` enum as the internal representation.
+    /// wasm-bindgen can only pass fieldless enums across the WASM boundary,
+    /// as plain numbers. Strings read better in app code, so the constructor
+    /// keeps them; `with_mode(GameMode.PvP)` takes the numeric enum.
     #[wasm_bindgen(constructor)]
-    pub fn new(mode: &str) -> GameState {
-        let game_mode = match mode.to_lowercase().as_str() {
-            "classic" => GameMode::Classic,
-            "pvp" => GameMode::PvP,
-            _ => panic!("Invalid game mode: '{}'. Use 'classic' or 'pvp'.", mode),
-        };
+    pub fn new(mode: &str) -> Result<GameState, JsError> {
+        let mode = parse_mode(mode).map_err(|e| JsError::new(&e))?;
+        Ok(GameState::from_inner(GameStateInner::new(mode)))
+    }
 
-        GameState {
-            inner: GameStateInner::new(game_mode),
-        }
+    /// Create a new game from the numeric `GameMode` enum.
+    pub fn with_mode(mode: GameMode) -> GameState {
+        GameState::from_inner(GameStateInner::new(mode))
     }
 
-    /// Sets the intended next direction for Pac-Man.
-    pub fn set_direction(&mut self, dir: &str) {
-        use crate::entities::Direction;
-        let direction = match dir.to_lowercase().as_str() {
-            "up" => Direction::Up,
-            "down" => Direction::Down,
-            "left" => Direction::Left,
-            "right" => Direction::Right,
-            _ => return, // Ignore invalid input
+    /// Create a new game with settings restored from `config_to_string()`.
+    ///
+    /// Missing settings take their defaults. Malformed JSON (e.g. a
+    /// corrupted localStorage entry) falls back to the default config
+    /// rather than refusing to start the game. Throws on an unknown mode
+    /// or assists that `Assist::check` rejects (e.g. a `game_speed` of 0).
+    pub fn with_config(mode: &str, config: &str) -> Result<GameState, JsError> {
+        let mode = parse_mode(mode).map_err(|e| JsError::new(&e))?;
+        let config = config_from_string(config).unwrap_or_default();
+        config.assist.check().map_err(|e| JsError::new(&e))?;
+        Ok(GameState::from_inner(GameStateInner::with_config(
+            mode, config,
+        )))
+    }
+
+    /// Create a new game from a `GameOptions` object, e.g.
+    /// `GameState.with_options({ lives: 5, difficulty: "Easy", ghosts: 2 })`.
+    /// Missing options take their defaults; `undefined` is all defaults.
+    ///
+    /// Throws if the object doesn't fit `GameOptions`, a value is out of
+    /// range (see `options.rs`), or the `maze` layout doesn't parse (see
+    /// `MazeError`) or fails `validate_layout()`.
+    pub fn with_options(options: JsGameOptions) -> Result<GameState, JsError> {
+        let options: GameOptions = if options.is_undefined() || options.is_null() {
+            GameOptions::default()
+        } else {
+            serde_wasm_bindgen::from_value(options.into()).map_err(JsError::from)?
         };
+        GameStateInner::with_options(options)
+            .map(GameState::from_inner)
+            .map_err(|e| JsError::new(&e))
+    }
+
+    /// Start a practice game from a chosen situation: level, positions,
+    /// dots already eaten, wave-schedule offset (see `practice.rs`).
+    ///
+    /// Throws if a placement is in a wall or off the board, or a value is
+    /// out of range.
+    pub fn practice(setup: JsPracticeSetup) -> Result<GameState, JsError> {
+        let setup: PracticeSetup = if setup.is_undefined() || setup.is_null() {
+            PracticeSetup::default()
+        } else {
+            serde_wasm_bindgen::from_value(setup.into()).map_err(JsError::from)?
+        };
+        GameStateInner::practice(setup)
+            .map(GameState::from_inner)
+            .map_err(|e| JsError::new(&e))
+    }
+
+    /// Build an arbitrary situation from a `Scenario` JSON string: maze
+    /// layout, positions and modes, timers, score, phase (see
+    /// `scenario.rs`). For puzzles, repro cases and shared challenges.
+    ///
+    /// Throws if the JSON is malformed, the maze fails to parse or
+    /// `validate_layout()`, a placement is in a wall or off the board, or
+    /// a value is out of range.
+    pub fn from_scenario(json: &str) -> Result<GameState, JsError> {
+        GameStateInner::from_scenario(json)
+            .map(GameState::from_inner)
+            .map_err(|e| JsError::new(&e))
+    }
+
+    /// Check a maze layout (legend as in `GameOptions.maze`) for a maze
+    /// editor: every problem `Maze::validate` finds, so they can all be
+    /// marked at once (see `validate.rs`).
+    ///
+    /// Throws if the layout doesn't parse (see `MazeError`).
+    pub fn validate_layout(layout: &str) -> Result<JsMazeReport, JsError> {
+        let maze: Maze = layout
+            .parse()
+            .map_err(|e: MazeError| JsError::new(&e.to_string()))?;
+        serde_wasm_bindgen::to_value(&maze.validate())
+            .map(JsCast::unchecked_into)
+            .map_err(JsError::from)
+    }
+
+    /// Restore a game saved with `to_json()` (by this or an older build).
+    /// Callbacks are not part of a save; register them again on the
+    /// returned game.
+    ///
+    /// Throws if the save is malformed or from a newer build.
+    pub fn from_json(json: &str) -> Result<GameState, JsError> {
+        GameStateInner::from_json(json)
+            .map(GameState::from_inner)
+            .map_err(JsError::from)
+    }
+
+    /// Serialize the whole game (timers, ghost modes, board, ...) to a
+    /// JSON string, e.g. for localStorage. Restore it with `from_json()`.
+    pub fn to_json(&self) -> String {
+        self.inner.to_json()
+    }
+
+    /// Serialize this game's settings to a JSON string for storage.
+    pub fn config_to_string(&self) -> String {
+        config_to_string(&self.inner.config)
+    }
+
+    /// Start a best-of-`rounds` PvP match (see `pvp.rs`): the players swap
+    /// sides every round, and `pvp_match` in the snapshot keeps the tally.
+    /// Throws on 0 rounds.
+    pub fn pvp_match(rounds: u8) -> Result<GameState, JsError> {
+        GameStateInner::new_match(rounds)
+            .map(GameState::from_inner)
+            .map_err(|e| JsError::new(&e))
+    }
 
-        self.inner.pacman.next_direction = direction;
+    /// Start an alternating two-player Classic game (see `two_player.rs`).
+    pub fn two_player() -> GameState {
+        GameState::from_inner(GameStateInner::two_player())
+    }
+
+    /// Start a PvP game with the autopilot as Pac-Man: Player 2 plays the
+    /// ghost against the computer (see `ai.rs`).
+    pub fn ghosts_vs_cpu() -> GameState {
+        GameState::from_inner(GameStateInner::ghosts_vs_cpu())
+    }
 
-        // Start game on first input
-        if self.inner.phase == GamePhase::Ready {
-            self.inner.phase = GamePhase::Playing;
+    /// Start the daily challenge for `seed` (see `daily.rs`): everyone
+    /// who starts the same seed plays the same game.
+    pub fn daily(seed: u32) -> GameState {
+        GameState::from_inner(GameStateInner::daily(seed))
+    }
+
+    /// Start the tutorial (see `tutorial.rs`): a plain board, one ghost,
+    /// and objectives to work through, with hints along the way.
+    pub fn tutorial() -> GameState {
+        GameState::from_inner(GameStateInner::tutorial())
+    }
+
+    /// Start a chaos game with `count` ghosts (2–64) of random
+    /// personalities from `seed` (see `chaos.rs`). Throws on a bad count.
+    pub fn chaos(count: usize, seed: u32) -> Result<GameState, JsError> {
+        GameStateInner::chaos(count, seed)
+            .map(GameState::from_inner)
+            .map_err(|e| JsError::new(&e))
+    }
+
+    /// Create a campaign that plays the given maze layouts in order.
+    ///
+    /// Each layout is a newline-separated string using the same legend as
+    /// the built-in maze (see `CellType::from_char`). Throws on an unknown
+    /// mode, or a layout that doesn't parse (see `MazeError`) or fails
+    /// `validate_layout()`.
+    pub fn campaign(mode: &str, layouts: Vec<String>) -> Result<GameState, JsError> {
+        let mode = parse_mode(mode).map_err(|e| JsError::new(&e))?;
+        GameStateInner::campaign_from_layouts(mode, &layouts)
+            .map(GameState::from_inner)
+            .map_err(|e| JsError::new(&e))
+    }
+
+    /// Finish the current campaign maze and load the next one.
+    ///
+    /// Returns `false` when the campaign is over (or this isn't one).
+    pub fn transition_to_next_maze(&mut self) -> bool {
+        self.inner.transition_to_next_maze()
+    }
+
+    /// Combined campaign summary as a JS object (`null` outside a campaign).
+    pub fn campaign_summary(&self) -> Result<JsCampaignSummary, JsError> {
+        serde_wasm_bindgen::to_value(&self.inner.campaign_summary())
+            .map(JsCast::unchecked_into)
+            .map_err(JsError::from)
+    }
+
+    /// Sets the intended next direction for Pac-Man: a `Direction` enum
+    /// value (`Direction.Up`) or a string (`"up"`).
+    pub fn set_direction(&mut self, dir: JsValue) {
+        // Ignore invalid input
+        if let Some(direction) = direction_from_js(&dir) {
+            self.inner.set_direction(direction);
         }
     }
 
-    /// Sets the intended next direction for Player 2's Ghost (Blinky).
-    pub fn set_player2_direction(&mut self, dir: &str) {
-        use crate::entities::Direction;
-        let direction = match dir.to_lowercase().as_str() {
-            "up" => Direction::Up,
-            "down" => Direction::Down,
-            "left" => Direction::Left,
-            "right" => Direction::Right,
-            _ => return,
-        };
+    /// Sets the intended next direction for Player 2's Ghost (Blinky), or
+    /// the second Pac-Man in Co-op, as a `Direction` or a string like
+    /// `set_direction`.
+    pub fn set_player2_direction(&mut self, dir: JsValue) {
+        if let Some(direction) = direction_from_js(&dir) {
+            self.inner.set_player2_direction(direction);
+        }
+    }
 
-        if !self.inner.ghosts.is_empty() {
-            self.inner.ghosts[0].next_direction = direction;
+    /// Steer player 1: Pac-Man, or the ghost in PvP match rounds where
+    /// the players have swapped sides.
+    pub fn set_direction_p1(&mut self, dir: JsValue) {
+        if let Some(direction) = direction_from_js(&dir) {
+            self.inner.set_player_direction(1, direction);
         }
+    }
 
-        // Start game on first input
-        if self.inner.phase == GamePhase::Ready {
-            self.inner.phase = GamePhase::Playing;
+    /// Steer player 2: the PvP ghost or the second Co-op Pac-Man, or
+    /// Pac-Man in PvP match rounds where the players have swapped sides.
+    pub fn set_direction_p2(&mut self, dir: JsValue) {
+        if let Some(direction) = direction_from_js(&dir) {
+            self.inner.set_player_direction(2, direction);
         }
     }
 
-    /// Advance game logic by delta time (in milliseconds)
-    pub fn tick(&mut self, dt_ms: f64) {
+    /// Steer Pac-Man with a swipe or analog-stick vector (+y is down): the
+    /// dominant axis picks the direction. Vectors shorter than
+    /// `INPUT_DEADZONE` (a resting stick, a tap) are ignored.
+    pub fn set_direction_vector(&mut self, dx: f64, dy: f64) {
+        if let Some(direction) = Direction::from_vector(dx, dy, INPUT_DEADZONE) {
+            self.inner.set_direction(direction);
+        }
+    }
+
+    /// `set_direction_vector` for Player 2's Ghost (Blinky).
+    pub fn set_player2_direction_vector(&mut self, dx: f64, dy: f64) {
+        if let Some(direction) = Direction::from_vector(dx, dy, INPUT_DEADZONE) {
+            self.inner.set_player2_direction(direction);
+        }
+    }
+
+    /// Advance game logic by delta time (in milliseconds). Returns a mask
+    /// of `Change` bits, so parts of the state this frame left alone need
+    /// not be read again.
+    pub fn tick(&mut self, dt_ms: f64) -> u8 {
         let dt_seconds = dt_ms / 1000.0;
-        self.inner.tick(dt_seconds);
+        let changes = self.inner.tick(dt_seconds);
+        self.dispatch_callbacks();
+        changes
+    }
+
+    /// Advance `n` fixed frames of `frame_ms` each in one call, for
+    /// fast-forward, replay checks and headless runs. The same as calling
+    /// `tick(frame_ms)` `n` times: callbacks fire after every frame, and
+    /// `drain_events()` returns the events of all of them. Returns the
+    /// `Change` bits of every frame combined.
+    pub fn tick_frames(&mut self, n: u32, frame_ms: f64) -> u8 {
+        let mut changes = 0;
+        for _ in 0..n {
+            changes |= self.tick(frame_ms);
+        }
+        changes
+    }
+
+    /// Run the game slower (e.g. `0.5` for slow motion) or faster (`4` to
+    /// fast-forward) without changing the `dt` passed to `tick()`. `1` is
+    /// normal speed and `0` freezes the game; clamped to 0–16.
+    pub fn set_time_scale(&mut self, scale: f64) {
+        self.inner.set_time_scale(scale);
+    }
+
+    pub fn get_time_scale(&self) -> f64 {
+        self.inner.time_scale
+    }
+
+    /// Switch accessibility assists on or off (see `assist.rs`). Fields
+    /// left out are off; errors on an out-of-range value.
+    pub fn set_assist(&mut self, assist: JsAssist) -> Result<(), JsError> {
+        let assist: Assist = serde_wasm_bindgen::from_value(assist.into())?;
+        self.inner.set_assist(assist).map_err(|e| JsError::new(&e))
+    }
+
+    /// Register a callback invoked with the new score whenever it changes.
+    pub fn on_score_change(&mut self, callback: js_sys::Function) {
+        self.on_score_change = Some(callback);
+    }
+
+    /// Register a callback invoked with the new phase name (as returned by
+    /// `get_phase()`) whenever the phase changes.
+    pub fn on_phase_change(&mut self, callback: js_sys::Function) {
+        self.on_phase_change = Some(callback);
+    }
+
+    /// Register a callback invoked as `(cue, channel, priority, action)` for
+    /// every sound event, e.g. `("death", "voice", 255, "play")`.
+    ///
+    /// `action` is `"play"` for one-shots, or `"start"` / `"stop"` for loops
+    /// (`"siren_0"`–`"siren_4"`, `"power_loop"`, `"eyes_loop"`); at most one
+    /// loop plays at a time, and a stop always precedes the next start.
+    ///
+    /// A sound should only cut off one already playing on the same channel
+    /// if its priority is at least as high; priority 255 stops all channels.
+    pub fn on_sound_event(&mut self, callback: js_sys::Function) {
+        self.on_sound_event = Some(callback);
+    }
+
+    /// Register a callback invoked with every event, as the same object
+    /// `drain_events()` returns, in the order they happened.
+    ///
+    /// Events are delivered at the end of the `tick()` that raised them, so
+    /// a listener never misses one however irregularly the render loop
+    /// polls. The `drain_events()` queue is unaffected.
+    pub fn on_event(&mut self, callback: JsEventCallback) {
+        self.on_event = Some(callback.unchecked_into());
     }
 
     /// Serialize the entire game state to a JS object.
@@ -635,9 +2222,57 @@ impl GameState {
     ///
     /// # Performance note
     /// Full serialization on every call (~868 maze cells + entities).
-    /// Fine for debugging; will switch to delta-based updates for 60fps.
-    pub fn to_js(&self) -> JsValue {
-        serde_wasm_bindgen::to_value(&self.inner).unwrap()
+    /// Fine for debugging; per frame, use `to_js_frame()` instead.
+    pub fn to_js(&self) -> Result<JsGameSnapshot, JsError> {
+        serde_wasm_bindgen::to_value(&self.inner)
+            .map(JsCast::unchecked_into)
+            .map_err(JsError::from)
+    }
+
+    /// Like `to_js()`, leaving out what `options` excludes, e.g.
+    /// `{ includeMaze: false }` (see `snapshot.rs`). `undefined` includes
+    /// everything.
+    pub fn to_js_with(&self, options: JsSnapshotOptions) -> Result<JsPartialSnapshot, JsError> {
+        let options: SnapshotOptions = if options.is_undefined() || options.is_null() {
+            SnapshotOptions::default()
+        } else {
+            serde_wasm_bindgen::from_value(options.into()).map_err(JsError::from)?
+        };
+        serde_wasm_bindgen::to_value(&self.inner.snapshot(options))
+            .map(JsCast::unchecked_into)
+            .map_err(JsError::from)
+    }
+
+    /// Serialize just what changes frame to frame (entities, phase, HUD
+    /// values, this tick's events), without the maze.
+    pub fn to_js_frame(&self) -> Result<JsFrameSnapshot, JsError> {
+        serde_wasm_bindgen::to_value(&self.inner.frame())
+            .map(JsCast::unchecked_into)
+            .map_err(JsError::from)
+    }
+
+    /// The maze as a `Uint8Array` of cell codes, row-major
+    /// (`row * width + col`; see `CellType::code` for the table).
+    ///
+    /// Call once per board, then apply `CellChanged` events to keep it
+    /// current; refetch after a `LevelAdvanced` event or a maze change.
+    pub fn get_maze_bytes(&self) -> Vec<u8> {
+        self.inner.maze.to_bytes()
+    }
+
+    /// Address of the maze's cell codes in WASM memory, for a zero-copy
+    /// view: `new Uint8Array(memory.buffer, game.maze_ptr(), game.maze_len())`.
+    ///
+    /// The view follows the game live (eaten dots, opened gates, ...) with
+    /// no further calls. Rebuild it after `LevelAdvanced` or a maze change,
+    /// and whenever `memory.buffer` changes (WASM memory grew).
+    pub fn maze_ptr(&mut self) -> *const u8 {
+        self.inner.maze.byte_view().as_ptr()
+    }
+
+    /// Number of bytes at `maze_ptr()` (`width * height`).
+    pub fn maze_len(&mut self) -> usize {
+        self.inner.maze.byte_view().len()
     }
 
     /// Get the current game mode as a string.
@@ -645,9 +2280,444 @@ impl GameState {
         self.inner.mode_str().to_string()
     }
 
-    /// Get the current game phase as a string.
-    pub fn get_phase(&self) -> String {
-        self.inner.phase_str().to_string()
+    /// Get the current game phase as a string.
+    pub fn get_phase(&self) -> String {
+        self.inner.phase_str().to_string()
+    }
+
+    /// Get the current game mode as a numeric code (no allocation).
+    ///
+    /// Prefer this over `get_mode()` when polling every frame: strings are
+    /// freshly allocated on each call, a number is just returned. Compare
+    /// it with the `GameMode` enum.
+    pub fn get_mode_code(&self) -> GameMode {
+        self.inner.mode
+    }
+
+    /// Get the current game phase as a numeric code (no allocation).
+    ///
+    /// `0` ready, `1` playing, `2` paused, `3` gameover, `4` dying,
+    /// `5` respawning, `6` level complete, `7` intermission, `8` demo —
+    /// the `Phase` enum, so `game.get_phase_code() === Phase.Playing`.
+    pub fn get_phase_code(&self) -> Phase {
+        self.inner.phase.id()
+    }
+
+    /// Check if the tile at (col, row) is a junction (3+ walkable neighbours).
+    pub fn is_intersection(&self, col: usize, row: usize) -> bool {
+        self.inner.maze.is_intersection(col, row)
+    }
+
+    /// Code of the cell at (row, col) (see `CellType::code`), or
+    /// `undefined` outside the maze.
+    pub fn get_cell(&self, row: usize, col: usize) -> Option<u8> {
+        self.inner.maze.get_cell(row, col).map(CellType::code)
+    }
+
+    /// Whether entities can stand at tile coordinates (x, y) (rounded to
+    /// the nearest tile). Off the sides of the board counts as walkable:
+    /// that's the tunnel wrap-around.
+    pub fn is_walkable(&self, x: f64, y: f64) -> bool {
+        self.inner.maze.is_walkable(x, y)
+    }
+
+    /// Maze size in tiles, for indexing `get_maze_bytes()`.
+    pub fn get_maze_width(&self) -> usize {
+        self.inner.maze.width
+    }
+
+    pub fn get_maze_height(&self) -> usize {
+        self.inner.maze.height
+    }
+
+    /// The junction map as a packed bitmap (`Uint8Array`, row-major, LSB first).
+    pub fn get_junction_bitmap(&self) -> Vec<u8> {
+        self.inner.maze.junction_bitmap()
+    }
+
+    /// Every event since the last call, as a JS array of event objects
+    /// (oldest first). Use this instead of reading `events` from the
+    /// snapshot when ticking more than once per frame.
+    pub fn drain_events(&mut self) -> Result<JsGameEvents, JsError> {
+        serde_wasm_bindgen::to_value(&self.inner.drain_events())
+            .map(JsCast::unchecked_into)
+            .map_err(JsError::from)
+    }
+
+    /// Stable numeric codes of the events from the last tick, in order.
+    ///
+    /// Returned as a `Uint16Array`; see `GameEvent::code` for the table.
+    pub fn event_codes(&self) -> Vec<u16> {
+        self.inner.events.iter().map(GameEvent::code).collect()
+    }
+
+    /// Downsampled radar grid (row-major `Uint8Array`, see `minimap.rs`
+    /// for the bit layout). Its width is `get_minimap_width()`.
+    pub fn minimap(&self) -> Vec<u8> {
+        self.inner.minimap()
+    }
+
+    /// Outlines of the maze walls as a flat `Float64Array`:
+    /// `[outer, point_count, x0, y0, ...]` per path, in tile-corner units.
+    ///
+    /// Depends only on the static walls, so call it once per maze (after
+    /// construction or `transition_to_next_maze()`).
+    pub fn wall_paths(&self) -> Vec<f64> {
+        self.inner.maze.wall_path_data()
+    }
+
+    /// Dark mazes: which tiles Pac-Man can see, as a row-major
+    /// `Uint8Array` (1 = visible), as wide as the maze. Empty when the
+    /// whole maze is lit (see `fog.rs`).
+    pub fn visibility(&self) -> Vec<u8> {
+        self.inner.visibility.iter().map(|&v| v as u8).collect()
+    }
+
+    /// Number of columns in the `minimap()` grid.
+    pub fn get_minimap_width(&self) -> usize {
+        self.inner.minimap_size().0
+    }
+
+    /// Pac-Man and ghost positions as a flat `Float64Array`
+    /// `[pac_x, pac_y, g0_x, g0_y, ...]`, in tile units.
+    ///
+    /// Meant for the render loop: unlike `to_js()`, nothing goes through
+    /// serde. Pair with `get_entity_codes()` for modes and directions.
+    pub fn get_entity_positions(&self) -> Vec<f64> {
+        self.inner.entity_positions()
+    }
+
+    /// A `Uint8Array` parallel to `get_entity_positions()`:
+    /// `[pac_dir, g0_mode, g0_dir, ...]`. Directions: `0` up, `1` down,
+    /// `2` left, `3` right; modes: `0` chase, `1` scatter, `2` frightened,
+    /// `3` eaten.
+    pub fn get_entity_codes(&self) -> Vec<u8> {
+        self.inner.entity_codes()
+    }
+
+    /// Every sprite to draw this frame, back to front, as a flat
+    /// `Float64Array`: `[sprite, x, y, frame, flip, ...]` (see `render.rs`
+    /// for the codes). Walls come from `wall_paths()`.
+    pub fn render_commands(&self) -> Vec<f64> {
+        self.inner.render_command_data()
+    }
+
+    /// Intermission actors as a flat `Float64Array`:
+    /// `[kind, x, y, direction, ...]` (see `intermission.rs`). Empty outside
+    /// an intermission.
+    pub fn cutscene(&self) -> Vec<f64> {
+        self.inner.cutscene_data()
+    }
+
+    /// Pause live gameplay. Returns `true` if the game is now paused.
+    pub fn pause(&mut self) -> bool {
+        self.inner.pause()
+    }
+
+    /// Resume a player pause. Returns `false` (and does nothing) unless the
+    /// game was paused with `pause()` — e.g. the death animation can't be
+    /// resumed early.
+    pub fn resume(&mut self) -> bool {
+        self.inner.resume()
+    }
+
+    /// Why gameplay is on hold: `"user"`, `"ready"`, `"death"`, `"level"`,
+    /// `"game_over"`, or `""` while it's running.
+    pub fn pause_reason(&self) -> String {
+        self.inner
+            .pause_reason()
+            .map_or("", PauseReason::as_str)
+            .to_string()
+    }
+
+    /// Start a new game on this instance (e.g. after Game Over) instead of
+    /// constructing a new `GameState`. Callbacks stay registered.
+    pub fn restart(&mut self) {
+        self.inner.reset();
+    }
+
+    /// Start attract mode from the idle READY screen. Returns `false` if
+    /// the game isn't idle. Any input (or `stop_demo()`) ends it.
+    pub fn start_demo(&mut self) -> bool {
+        self.inner.start_demo()
+    }
+
+    /// End attract mode and return to the READY screen.
+    pub fn stop_demo(&mut self) {
+        self.inner.stop_demo();
+    }
+
+    /// The intermission act being played (1–3), or 0.
+    pub fn get_intermission_act(&self) -> u8 {
+        match self.inner.phase {
+            GamePhase::Intermission(act) => act,
+            _ => 0,
+        }
+    }
+
+    /// Which siren loop to play: `0`–`4` as the board empties, `5` while
+    /// ghosts are frightened, `6` while eaten ghosts return home.
+    pub fn get_siren_level(&self) -> u8 {
+        self.inner.siren_level()
+    }
+
+    /// Pac-Man's score. Like the getters below, a plain field read: cheap
+    /// enough to poll every frame for the HUD instead of calling `to_js()`.
+    pub fn get_score(&self) -> u32 {
+        self.inner.pacman.score
+    }
+
+    /// Lives left, including the one in play.
+    pub fn get_lives(&self) -> u8 {
+        self.inner.pacman.lives
+    }
+
+    /// Who is at the controls in alternating two-player: 1 or 2 (always 1
+    /// otherwise).
+    pub fn get_active_player(&self) -> u8 {
+        self.inner.active_player
+    }
+
+    /// Seconds survived in Survival mode (0 in other modes).
+    pub fn get_survival_time(&self) -> f64 {
+        self.inner.survival.as_ref().map_or(0.0, |s| s.elapsed)
+    }
+
+    /// Ghosts in play: how many ghost entries `entity_positions()` and
+    /// `entity_codes()` hold (dozens, in chaos).
+    pub fn get_ghost_count(&self) -> usize {
+        self.inner.ghosts.len()
+    }
+
+    /// Current level, starting at 1.
+    pub fn get_level(&self) -> u32 {
+        self.inner.level
+    }
+
+    /// Dots and power pellets still on the board.
+    pub fn get_dots_remaining(&self) -> usize {
+        self.inner.dots_remaining
+    }
+
+    /// Urgency-mode time bonus still available this level (whole points).
+    pub fn get_urgency_bonus(&self) -> u32 {
+        self.inner.urgency_bonus.floor() as u32
+    }
+
+    /// This game's tallies for a stats screen: dots, pellets, ghosts by
+    /// personality, deaths, distance run, time per level (see `stats.rs`).
+    pub fn get_stats(&self) -> Result<JsStats, JsError> {
+        serde_wasm_bindgen::to_value(&self.inner.stats)
+            .map(JsCast::unchecked_into)
+            .map_err(JsError::from)
+    }
+
+    /// Score, high score, lives, level, fruit row and "1UP" blink, for
+    /// updating the HUD without the world snapshot (see `hud.rs`).
+    pub fn get_hud(&self) -> Result<JsHud, JsError> {
+        serde_wasm_bindgen::to_value(&self.inner.hud())
+            .map(JsCast::unchecked_into)
+            .map_err(JsError::from)
+    }
+
+    /// Best score so far (including the current game).
+    pub fn get_high_score(&self) -> u32 {
+        self.inner.high_score.max(self.inner.pacman.score)
+    }
+
+    /// Seed the high score, e.g. from localStorage. Never lowers it.
+    pub fn set_high_score(&mut self, score: u32) {
+        self.inner.high_score = self.inner.high_score.max(score);
+    }
+
+    /// Milliseconds left on the current power pellet (0 when none), for a
+    /// HUD countdown bar.
+    pub fn frightened_remaining_ms(&self) -> f64 {
+        self.inner.frightened_remaining_ms()
+    }
+
+    /// Each ghost's target tile as `[col, row]`, or `null` when it isn't
+    /// chasing one (see `GameStateInner::ghost_targets`). For debug
+    /// overlays.
+    pub fn get_ghost_targets(&self) -> Result<JsGhostTargets, JsError> {
+        serde_wasm_bindgen::to_value(&self.inner.ghost_targets())
+            .map(JsCast::unchecked_into)
+            .map_err(JsError::from)
+    }
+
+    /// Whether any ghost is frightened right now, for switching sprites.
+    pub fn is_any_ghost_frightened(&self) -> bool {
+        self.inner.is_any_ghost_frightened()
+    }
+}
+
+/// The software renderer (see `framebuffer.rs`).
+#[cfg(feature = "framebuffer")]
+#[wasm_bindgen]
+impl GameState {
+    /// Draw the current frame into the framebuffer.
+    pub fn render_framebuffer(&mut self) {
+        self.inner.render_into(&mut self.framebuffer);
+    }
+
+    /// Address of the RGBA pixels in WASM memory. Valid until the next
+    /// call into the game.
+    pub fn framebuffer_ptr(&self) -> *const u8 {
+        self.framebuffer.pixels.as_ptr()
+    }
+
+    /// Number of bytes at `framebuffer_ptr()` (`width * height * 4`).
+    pub fn framebuffer_len(&self) -> usize {
+        self.framebuffer.pixels.len()
+    }
+
+    pub fn framebuffer_width(&self) -> usize {
+        self.framebuffer.width
+    }
+
+    pub fn framebuffer_height(&self) -> usize {
+        self.framebuffer.height
+    }
+}
+
+/// Sound synthesis (see `audio.rs`).
+#[cfg(feature = "audio")]
+#[wasm_bindgen]
+impl GameState {
+    /// Mono PCM (`Float32Array`, -1 to 1) for a sound cue id as passed to
+    /// `on_sound_event`, e.g. `"waka"` or `"siren_2"`, at `sample_rate` Hz.
+    /// Loop cues are a single cycle, to play with `loop = true`.
+    ///
+    /// # Errors
+    /// Throws on an unknown cue or a zero sample rate.
+    pub fn synthesize_sound(cue: &str, sample_rate: u32) -> Result<Vec<f32>, JsError> {
+        let cue = SoundCue::from_name(cue).ok_or_else(|| JsError::new("unknown sound cue"))?;
+        if sample_rate == 0 {
+            return Err(JsError::new("sample rate must be positive"));
+        }
+        Ok(crate::audio::synthesize(cue, sample_rate))
+    }
+
+    /// Every cue id `synthesize_sound` accepts.
+    pub fn sound_cue_ids() -> Vec<String> {
+        crate::audio::ALL_CUES
+            .iter()
+            .map(|cue| cue.as_str().to_string())
+            .collect()
+    }
+}
+
+#[cfg(feature = "debug")]
+#[wasm_bindgen]
+impl GameState {
+    /// Move `"pacman"` or a ghost (`"blinky"`, ..., or its index in the
+    /// roster, `"0"`, `"1"`, ...) to tile (col, row).
+    ///
+    /// # Errors
+    /// Throws on an unknown name or a tile that isn't walkable.
+    pub fn debug_teleport(&mut self, entity: &str, col: usize, row: usize) -> Result<(), JsError> {
+        let entity = crate::debug::parse_entity(entity).map_err(|e| JsError::new(&e))?;
+        self.inner
+            .debug_teleport(entity, col, row)
+            .map_err(|e| JsError::new(&e))
+    }
+
+    /// Hold a ghost (named as in `debug_teleport`) in place, or let it go
+    /// with `frozen = false`.
+    pub fn debug_freeze_ghost(&mut self, ghost: &str, frozen: bool) -> Result<(), JsError> {
+        let ghost = crate::debug::parse_ghost(ghost).map_err(|e| JsError::new(&e))?;
+        self.inner
+            .debug_freeze(ghost, frozen)
+            .map_err(|e| JsError::new(&e))
+    }
+
+    /// Force a ghost into `"chase"`, `"scatter"`, `"frightened"` or
+    /// `"eaten"`; `"auto"` hands it back to the wave schedule.
+    pub fn debug_force_mode(&mut self, ghost: &str, mode: &str) -> Result<(), JsError> {
+        let ghost = crate::debug::parse_ghost(ghost).map_err(|e| JsError::new(&e))?;
+        let mode = crate::debug::parse_ghost_mode(mode).map_err(|e| JsError::new(&e))?;
+        self.inner
+            .debug_force_mode(ghost, mode)
+            .map_err(|e| JsError::new(&e))
+    }
+
+    pub fn debug_set_score(&mut self, score: u32) {
+        self.inner.debug_set_score(score);
+    }
+
+    pub fn debug_set_lives(&mut self, lives: u8) {
+        self.inner.debug_set_lives(lives);
+    }
+
+    /// Remove the dots and power pellets in a `rows` × `cols` block from
+    /// tile (row, col), unscored. Returns how many were removed.
+    pub fn debug_clear_region(
+        &mut self,
+        row: usize,
+        col: usize,
+        rows: usize,
+        cols: usize,
+    ) -> usize {
+        self.inner.debug_clear_region(row, col, rows, cols)
+    }
+}
+
+/// Parse a game mode string from JS (case-insensitive).
+fn parse_mode(mode: &str) -> Result<GameMode, String> {
+    match mode.to_lowercase().as_str() {
+        "classic" => Ok(GameMode::Classic),
+        "pvp" => Ok(GameMode::PvP),
+        "coop" => Ok(GameMode::Coop),
+        "survival" => Ok(GameMode::Survival),
+        _ => Err(format!(
+            "Invalid game mode: '{}'. Use 'classic', 'pvp', 'coop' or 'survival'.",
+            mode
+        )),
+    }
+}
+
+/// The JS-facing name of a phase.
+fn phase_name(phase: GamePhase) -> &'static str {
+    match phase {
+        GamePhase::Ready => "ready",
+        GamePhase::Playing => "playing",
+        GamePhase::UserPaused => "paused",
+        GamePhase::GameOver => "gameover",
+        GamePhase::Dying => "dying",
+        GamePhase::Respawning => "respawning",
+        GamePhase::LevelComplete => "level_complete",
+        GamePhase::Intermission(_) => "intermission",
+        GamePhase::Demo => "demo",
+    }
+}
+
+/// Read a direction from JS: a `Direction` enum value or a string.
+fn direction_from_js(dir: &JsValue) -> Option<Direction> {
+    match dir.as_string() {
+        Some(name) => parse_direction(&name),
+        None => dir.as_f64().and_then(direction_from_number),
+    }
+}
+
+/// A `Direction` enum value from JS. Only the exact codes 0-3 count:
+/// fractions, negatives, NaN and out-of-range numbers are ignored rather
+/// than truncated onto a real direction.
+fn direction_from_number(n: f64) -> Option<Direction> {
+    if n.fract() == 0.0 && (0.0..=3.0).contains(&n) {
+        Direction::from_code(n as u8)
+    } else {
+        None
+    }
+}
+
+/// Parse a direction string from JS (case-insensitive).
+fn parse_direction(dir: &str) -> Option<Direction> {
+    match dir.to_lowercase().as_str() {
+        "up" => Some(Direction::Up),
+        "down" => Some(Direction::Down),
+        "left" => Some(Direction::Left),
+        "right" => Some(Direction::Right),
+        _ => None,
     }
 }
 
@@ -658,7 +2728,7 @@ impl GameState {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::entities::GhostType;
+    use crate::entities::{GhostType, Position};
     use crate::maze::{MAZE_HEIGHT, MAZE_WIDTH};
 
     // Helper to build inner state (avoids repeating the match logic in tests)
@@ -691,6 +2761,16 @@ mod tests {
         assert_eq!(state.phase_str(), "ready");
     }
 
+    #[test]
+    fn mode_and_phase_codes_match_strings() {
+        let mut state = pvp();
+        assert_eq!(state.mode_code(), 1);
+        assert_eq!(state.phase_code(), 0);
+        state.phase = GamePhase::GameOver;
+        assert_eq!(state.phase_code(), 3);
+        assert_eq!(classic().mode_code(), 0);
+    }
+
     #[test]
     fn new_game_has_correct_maze_size() {
         let state = classic();
@@ -698,6 +2778,23 @@ mod tests {
         assert_eq!(state.maze.height, MAZE_HEIGHT);
     }
 
+    #[test]
+    fn pacman_starts_on_the_oriented_spawn() {
+        for (mirror, flip) in [(true, false), (false, true)] {
+            let config = GameConfig {
+                mirror,
+                flip,
+                ..GameConfig::default()
+            };
+            let gs = GameStateInner::with_config(GameMode::Coop, config);
+            let (x, y) = gs.maze.metadata.pacman_spawn;
+            for pac in [&gs.pacman, gs.pacman2.as_ref().unwrap()] {
+                assert_eq!((pac.position.x, pac.position.y), (x, y));
+            }
+            assert!(gs.maze.is_walkable(x, y));
+        }
+    }
+
     #[test]
     fn new_game_has_four_ghosts() {
         let state = classic();
@@ -755,21 +2852,484 @@ mod tests {
     // Test the WASM wrapper's string parsing
     #[test]
     fn wasm_wrapper_classic() {
-        let gs = GameState::new("classic");
+        let gs = GameState::new("classic").unwrap();
         assert_eq!(gs.get_mode(), "classic");
         assert_eq!(gs.get_phase(), "ready");
     }
 
+    #[test]
+    fn wasm_wrapper_maze_queries() {
+        let gs = GameState::new("classic").unwrap();
+        assert_eq!(gs.get_cell(0, 0), Some(CellType::Wall.code()));
+        assert_eq!(gs.get_cell(1, 1), Some(CellType::Dot.code()));
+        assert_eq!(gs.get_cell(gs.get_maze_height(), 0), None);
+        assert!(!gs.is_walkable(0.0, 0.0));
+        assert!(gs.is_walkable(1.2, 0.9));
+    }
+
+    #[test]
+    fn wasm_wrapper_tick_frames_matches_single_ticks() {
+        let mut batched = GameState::new("classic").unwrap();
+        let mut single = GameState::new("classic").unwrap();
+        batched.inner.phase = GamePhase::Playing;
+        single.inner.phase = GamePhase::Playing;
+        let start = single.inner.pacman.position.x;
+        batched.tick_frames(30, 1000.0 / 60.0);
+        for _ in 0..30 {
+            single.tick(1000.0 / 60.0);
+        }
+        // The game really ran, and both ran it the same
+        assert!(single.inner.global_timer > 0.0);
+        assert_ne!(single.inner.pacman.position.x, start);
+        assert_eq!(batched.inner.global_timer, single.inner.global_timer);
+        assert_eq!(batched.to_json(), single.to_json());
+    }
+
     #[test]
     fn wasm_wrapper_pvp_case_insensitive() {
-        let gs = GameState::new("PVP");
+        let gs = GameState::new("PVP").unwrap();
         assert_eq!(gs.get_mode(), "pvp");
     }
 
+    // `JsError` can't be built off-wasm, so check the message it would carry
+    #[test]
+    fn invalid_mode_is_an_error_not_a_panic() {
+        let err = parse_mode("invalid").unwrap_err();
+        assert!(err.contains("Invalid game mode"));
+    }
+
+    #[test]
+    fn only_whole_direction_codes_steer() {
+        assert_eq!(direction_from_number(0.0), Some(Direction::Up));
+        assert_eq!(direction_from_number(3.0), Some(Direction::Right));
+        for n in [2.5, -1.0, 4.0, 256.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(direction_from_number(n), None);
+        }
+    }
+
+    #[test]
+    fn pacman_cannot_enter_one_way_gate_backwards() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        // Pac-Man starts at (14, 23) heading left; gate at col 13 points right
+        gs.maze.cells[23][13] = CellType::OneWay(Direction::Right);
+
+        for _ in 0..30 {
+            gs.tick(0.016);
+        }
+
+        let (col, row) = gs.pacman.position.to_grid();
+        assert_eq!((col, row), (14, 23), "Gate should block leftward entry");
+    }
+
+    #[test]
+    fn pacman_slides_through_junction_on_ice() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        // Row 23 heading left; col 12 is a junction with an opening upward
+        for col in 8..=13 {
+            gs.maze.cells[23][col] = CellType::Ice;
+        }
+        gs.pacman.next_direction = Direction::Up;
+
+        for _ in 0..40 {
+            gs.tick(0.016);
+        }
+
+        let (_, row) = gs.pacman.position.to_grid();
+        assert_eq!(row, 23, "Pac-Man should not turn up while on ice");
+        assert_eq!(gs.pacman.direction, Direction::Left);
+    }
+
+    #[test]
+    fn pacman_moves_slower_through_mud() {
+        let mut normal = classic();
+        normal.phase = GamePhase::Playing;
+        // Bare floor on both runs, so neither stalls to eat dots
+        for col in 8..=14 {
+            normal.maze.cells[23][col] = CellType::Empty;
+        }
+        let mut muddy = normal.clone();
+        for col in 8..=14 {
+            muddy.maze.cells[23][col] = CellType::Slow;
+        }
+
+        for _ in 0..10 {
+            normal.tick(0.016);
+            muddy.tick(0.016);
+        }
+
+        let normal_travel = 14.0 - normal.pacman.position.x;
+        let muddy_travel = 14.0 - muddy.pacman.position.x;
+        assert!((muddy_travel - normal_travel * 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn warp_teleports_pacman_then_cools_down() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.maze.cells[23][13] = CellType::Warp(1);
+        gs.maze.cells[29][20] = CellType::Warp(1);
+
+        for _ in 0..10 {
+            gs.tick(0.016);
+        }
+
+        let (_, row) = gs.pacman.position.to_grid();
+        assert_eq!(row, 29, "Pac-Man should arrive at the partner warp");
+        assert_eq!(gs.warp_cooldowns.len(), 1);
+        assert_eq!(gs.warp_cooldowns[0].id, 1);
+
+        // Walking back onto the warp while it cools down does nothing
+        gs.pacman.position = crate::entities::Position::new(20.0, 29.0);
+        gs.tick(0.016);
+        let (_, row) = gs.pacman.position.to_grid();
+        assert_eq!(row, 29);
+    }
+
+    #[test]
+    fn ghosts_warp_too_and_keep_their_heading() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        // Blinky heads left from above the house; a warp two tiles along
+        // takes him to the bottom corridor
+        let (col, row) = gs.ghosts[0].position.to_grid();
+        gs.maze.cells[row][col - 2] = CellType::Warp(4);
+        gs.maze.cells[29][20] = CellType::Warp(4);
+        gs.ghosts[0].direction = Direction::Left;
+
+        for _ in 0..30 {
+            gs.tick(0.016);
+            if gs.ghosts[0].position.to_grid().1 == 29 {
+                break;
+            }
+        }
+        assert_eq!(gs.ghosts[0].position.to_grid(), (20, 29));
+        assert_eq!(gs.ghosts[0].direction, Direction::Left);
+
+        // Standing on the far end doesn't send him back
+        gs.tick(0.016);
+        assert_eq!(gs.ghosts[0].position.to_grid().1, 29);
+    }
+
+    #[test]
+    fn wall_breaker_lets_pacman_smash_one_wall() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        // Row 20: thin the double wall at cols 13–14 and drop a power-up on the way
+        gs.maze.cells[20][14] = CellType::Dot;
+        gs.maze.cells[20][11] = CellType::WallBreaker;
+        gs.pacman.position = crate::entities::Position::new(10.0, 20.0);
+        gs.pacman.direction = Direction::Right;
+        gs.pacman.next_direction = Direction::Right;
+
+        let mut events = Vec::new();
+        for _ in 0..30 {
+            gs.tick(0.016);
+            events.extend(gs.events.iter().cloned());
+        }
+
+        assert_eq!(gs.maze.get_cell(20, 13), Some(CellType::Empty));
+        assert_eq!(gs.pacman.wall_breaks, 0);
+        assert!(gs.pacman.position.x > 13.0, "Pac-Man should pass the hole");
+        assert!(events.contains(&GameEvent::CellChanged {
+            row: 20,
+            col: 13,
+            cell: CellType::Empty,
+        }));
+    }
+
+    #[test]
+    fn timed_walls_toggle_on_their_period() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.maze.cells[1][1] = CellType::TimedWall { open: false };
+        gs.maze.timed_wall_period = 1.0;
+        let opened = GameEvent::CellChanged {
+            row: 1,
+            col: 1,
+            cell: CellType::TimedWall { open: true },
+        };
+
+        gs.tick(0.6);
+        assert!(!gs.events.contains(&opened));
+        gs.tick(0.6);
+        assert_eq!(
+            gs.maze.get_cell(1, 1),
+            Some(CellType::TimedWall { open: true })
+        );
+        assert!(gs.events.contains(&opened));
+    }
+
+    #[test]
+    fn timed_wall_does_not_close_on_pacman() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.maze.cells[23][14] = CellType::TimedWall { open: true };
+        gs.maze.timed_wall_period = 0.01;
+        gs.pacman.direction = Direction::Up; // Facing a wall: stays put
+        gs.pacman.next_direction = Direction::Up;
+
+        gs.tick(0.016);
+        assert_eq!(
+            gs.maze.get_cell(23, 14),
+            Some(CellType::TimedWall { open: true })
+        );
+    }
+
+    #[test]
+    fn switch_toggles_linked_gate_once_per_visit() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.maze.cells[1][1] = CellType::Gate { open: false };
+        gs.maze.cells[23][14] = CellType::Switch;
+        gs.maze.link_switch((23, 14), &[(1, 1)]);
+        gs.pacman.direction = Direction::Up; // Facing a wall: stays on the switch
+        gs.pacman.next_direction = Direction::Up;
+
+        gs.tick(0.016);
+        assert_eq!(gs.maze.get_cell(1, 1), Some(CellType::Gate { open: true }));
+        assert_eq!(gs.active_switch, Some((23, 14)));
+
+        // Still standing on it — no second toggle
+        gs.tick(0.016);
+        assert_eq!(gs.maze.get_cell(1, 1), Some(CellType::Gate { open: true }));
+    }
+
+    #[test]
+    fn collecting_a_key_opens_matching_doors() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.maze.cells[23][14] = CellType::Key(2);
+        gs.maze.cells[1][1] = CellType::LockedDoor(2);
+        gs.maze.cells[1][2] = CellType::LockedDoor(0);
+
+        gs.tick(0.016);
+
+        assert_eq!(gs.pacman.keys, vec![2]);
+        assert_eq!(gs.maze.get_cell(1, 1), Some(CellType::Empty));
+        assert_eq!(gs.maze.get_cell(1, 2), Some(CellType::LockedDoor(0)));
+        assert!(gs.events.contains(&GameEvent::DoorOpened {
+            row: 1,
+            col: 1,
+            key: 2
+        }));
+    }
+
+    #[test]
+    fn urgency_bonus_decays_and_is_banked_on_level_complete() {
+        let config = GameConfig {
+            urgency_bonus: 1000,
+            urgency_decay_per_second: 100.0,
+            ..GameConfig::default()
+        };
+        let mut gs = GameStateInner::with_config(GameMode::Classic, config);
+        gs.phase = GamePhase::Playing;
+        gs.pacman.direction = Direction::Up; // Parked against a wall
+        gs.pacman.next_direction = Direction::Up;
+        gs.maze.cells[23][14] = CellType::Empty;
+
+        gs.tick(1.0);
+        gs.tick(1.0);
+        // Sub-stepped, so allow for rounding
+        assert!((gs.urgency_bonus - 800.0).abs() < 1e-6);
+
+        // Last dot left is right under Pac-Man
+        gs.maze.cells[23][14] = CellType::Dot;
+        gs.dots_remaining = 1;
+        gs.tick(0.5);
+
+        // Banked the moment the dot goes, in the frame's first sub-step
+        assert_eq!(gs.score_breakdown.time_bonus, 798);
+        assert_eq!(gs.pacman.score, 808);
+        assert_eq!(gs.urgency_bonus, 0.0);
+    }
+
+    #[test]
+    fn extra_life_is_awarded_once() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.pacman.direction = Direction::Up; // Parked against a wall
+        gs.pacman.next_direction = Direction::Up;
+        gs.pacman.score = 9_995;
+        gs.tick(0.016); // Eats the dot under him: 10,005
+
+        assert_eq!(gs.pacman.lives, 4);
+        assert!(gs.events.contains(&GameEvent::ExtraLife { lives: 4 }));
+
+        gs.pacman.score = 25_000;
+        gs.tick(0.016);
+        assert_eq!(gs.pacman.lives, 4);
+    }
+
+    #[test]
+    fn extra_life_can_be_disabled() {
+        let config = GameConfig {
+            extra_life_score: 0,
+            ..GameConfig::default()
+        };
+        let mut gs = GameStateInner::with_config(GameMode::Classic, config);
+        gs.pacman.score = 50_000;
+        gs.tick(0.016);
+        assert_eq!(gs.pacman.lives, 3);
+    }
+
+    #[test]
+    fn urgency_mode_is_off_by_default() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.tick(0.016);
+        assert_eq!(gs.urgency_bonus, 0.0);
+        assert_eq!(gs.score_breakdown.time_bonus, 0);
+    }
+
+    #[test]
+    fn score_and_phase_changes_are_reported_once() {
+        let mut gs = classic();
+        gs.set_direction(Direction::Left); // Starts the countdown
+        gs.tick(READY_DURATION - 0.01);
+        gs.tick(0.016); // Ready → Playing, eats the dot under Pac-Man
+
+        assert!(gs.events.contains(&GameEvent::PhaseChanged {
+            from: GamePhase::Ready,
+            to: GamePhase::Playing,
+        }));
+        assert!(gs.events.contains(&GameEvent::ScoreChanged { score: 10 }));
+        assert!(gs.events.contains(&GameEvent::sound(SoundCue::Waka)));
+
+        gs.pacman.direction = Direction::Up; // Park against a wall
+        gs.pacman.next_direction = Direction::Up;
+        gs.tick(0.016);
+        assert!(!gs
+            .events
+            .iter()
+            .any(|e| matches!(e, GameEvent::PhaseChanged { .. })));
+    }
+
+    /// Classic game with Blinky parked on Pac-Man's tile.
+    fn about_to_be_caught() -> GameStateInner {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.ghosts[0].position = gs.pacman.position.clone();
+        gs.ghosts[0].mode = GhostMode::Chase;
+        gs
+    }
+
+    #[test]
+    fn death_plays_out_then_respawns() {
+        let mut gs = about_to_be_caught();
+        gs.tick(0.016);
+        assert_eq!(gs.phase, GamePhase::Dying);
+        assert_eq!(gs.pacman.lives, 2);
+
+        gs.tick(DYING_DURATION);
+        assert_eq!(gs.phase, GamePhase::Respawning);
+        assert_eq!(gs.ghosts[0].position.to_grid(), (14, 11));
+        assert_eq!(gs.phase_str(), "respawning");
+
+        gs.tick(RESPAWN_DURATION);
+        assert_eq!(gs.phase, GamePhase::Playing);
+    }
+
+    #[test]
+    fn last_death_ends_in_game_over() {
+        let mut gs = about_to_be_caught();
+        gs.pacman.lives = 1;
+        gs.tick(0.016);
+        assert_eq!(gs.phase, GamePhase::Dying);
+        gs.tick(DYING_DURATION);
+        assert_eq!(gs.phase, GamePhase::GameOver);
+    }
+
+    #[test]
+    fn intermission_follows_level_two() {
+        let mut gs = classic();
+        gs.level = 2;
+        gs.enter_timed_phase(GamePhase::LevelComplete, LEVEL_COMPLETE_DURATION);
+        gs.tick(LEVEL_COMPLETE_DURATION);
+        assert_eq!(gs.phase, GamePhase::Intermission(1));
+        assert_eq!(gs.phase_code(), 7);
+
+        gs.tick(INTERMISSION_DURATION);
+        assert_eq!(gs.level, 3);
+        assert_eq!(gs.phase, GamePhase::Ready);
+    }
+
+    #[test]
+    fn eaten_ghost_returns_through_the_door_and_leaves_again() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.pacman.position = Position::new(1.0, 29.0); // Out of the way
+        gs.ghosts[0].position = Position::new(18.0, 11.0);
+        gs.ghosts[0].direction = Direction::Left;
+        gs.ghosts[0].mode = GhostMode::Eaten;
+
+        let mut went_inside = false;
+        for _ in 0..300 {
+            gs.tick(1.0 / 60.0);
+            went_inside |= gs.ghosts[0].house == HouseState::LeavingHouse;
+            if went_inside && gs.ghosts[0].house == HouseState::Outside {
+                break;
+            }
+        }
+        assert!(went_inside, "eyes never reached the house");
+        assert_eq!(gs.ghosts[0].house, HouseState::Outside);
+        assert_ne!(gs.ghosts[0].mode, GhostMode::Eaten);
+        assert_eq!(gs.ghosts[0].position.to_grid(), (14, 11));
+    }
+
+    #[test]
+    fn eyes_find_their_way_home_from_the_far_corner() {
+        // Eyes in the bottom-left corner, as far from the house as it gets
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.pacman.position = Position::new(1.0, 29.0);
+        gs.ghosts[0].position = Position::new(3.0, 29.0);
+        gs.ghosts[0].direction = Direction::Right;
+        gs.ghosts[0].mode = GhostMode::Eaten;
+
+        let mut revived_after = None;
+        for frame in 0..600 {
+            gs.tick(1.0 / 60.0);
+            if gs.ghosts[0].house != HouseState::Outside {
+                revived_after = Some(frame);
+                break;
+            }
+        }
+        // 30-odd tiles at double speed: well under three seconds
+        assert!(
+            revived_after.is_some_and(|f| f < 180),
+            "{:?}",
+            revived_after
+        );
+    }
+
+    #[test]
+    fn ghosts_slow_down_in_tunnels() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.pacman.position = Position::new(1.0, 29.0);
+        gs.ghosts[0].position = Position::new(3.0, 14.0);
+        gs.ghosts[0].direction = Direction::Left;
+        gs.ghosts[0].mode = GhostMode::Scatter; // Same as the wave: no reversal
+
+        gs.tick(0.1);
+        let moved = 3.0 - gs.ghosts[0].position.x;
+        let tunnel_speed = SpeedTable::speed(SpeedTable::for_level(1).ghost_tunnel);
+        assert!((moved - tunnel_speed * 0.1).abs() < 1e-9, "moved {}", moved);
+    }
+
     #[test]
-    #[should_panic(expected = "Invalid game mode")]
-    fn wasm_wrapper_invalid_mode_panics() {
-        GameState::new("invalid");
+    fn frightened_ghosts_change_look() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.maze.cells[23][14] = CellType::PowerPellet;
+        gs.tick(0.016);
+
+        for ghost in &gs.ghosts {
+            assert_eq!(ghost.mode, GhostMode::Frightened);
+            assert_eq!(ghost.look, GhostLook::Frightened);
+        }
     }
 
     #[test]
@@ -804,4 +3364,428 @@ mod tests {
             gs.ghosts[0].position.x, gs.ghosts[0].position.y, gs.ghosts[0].direction
         );
     }
+
+    #[test]
+    fn eating_stalls_pacman_briefly() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.maze.cells[23][14] = CellType::PowerPellet;
+        gs.tick(0.016);
+        assert_eq!(gs.eat_stall, PELLET_STALL);
+
+        // Frozen for the stall, then moving again
+        let x = gs.pacman.position.x;
+        gs.tick(PELLET_STALL);
+        assert_eq!(gs.pacman.position.x, x);
+        gs.tick(0.016);
+        assert!(gs.pacman.position.x < x);
+    }
+
+    #[test]
+    fn pacman_cuts_corners_within_the_window() {
+        // Heading left along row 23, 0.2 tiles short of the junction at
+        // col 12 with Up buffered
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.pacman.position = Position::new(12.2, 23.0);
+        gs.pacman.next_direction = Direction::Up;
+        gs.tick(0.016);
+
+        assert_eq!(gs.pacman.direction, Direction::Up);
+        assert!(gs.pacman.position.y < 23.0);
+        assert!(gs.pacman.position.x > 12.0 && gs.pacman.position.x < 12.2);
+
+        // Without a window the turn waits for the center
+        let mut strict = classic();
+        strict.config.cornering_window = 0.0;
+        strict.phase = GamePhase::Playing;
+        strict.pacman.position = Position::new(12.2, 23.0);
+        strict.pacman.next_direction = Direction::Up;
+        strict.tick(0.016);
+        assert_eq!(strict.pacman.direction, Direction::Left);
+    }
+
+    #[test]
+    fn ghosts_cannot_turn_up_in_no_up_zones() {
+        // Blinky scattering toward the top-right corner reaches the junction
+        // at (12, 11) heading left: up would be closer, but it's barred
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.ghosts[0].position = Position::new(12.1, 11.0);
+        gs.ghosts[0].direction = Direction::Left;
+        gs.tick(0.016);
+        assert_eq!(gs.ghosts[0].mode, GhostMode::Scatter);
+        assert_ne!(gs.ghosts[0].direction, Direction::Up);
+
+        // Without the restriction it takes the shortcut
+        let mut free = classic();
+        free.maze.set_no_up(11, 12, false);
+        free.phase = GamePhase::Playing;
+        free.ghosts[0].position = Position::new(12.1, 11.0);
+        free.ghosts[0].direction = Direction::Left;
+        free.tick(0.016);
+        assert_eq!(free.ghosts[0].direction, Direction::Up);
+    }
+
+    #[test]
+    fn overflow_bug_shifts_targets_when_facing_up() {
+        let pac = Position::new(14.0, 23.0);
+        let blinky = Position::new(14.0, 23.0);
+        let meta = MazeMetadata::default();
+        let mut ghosts = Ghost::create_all();
+        for ghost in &mut ghosts {
+            ghost.mode = GhostMode::Chase;
+        }
+        let (pinky, inky) = (&ghosts[1], &ghosts[2]);
+
+        let target = |g: &Ghost, dir, bug| {
+            GameStateInner::get_ghost_target(g, &meta, &pac, dir, &blinky, 0, bug)
+        };
+        assert_eq!(target(pinky, Direction::Up, false), (14, 19));
+        assert_eq!(target(pinky, Direction::Up, true), (10, 19));
+        // Pivot (12, 21), doubled away from Blinky
+        assert_eq!(target(inky, Direction::Up, true), (10, 19));
+        // Other headings are unaffected
+        assert_eq!(target(pinky, Direction::Left, true), (10, 23));
+    }
+
+    #[test]
+    fn long_frames_are_substepped_so_ghosts_cannot_pass_through() {
+        // Closing at ~19 tiles/s from three tiles apart: one unsplit 0.2 s
+        // step would carry them past each other
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.pacman.position = Position::new(8.0, 23.0);
+        gs.pacman.direction = Direction::Right;
+        gs.pacman.next_direction = Direction::Right;
+        gs.ghosts[0].position = Position::new(11.0, 23.0);
+        gs.ghosts[0].direction = Direction::Left;
+        gs.global_timer = 10.0; // Chase wave, no reversal
+        for ghost in &mut gs.ghosts {
+            ghost.mode = GhostMode::Chase;
+        }
+        gs.tick(0.2);
+        assert_eq!(gs.phase, GamePhase::Dying);
+    }
+
+    #[test]
+    fn tile_collisions_catch_swaps() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        gs.config.collision = CollisionMode::Tile;
+        gs.pacman.position = Position::new(10.0, 23.0);
+        gs.ghosts[0].position = Position::new(11.0, 23.0);
+        gs.ghosts[0].mode = GhostMode::Chase;
+        let mut before: Vec<_> = gs.ghosts.iter().map(|g| g.position.to_grid()).collect();
+
+        // Not touching, no swap
+        gs.check_collisions((10, 23), &before);
+        assert_eq!(gs.phase, GamePhase::Playing);
+
+        // They traded tiles during the step
+        before[0] = (10, 23);
+        gs.check_collisions((11, 23), &before);
+        assert_eq!(gs.phase, GamePhase::Dying);
+    }
+
+    #[test]
+    fn first_input_starts_a_ready_countdown() {
+        let mut gs = classic();
+        gs.set_direction(Direction::Left);
+        assert_eq!(gs.phase, GamePhase::Ready);
+
+        gs.tick(0.5);
+        assert!(gs.events.contains(&GameEvent::Countdown { seconds: 2 }));
+        gs.tick(0.6);
+        assert!(gs.events.contains(&GameEvent::Countdown { seconds: 1 }));
+        // More input doesn't restart it
+        gs.set_direction(Direction::Right);
+        gs.tick(0.9);
+        assert_eq!(gs.phase, GamePhase::Playing);
+    }
+
+    #[test]
+    fn respawn_counts_down_too() {
+        let mut gs = classic();
+        gs.enter_timed_phase(GamePhase::Dying, DYING_DURATION);
+        gs.tick(DYING_DURATION);
+        assert_eq!(gs.phase, GamePhase::Respawning);
+
+        gs.tick(0.016);
+        assert!(gs.events.contains(&GameEvent::Countdown { seconds: 2 }));
+        gs.tick(RESPAWN_DURATION);
+        assert_eq!(gs.phase, GamePhase::Playing);
+    }
+
+    #[test]
+    fn pellets_blink_during_play() {
+        let mut gs = classic();
+        assert!(gs.pellets_visible);
+        gs.phase = GamePhase::Playing;
+        gs.tick(PELLET_BLINK_PERIOD + 0.01);
+        assert!(!gs.pellets_visible);
+        gs.tick(PELLET_BLINK_PERIOD);
+        assert!(gs.pellets_visible);
+    }
+
+    #[test]
+    fn pause_and_resume_only_touch_live_play() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Playing;
+        assert!(gs.pause());
+        assert_eq!(gs.pause_reason(), Some(PauseReason::User));
+        let x = gs.pacman.position.x;
+        gs.tick(0.5);
+        assert_eq!(gs.pacman.position.x, x);
+        assert!(gs.resume());
+        assert_eq!(gs.pause_reason(), None);
+
+        // Can't resume out of the death animation
+        gs.enter_timed_phase(GamePhase::Dying, DYING_DURATION);
+        assert!(!gs.pause());
+        assert!(!gs.resume());
+        assert_eq!(gs.phase, GamePhase::Dying);
+        assert_eq!(gs.pause_reason(), Some(PauseReason::Death));
+    }
+
+    #[test]
+    fn reset_starts_over_in_place() {
+        let mut gs = about_to_be_caught();
+        gs.pacman.score = 4200;
+        gs.level = 3;
+        gs.maze.cells[23][14] = CellType::Empty;
+        gs.tick(0.016);
+        assert_eq!(gs.phase, GamePhase::Dying);
+
+        let cells = gs.maze.cells.as_ptr();
+        gs.reset();
+        assert_eq!(gs.maze.cells.as_ptr(), cells);
+        assert_eq!(gs.phase, GamePhase::Ready);
+        assert_eq!((gs.pacman.score, gs.pacman.lives, gs.level), (0, 3, 1));
+        assert_eq!(gs.maze.get_cell(23, 14), Some(CellType::Dot));
+        assert_eq!(gs.dots_remaining, classic().dots_remaining);
+
+        gs.tick(0.016);
+        assert!(gs.events.contains(&GameEvent::ScoreChanged { score: 0 }));
+    }
+
+    #[test]
+    fn entity_arrays_line_up_with_entities() {
+        let mut gs = classic();
+        gs.ghosts[1].mode = GhostMode::Frightened;
+        gs.ghosts[1].direction = Direction::Left;
+
+        let positions = gs.entity_positions();
+        assert_eq!(positions.len(), 2 + 2 * gs.ghosts.len());
+        assert_eq!(positions[0], gs.pacman.position.x);
+        assert_eq!(positions[5], gs.ghosts[1].position.y);
+
+        let codes = gs.entity_codes();
+        assert_eq!(codes.len(), 1 + 2 * gs.ghosts.len());
+        assert_eq!(&codes[3..5], &[2, 2]);
+    }
+
+    #[test]
+    fn frame_snapshot_leaves_out_the_maze() {
+        let gs = classic();
+        let frame = serde_json::to_value(gs.frame()).unwrap();
+        assert!(frame.get("maze").is_none());
+        assert!(frame.get("visibility").is_none());
+        assert_eq!(frame["level"], 1);
+        assert_eq!(frame["ghosts"].as_array().unwrap().len(), gs.ghosts.len());
+    }
+
+    #[test]
+    fn numeric_enums_match_the_codes() {
+        let mut gs = classic();
+        gs.phase = GamePhase::Intermission(2);
+        assert_eq!(gs.phase.id(), Phase::Intermission);
+        assert_eq!(gs.phase_code(), Phase::Intermission as u8);
+        assert_eq!(pvp().mode_code(), GameMode::PvP as u8);
+        for dir in [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ] {
+            assert_eq!(dir as u8, dir.code());
+            assert_eq!(Direction::from_code(dir.code()), Some(dir));
+        }
+        assert_eq!(Direction::from_code(4), None);
+    }
+
+    #[test]
+    fn frightened_getters_follow_the_power_pellet() {
+        let mut gs = classic();
+        assert_eq!(gs.frightened_remaining_ms(), 0.0);
+        assert!(!gs.is_any_ghost_frightened());
+
+        gs.phase = GamePhase::Playing;
+        gs.frightened_timer = 2.0;
+        gs.ghosts[1].mode = GhostMode::Frightened;
+        assert_eq!(gs.frightened_remaining_ms(), 2000.0);
+        assert!(gs.is_any_ghost_frightened());
+
+        gs.ghosts[1].mode = GhostMode::Eaten;
+        assert!(!gs.is_any_ghost_frightened());
+    }
+
+    #[test]
+    fn ghost_targets_match_the_steering_targets() {
+        let mut gs = classic();
+        for ghost in &mut gs.ghosts {
+            ghost.house = HouseState::Outside;
+            ghost.mode = GhostMode::Chase;
+        }
+        gs.pacman.position = Position::new(14.0, 23.0);
+        gs.pacman.direction = Direction::Left;
+        gs.ghosts[3].mode = GhostMode::Frightened;
+
+        let targets = gs.ghost_targets();
+        assert_eq!(targets[0], Some((14, 23)));
+        assert_eq!(targets[1], Some((10, 23)));
+        assert_eq!(targets[3], None);
+
+        gs.ghosts[1].house = HouseState::InHouse;
+        assert_eq!(gs.ghost_targets()[1], None);
+    }
+
+    #[test]
+    fn stale_buffered_turns_expire() {
+        let mut gs = classic();
+        gs.set_direction(Direction::Up);
+        gs.expire_inputs(0.3);
+        assert_eq!(gs.pacman.next_direction, Direction::Up);
+        gs.expire_inputs(0.3);
+        assert_eq!(gs.pacman.next_direction, Direction::Left);
+
+        // A window of 0 keeps the turn until it's taken
+        gs.config.input_window = 0.0;
+        gs.set_direction(Direction::Up);
+        gs.expire_inputs(5.0);
+        assert_eq!(gs.pacman.next_direction, Direction::Up);
+    }
+
+    #[test]
+    fn queued_turns_are_taken_in_order() {
+        let mut gs = classic();
+        gs.set_direction(Direction::Up);
+        gs.set_direction(Direction::Right);
+        gs.set_direction(Direction::Right);
+        assert_eq!(gs.pacman.next_direction, Direction::Up);
+        assert_eq!(gs.pacman.queued_turns, vec![(Direction::Right, 0.0)]);
+
+        // Up is taken, so Right is next, keeping the age it has
+        gs.expire_inputs(0.3);
+        gs.pacman.direction = Direction::Up;
+        gs.expire_inputs(0.1);
+        assert_eq!(gs.pacman.next_direction, Direction::Right);
+        assert!((gs.pacman.input_age - 0.4).abs() < 1e-9);
+        gs.expire_inputs(0.2);
+        assert_eq!(gs.pacman.next_direction, Direction::Up);
+        assert!(gs.pacman.queued_turns.is_empty());
+
+        // A full queue drops its oldest turn
+        let presses = [
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+            Direction::Left,
+        ];
+        for direction in presses {
+            gs.set_direction(direction);
+        }
+        assert_eq!(gs.pacman.next_direction, Direction::Down);
+        assert_eq!(
+            gs.pacman.queued_turns,
+            vec![(Direction::Right, 0.0), (Direction::Left, 0.0)]
+        );
+    }
+
+    #[test]
+    fn time_scale_stretches_every_tick() {
+        let mut slow = classic();
+        let mut normal = classic();
+        slow.phase = GamePhase::Playing;
+        normal.phase = GamePhase::Playing;
+        slow.set_time_scale(0.5);
+        for _ in 0..20 {
+            slow.tick(1.0 / 60.0);
+        }
+        for _ in 0..10 {
+            normal.tick(1.0 / 60.0);
+        }
+        assert!((slow.global_timer - normal.global_timer).abs() < 1e-9);
+        assert!((slow.pacman.position.x - normal.pacman.position.x).abs() < 1e-9);
+
+        slow.set_time_scale(f64::NAN);
+        assert_eq!(slow.time_scale, 0.5);
+        slow.set_time_scale(100.0);
+        assert_eq!(slow.time_scale, MAX_TIME_SCALE);
+    }
+
+    #[test]
+    fn bad_and_huge_frames_are_bounded() {
+        let mut state = classic();
+        state.phase = GamePhase::Playing;
+        for dt in [f64::NAN, f64::INFINITY, -1.0] {
+            assert_eq!(state.tick(dt), 0);
+        }
+        assert_eq!(state.global_timer, 0.0);
+
+        // An hour in the background plays as one capped frame
+        state.tick(3600.0);
+        assert!((state.global_timer - MAX_FRAME).abs() < 1e-9);
+        assert_eq!(state.phase, GamePhase::Playing);
+    }
+
+    #[test]
+    fn tick_reports_what_changed() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        assert_eq!(state.tick(1.0 / 60.0), 0);
+
+        // Start playing: Pac-Man moves onto the next dot
+        state.phase = GamePhase::Playing;
+        let mut changes = 0;
+        for _ in 0..10 {
+            changes |= state.tick(1.0 / 60.0);
+        }
+        let all = Change::Score as u8
+            | Change::Maze as u8
+            | Change::Phase as u8
+            | Change::Entities as u8
+            | Change::Events as u8;
+        assert_eq!(changes, all);
+
+        // Paused, once the pause itself has been reported
+        state.pause();
+        state.tick(1.0 / 60.0);
+        state.drain_events();
+        assert_eq!(state.tick(1.0 / 60.0), 0);
+    }
+
+    #[test]
+    fn pvp_player_drives_the_first_ghost_of_any_roster() {
+        let config = GameConfig {
+            ghost_count: 6,
+            ..GameConfig::default()
+        };
+        let state = GameStateInner::with_config(GameMode::Classic, config);
+        assert_eq!(state.ghosts.len(), 6);
+        assert_eq!(state.ghosts[4].ghost_type, GhostType::Blinky);
+
+        let config = GameConfig {
+            ghost_roster: vec![GhostType::Pinky, GhostType::Blinky],
+            ..GameConfig::default()
+        };
+        let mut pvp = GameStateInner::with_config(GameMode::PvP, config.clone());
+        pvp.set_player2_direction(Direction::Right);
+        assert_eq!(pvp.player_ghost(), Some(0));
+        assert_eq!(pvp.ghosts[0].next_direction, Direction::Right);
+        assert_eq!(pvp.ghost_targets()[0], None);
+
+        let classic = GameStateInner::with_config(GameMode::Classic, config);
+        assert_eq!(classic.player_ghost(), None);
+        assert!(classic.ghost_targets()[0].is_some());
+    }
 }
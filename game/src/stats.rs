@@ -0,0 +1,129 @@
+// game/src/stats.rs
+//
+// Per-game statistics for a stats screen.
+//
+// `GameStateInner::stats` tallies what happened over the game — dots,
+// power pellets, ghosts by personality, deaths, how far Pac-Man ran, and
+// the time spent on each level — so `get_stats()` can hand JS a finished
+// object instead of JS counting events itself.
+//
+// The eating and dying are counted from each tick's events; distance and
+// time are added up in the step of play, where Pac-Man moves. Everything
+// starts over with `restart()`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::entities::Position;
+use crate::events::GameEvent;
+use crate::state::GameStateInner;
+
+// ─── Stats ──────────────────────────────────────────────────────────────────
+
+/// Tallies for the game so far.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Stats {
+    pub dots_eaten: u32,
+    pub pellets_eaten: u32,
+    /// Ghosts eaten, by `GhostType::code`.
+    pub ghosts_eaten: [u32; 4],
+    pub deaths: u32,
+    /// Tiles Pac-Man has run, warps and tunnel wraps not included.
+    pub distance: f64,
+    /// Seconds of play on each level, level 1 first.
+    pub level_times: Vec<f64>,
+}
+
+impl Stats {
+    /// Count one tick's `events`.
+    fn record(&mut self, events: &[GameEvent]) {
+        for event in events {
+            match event {
+                GameEvent::DotEaten { .. } => self.dots_eaten += 1,
+                GameEvent::PowerPelletEaten { .. } => self.pellets_eaten += 1,
+                GameEvent::GhostEaten { ghost, .. } => {
+                    self.ghosts_eaten[ghost.code() as usize] += 1;
+                }
+                GameEvent::LifeLost { .. } => self.deaths += 1,
+                _ => {}
+            }
+        }
+    }
+
+    /// Add Pac-Man's move from `from` to `to`. A jump of more than a tile
+    /// is a warp or a wrap, not running.
+    pub(crate) fn add_distance(&mut self, from: &Position, to: &Position) {
+        let (dx, dy) = (to.x - from.x, to.y - from.y);
+        let step = (dx * dx + dy * dy).sqrt();
+        if step <= 1.0 {
+            self.distance += step;
+        }
+    }
+
+    /// Add `dt` seconds of play on `level`.
+    pub(crate) fn add_time(&mut self, level: u32, dt: f64) {
+        let index = level.max(1) as usize - 1;
+        if self.level_times.len() <= index {
+            self.level_times.resize(index + 1, 0.0);
+        }
+        self.level_times[index] += dt;
+    }
+
+    /// Ghosts eaten of every personality together.
+    pub fn total_ghosts_eaten(&self) -> u32 {
+        self.ghosts_eaten.iter().sum()
+    }
+}
+
+impl GameStateInner {
+    /// Count this tick's events into `stats`.
+    pub(crate) fn update_stats(&mut self) {
+        self.stats.record(&self.events);
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{Direction, GhostMode, GhostType};
+    use crate::scenario::ScenarioBuilder;
+    use crate::state::{GameMode, GamePhase};
+
+    #[test]
+    fn a_game_is_tallied() {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.phase = GamePhase::Playing;
+        for _ in 0..60 {
+            state.tick(1.0 / 60.0);
+        }
+        let stats = &state.stats;
+        assert_eq!(stats.dots_eaten, state.score_breakdown.dots / 10);
+        assert!(stats.dots_eaten > 0);
+        assert!((stats.level_times[0] - 1.0).abs() < 1e-6);
+        // Pac-Man ran left the whole second
+        assert!((stats.distance - (14.0 - state.pacman.position.x)).abs() < 1e-6);
+
+        state.reset();
+        assert_eq!(state.stats, Stats::default());
+    }
+
+    #[test]
+    fn ghosts_and_deaths_are_counted() {
+        let mut state = ScenarioBuilder::new(GameMode::Classic)
+            .pacman_at(5, 6, Direction::Right)
+            .ghost(GhostType::Inky, 5, 6, GhostMode::Frightened)
+            .ghost(GhostType::Blinky, 5, 21, GhostMode::Chase)
+            .phase(GamePhase::Playing)
+            .frightened_timer(5.0)
+            .build();
+        state.tick(0.016);
+        assert_eq!(state.stats.ghosts_eaten, [0, 0, 1, 0]);
+        assert_eq!(state.stats.total_ghosts_eaten(), 1);
+
+        state.ghosts[0].position = state.pacman.position.clone();
+        state.tick(0.016);
+        assert_eq!(state.stats.deaths, 1);
+    }
+}
@@ -0,0 +1,170 @@
+// game/src/survival.rs
+//
+// Survival: an endless game on one board.
+//
+// There are no levels. Eaten dots and power pellets grow back a few at a
+// time, every `REGEN_INTERVAL` seconds, and a cleared board refills at
+// once. The ghosts speed up the longer Pac-Man lasts, by `RAMP_PER_MINUTE`
+// a minute up to `MAX_GHOST_RAMP`. The game ends, as ever, with the last
+// life; what counts is the score and how long he survived.
+//
+// `GameStateInner::survival` holds the clock (`Some` in Survival mode
+// only), and `update_survival` is the mode's own step, run with the rest of
+// a frame of play.
+
+use serde::{Deserialize, Serialize};
+
+use crate::maze::CellType;
+use crate::state::{GameMode, GameStateInner};
+
+// ─── Survival ───────────────────────────────────────────────────────────────
+
+/// Seconds between regrowths.
+pub const REGEN_INTERVAL: f64 = 8.0;
+
+/// Dots (or power pellets) grown back at each regrowth.
+pub const REGEN_COUNT: usize = 6;
+
+/// Extra ghost speed per minute survived (0.05 is 5% faster).
+pub const RAMP_PER_MINUTE: f64 = 0.05;
+
+/// Cap on the extra ghost speed.
+pub const MAX_GHOST_RAMP: f64 = 0.5;
+
+/// A survival run's clocks.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Survival {
+    /// Seconds of play survived so far.
+    pub elapsed: f64,
+    /// Seconds until the next regrowth.
+    pub regen_timer: f64,
+}
+
+impl Survival {
+    pub fn new() -> Survival {
+        Survival {
+            elapsed: 0.0,
+            regen_timer: REGEN_INTERVAL,
+        }
+    }
+
+    /// Factor on ghost speeds after `elapsed` seconds.
+    pub fn ghost_speed(&self) -> f64 {
+        1.0 + (self.elapsed / 60.0 * RAMP_PER_MINUTE).min(MAX_GHOST_RAMP)
+    }
+}
+
+impl GameStateInner {
+    /// Factor on ghost speeds for the current mode (1 outside Survival).
+    pub(crate) fn survival_ghost_speed(&self) -> f64 {
+        self.survival.as_ref().map_or(1.0, Survival::ghost_speed)
+    }
+
+    /// Survival's part of a step of play: run the clocks and regrow dots.
+    pub(crate) fn update_survival(&mut self, dt: f64) {
+        if self.mode != GameMode::Survival {
+            return;
+        }
+        let Some(survival) = self.survival.as_mut() else {
+            return;
+        };
+        survival.elapsed += dt;
+        survival.regen_timer -= dt;
+        let due = survival.regen_timer <= 0.0;
+        if due {
+            survival.regen_timer += REGEN_INTERVAL;
+        }
+        if self.dots_remaining == 0 {
+            self.regrow(usize::MAX);
+        } else if due {
+            self.regrow(REGEN_COUNT);
+        }
+    }
+
+    /// Put back up to `count` eaten dots and power pellets, picked at
+    /// random, where the board started with them. Pac-Man's own tile is
+    /// left alone.
+    fn regrow(&mut self, count: usize) {
+        let pac = self.pacman.position.to_grid();
+        let mut eaten: Vec<(usize, usize, CellType)> = Vec::new();
+        for (row, cells) in self.level_maze.cells.iter().enumerate() {
+            for (col, &cell) in cells.iter().enumerate() {
+                let was_food = matches!(cell, CellType::Dot | CellType::PowerPellet);
+                if was_food
+                    && (col, row) != pac
+                    && self.maze.get_cell(row, col) == Some(CellType::Empty)
+                {
+                    eaten.push((row, col, cell));
+                }
+            }
+        }
+        for _ in 0..count.min(eaten.len()) {
+            let i = self.rng.below(eaten.len() as u32) as usize;
+            let (row, col, cell) = eaten.swap_remove(i);
+            self.set_cell(row, col, cell);
+            self.dots_remaining += 1;
+        }
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::GamePhase;
+
+    /// Eat every dot and pellet in rows `rows`, without scoring.
+    fn clear_rows(state: &mut GameStateInner, rows: std::ops::Range<usize>) -> usize {
+        let mut cleared = 0;
+        for row in rows {
+            for col in 0..state.maze.width {
+                if matches!(
+                    state.maze.get_cell(row, col),
+                    Some(CellType::Dot | CellType::PowerPellet)
+                ) {
+                    state.set_cell(row, col, CellType::Empty);
+                    cleared += 1;
+                }
+            }
+        }
+        state.dots_remaining -= cleared;
+        cleared
+    }
+
+    #[test]
+    fn dots_grow_back_and_the_board_never_clears() {
+        let mut state = GameStateInner::new(GameMode::Survival);
+        state.phase = GamePhase::Playing;
+        let full = state.dots_remaining;
+        let cleared = clear_rows(&mut state, 0..10);
+
+        state.survival.as_mut().unwrap().regen_timer = 0.01;
+        state.tick(1.0 / 60.0);
+        let eaten = (state.pacman.score / 10) as usize;
+        assert_eq!(full - state.dots_remaining, cleared - REGEN_COUNT + eaten);
+        assert_eq!(state.maze.dots_remaining(), state.dots_remaining);
+
+        // Eating the last dot refills the board instead of ending a level
+        let height = state.maze.height;
+        clear_rows(&mut state, 0..height);
+        state.tick(1.0 / 60.0);
+        assert_eq!(state.phase, GamePhase::Playing);
+        assert_eq!(state.level, 1);
+        assert!(state.dots_remaining > full - 2);
+    }
+
+    #[test]
+    fn ghosts_speed_up_over_time() {
+        let mut survival = Survival::new();
+        assert_eq!(survival.ghost_speed(), 1.0);
+        survival.elapsed = 120.0;
+        assert!((survival.ghost_speed() - 1.1).abs() < 1e-9);
+        survival.elapsed = 3600.0;
+        assert_eq!(survival.ghost_speed(), 1.0 + MAX_GHOST_RAMP);
+
+        let classic = GameStateInner::new(GameMode::Classic);
+        assert!(classic.survival.is_none());
+        assert_eq!(classic.survival_ghost_speed(), 1.0);
+    }
+}
@@ -0,0 +1,182 @@
+// game/src/trace.rs
+//
+// Golden traces for regression testing.
+//
+// A trace runs a game from a fixed seed with a scripted list of inputs
+// and records one canonical text line per frame (positions, modes,
+// score). Record a trace once, commit it, and any later refactor can be
+// checked bit-for-bit by re-running the same script and comparing.
+
+use std::fmt::Write;
+
+use crate::config::GameConfig;
+use crate::entities::Direction;
+use crate::state::{GameMode, GameStateInner};
+
+// ─── Input scripts ──────────────────────────────────────────────────────────
+
+/// Which player an input belongs to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Player {
+    /// Pac-Man
+    One,
+    /// The PvP ghost
+    Two,
+}
+
+/// A direction press applied just before the given frame is simulated.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScriptedInput {
+    pub frame: u32,
+    pub player: Player,
+    pub direction: Direction,
+}
+
+/// Everything needed to reproduce a run exactly.
+#[derive(Clone, Debug)]
+pub struct TraceScript {
+    pub mode: GameMode,
+    pub seed: u32,
+    pub frames: u32,
+    /// Fixed timestep per frame, in seconds.
+    pub frame_dt: f64,
+    /// Inputs, in any order; several may share a frame.
+    pub inputs: Vec<ScriptedInput>,
+}
+
+// ─── Trace output ───────────────────────────────────────────────────────────
+
+impl GameStateInner {
+    /// One canonical trace line describing the current frame.
+    ///
+    /// Floats use `{:?}`, Rust's shortest representation that round-trips
+    /// exactly, so two lines are equal only if the states are bit-equal.
+    pub fn trace_line(&self, frame: u32) -> String {
+        let mut line = String::new();
+        let pac = &self.pacman;
+        let _ = write!(
+            line,
+            "{} {} pac {:?} {:?} {:?}",
+            frame,
+            self.phase_str(),
+            pac.position.x,
+            pac.position.y,
+            pac.direction,
+        );
+        for ghost in &self.ghosts {
+            let _ = write!(
+                line,
+                " | {:?} {:?} {:?} {:?} {:?}",
+                ghost.ghost_type, ghost.position.x, ghost.position.y, ghost.direction, ghost.mode,
+            );
+        }
+        let _ = write!(
+            line,
+            " | score {} lives {} dots {}",
+            pac.score, pac.lives, self.dots_remaining
+        );
+        line
+    }
+}
+
+/// Run `script` and return the full trace, one line per frame.
+///
+/// Line 0 is the initial state; line `n` is the state after frame `n`.
+/// Write the string to a file to record a golden trace.
+pub fn golden_trace(script: &TraceScript) -> String {
+    let config = GameConfig {
+        seed: script.seed,
+        ..GameConfig::default()
+    };
+    let mut state = GameStateInner::with_config(script.mode, config);
+
+    let mut trace = state.trace_line(0);
+    trace.push('\n');
+
+    for frame in 1..=script.frames {
+        for input in script.inputs.iter().filter(|i| i.frame == frame) {
+            match input.player {
+                Player::One => state.set_direction(input.direction),
+                Player::Two => state.set_player2_direction(input.direction),
+            }
+        }
+        state.tick(script.frame_dt);
+        trace.push_str(&state.trace_line(frame));
+        trace.push('\n');
+    }
+    trace
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn script(seed: u32) -> TraceScript {
+        TraceScript {
+            mode: GameMode::Classic,
+            seed,
+            frames: 240,
+            frame_dt: 1.0 / 60.0,
+            inputs: vec![
+                ScriptedInput {
+                    frame: 1,
+                    player: Player::One,
+                    direction: Direction::Left,
+                },
+                ScriptedInput {
+                    frame: 30,
+                    player: Player::One,
+                    direction: Direction::Up,
+                },
+                ScriptedInput {
+                    frame: 90,
+                    player: Player::One,
+                    direction: Direction::Right,
+                },
+            ],
+        }
+    }
+
+    /// The recorded run of `script(7)`. After an intended change to the
+    /// simulation, re-record it with `UPDATE_GOLDEN=1 cargo test`.
+    const GOLDEN: &str = "testdata/classic_seed7.trace";
+
+    #[test]
+    fn trace_matches_the_recorded_golden() {
+        let trace = golden_trace(&script(7));
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(GOLDEN);
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            std::fs::write(&path, &trace).unwrap();
+        }
+        let golden = std::fs::read_to_string(&path).unwrap();
+        for (frame, (got, want)) in trace.lines().zip(golden.lines()).enumerate() {
+            assert_eq!(
+                got, want,
+                "trace differs from {} at frame {}",
+                GOLDEN, frame
+            );
+        }
+        assert_eq!(trace.lines().count(), golden.lines().count());
+    }
+
+    #[test]
+    fn trace_is_reproducible() {
+        assert_eq!(golden_trace(&script(7)), golden_trace(&script(7)));
+    }
+
+    #[test]
+    fn trace_has_one_line_per_frame_plus_initial() {
+        let trace = golden_trace(&script(7));
+        assert_eq!(trace.lines().count(), 241);
+        assert!(trace.starts_with("0 ready pac 14.0 23.0 Left | Blinky 14.0 11.0"));
+    }
+
+    #[test]
+    fn inputs_change_the_trace() {
+        let mut idle = script(7);
+        idle.inputs.truncate(1);
+        assert_ne!(golden_trace(&idle), golden_trace(&script(7)));
+    }
+}
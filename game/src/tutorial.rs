@@ -0,0 +1,276 @@
+// game/src/tutorial.rs
+//
+// Tutorial: a guided first game.
+//
+// `GameState.tutorial()` starts a Classic game on a plain board
+// (`TUTORIAL_MAZE`) against Blinky alone, and takes the player through
+// three objectives in order:
+//
+// 1. eat `TUTORIAL_DOTS` dots,
+// 2. eat a power pellet,
+// 3. eat a frightened ghost.
+//
+// Progress is read off each tick's events, so the rules need no hooks for
+// it. `ObjectiveStarted` announces each new objective (the first one is in
+// the snapshot from the start), ending with `Objective::Complete`; after
+// that the game simply carries on. `HintShown` fires the first time a
+// situation worth explaining comes up — a power pellet within reach, a
+// ghost closing in, the ghosts turning blue — and the renderer shows the
+// matching text.
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::GameConfig;
+use crate::entities::{GhostMode, HouseState};
+use crate::events::GameEvent;
+use crate::maze::{CellType, Maze, MAZE_HEIGHT, MAZE_WIDTH};
+use crate::state::{GameMode, GamePhase, GameStateInner};
+
+// ─── Objectives ─────────────────────────────────────────────────────────────
+
+/// Dots to eat for the first objective.
+pub const TUTORIAL_DOTS: u32 = 10;
+
+/// How close (in tiles, along the grid) something must be to earn a hint.
+pub const HINT_RANGE: usize = 4;
+
+/// What the player is asked to do next.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Objective {
+    EatDots,
+    EatPowerPellet,
+    EatGhost,
+    /// Every objective is done.
+    Complete,
+}
+
+impl Objective {
+    /// The objective after this one.
+    pub fn next(self) -> Objective {
+        match self {
+            Objective::EatDots => Objective::EatPowerPellet,
+            Objective::EatPowerPellet => Objective::EatGhost,
+            Objective::EatGhost | Objective::Complete => Objective::Complete,
+        }
+    }
+}
+
+/// A situation the tutorial explains when it first comes up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Hint {
+    /// A power pellet is within `HINT_RANGE` while one is wanted.
+    PelletNearby,
+    /// A ghost that can catch Pac-Man is within `HINT_RANGE`.
+    GhostNearby,
+    /// The ghosts turned blue while one needs eating.
+    GhostsFrightened,
+}
+
+/// How far the player has got.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Tutorial {
+    pub objective: Objective,
+    /// Dots eaten toward `Objective::EatDots`.
+    pub dots: u32,
+    /// Hints already shown; each is shown once.
+    pub hints: Vec<Hint>,
+}
+
+impl Tutorial {
+    pub fn new() -> Tutorial {
+        Tutorial {
+            objective: Objective::EatDots,
+            dots: 0,
+            hints: Vec::new(),
+        }
+    }
+
+    /// Count one tick's `events` toward the objective. Returns whether it
+    /// was met.
+    fn progress(&mut self, events: &[GameEvent]) -> bool {
+        let mut met = false;
+        for event in events {
+            met |= match (self.objective, event) {
+                (Objective::EatDots, GameEvent::DotEaten { .. }) => {
+                    self.dots = (self.dots + 1).min(TUTORIAL_DOTS);
+                    self.dots == TUTORIAL_DOTS
+                }
+                (Objective::EatPowerPellet, GameEvent::PowerPelletEaten { .. }) => true,
+                (Objective::EatGhost, GameEvent::GhostEaten { .. }) => true,
+                _ => false,
+            };
+        }
+        met
+    }
+}
+
+impl Default for Tutorial {
+    fn default() -> Self {
+        Tutorial::new()
+    }
+}
+
+// ─── Board ──────────────────────────────────────────────────────────────────
+
+/// Long straight corridors around the classic middle, so the classic
+/// `MazeMetadata` holds. Same legend as `CellType::from_char`.
+const TUTORIAL_MAZE: [&str; MAZE_HEIGHT] = [
+    "WWWWWWWWWWWWWWWWWWWWWWWWWWWW", // 0
+    "W............WW............W", // 1
+    "W.WWWWWWWWWW.WW.WWWWWWWWWW.W", // 2
+    "WoWWWWWWWWWW.WW.WWWWWWWWWWoW", // 3
+    "W.WWWWWWWWWW.WW.WWWWWWWWWW.W", // 4
+    "W..........................W", // 5
+    "W.WWWW.WWWWWWWWWWWWWW.WWWW.W", // 6
+    "W.WWWW.WWWWWWWWWWWWWW.WWWW.W", // 7
+    "W............WW............W", // 8
+    "WWWWWW.WWWWW.WW.WWWWW.WWWWWW", // 9
+    "EEEEWW.WWWWW.WW.WWWWW.WWEEEE", // 10
+    "EEEEWW.WW..........WW.WWEEEE", // 11
+    "EEEEWW.WW.WWW--WWW.WW.WWEEEE", // 12 ← house door
+    "WWWWWW.WW.WEGGGGEW.WW.WWWWWW", // 13
+    "EEEEEE....WEGGGGEW....EEEEEE", // 14  ← tunnel row
+    "WWWWWW.WW.WEGGGGEW.WW.WWWWWW", // 15
+    "EEEEWW.WW.WWWWWWWW.WW.WWEEEE", // 16
+    "EEEEWW.WW..........WW.WWEEEE", // 17
+    "EEEEWW.WW.WWWWWWWW.WW.WWEEEE", // 18
+    "WWWWWW.WW.WWWWWWWW.WW.WWWWWW", // 19
+    "W............WW............W", // 20
+    "W.WWWWWWWWWW.WW.WWWWWWWWWW.W", // 21
+    "W.WWWWWWWWWW.WW.WWWWWWWWWW.W", // 22
+    "Wo........................oW", // 23
+    "W.WWWWWWWWWW.WW.WWWWWWWWWW.W", // 24
+    "W.WWWWWWWWWW.WW.WWWWWWWWWW.W", // 25
+    "W.WWWWWWWWWW.WW.WWWWWWWWWW.W", // 26
+    "W.WWWWWWWWWW.WW.WWWWWWWWWW.W", // 27
+    "W.WWWWWWWWWW.WW.WWWWWWWWWW.W", // 28
+    "W..........................W", // 29
+    "WWWWWWWWWWWWWWWWWWWWWWWWWWWW", // 30
+];
+
+impl GameStateInner {
+    /// Start the tutorial.
+    pub fn tutorial() -> GameStateInner {
+        let config = GameConfig {
+            ghost_count: 1,
+            ..GameConfig::default()
+        };
+        let mut state = GameStateInner::with_config(GameMode::Classic, config);
+        state.load_maze(Maze::from_rows(&TUTORIAL_MAZE, MAZE_WIDTH));
+        state.tutorial = Some(Tutorial::new());
+        state
+    }
+
+    /// The tutorial's part of a tick: count progress from this tick's
+    /// events, move on when an objective is met, and give any hint due.
+    pub(crate) fn update_tutorial(&mut self) {
+        let Some(mut tutorial) = self.tutorial.take() else {
+            return;
+        };
+        if tutorial.progress(&self.events) {
+            tutorial.objective = tutorial.objective.next();
+            self.events.push(GameEvent::ObjectiveStarted {
+                objective: tutorial.objective,
+            });
+        }
+        if let Some(hint) = self.hint_for(tutorial.objective) {
+            if !tutorial.hints.contains(&hint) {
+                tutorial.hints.push(hint);
+                self.events.push(GameEvent::HintShown { hint });
+            }
+        }
+        self.tutorial = Some(tutorial);
+    }
+
+    /// The hint the board calls for right now, if any.
+    fn hint_for(&self, objective: Objective) -> Option<Hint> {
+        if self.phase != GamePhase::Playing {
+            return None;
+        }
+        let (col, row) = self.pacman.position.to_grid();
+        let near = |(c, r): (usize, usize)| c.abs_diff(col) + r.abs_diff(row) <= HINT_RANGE;
+        let frightened = self.ghosts.iter().any(|g| g.mode == GhostMode::Frightened);
+        let threat = self.ghosts.iter().any(|g| {
+            matches!(g.mode, GhostMode::Chase | GhostMode::Scatter)
+                && g.house == HouseState::Outside
+                && near(g.position.to_grid())
+        });
+        let pellet = self
+            .maze
+            .positions_of(CellType::PowerPellet)
+            .into_iter()
+            .any(|(r, c)| near((c, r)));
+        match objective {
+            Objective::EatGhost if frightened => Some(Hint::GhostsFrightened),
+            Objective::EatPowerPellet if pellet => Some(Hint::PelletNearby),
+            Objective::EatDots | Objective::EatPowerPellet if threat => Some(Hint::GhostNearby),
+            _ => None,
+        }
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::Position;
+
+    #[test]
+    fn objectives_follow_each_other() {
+        let mut state = GameStateInner::tutorial();
+        assert_eq!(state.ghosts.len(), 1);
+        assert_eq!(state.maze.positions_of(CellType::PowerPellet).len(), 4);
+        state.phase = GamePhase::Playing;
+
+        // Pac-Man starts on row 23 heading left, through nothing but dots
+        let mut started = Vec::new();
+        for _ in 0..600 {
+            state.tick(1.0 / 60.0);
+            started.extend(state.events.iter().filter_map(|e| match e {
+                GameEvent::ObjectiveStarted { objective } => Some(*objective),
+                _ => None,
+            }));
+            if !started.is_empty() {
+                break;
+            }
+        }
+        assert_eq!(started, vec![Objective::EatPowerPellet]);
+        assert_eq!(state.tutorial.as_ref().unwrap().dots, TUTORIAL_DOTS);
+
+        // Eating the pellet early doesn't count for the ghost objective
+        let tutorial = state.tutorial.as_mut().unwrap();
+        assert!(!tutorial.progress(&[GameEvent::DotEaten { row: 0, col: 0 }]));
+        assert!(tutorial.progress(&[GameEvent::PowerPelletEaten { row: 0, col: 0 }]));
+        tutorial.objective = tutorial.objective.next();
+        assert_eq!(tutorial.objective, Objective::EatGhost);
+
+        state.reset();
+        assert_eq!(state.tutorial, Some(Tutorial::new()));
+    }
+
+    #[test]
+    fn hints_come_once_when_the_situation_arises() {
+        let mut state = GameStateInner::tutorial();
+        state.phase = GamePhase::Playing;
+        state.tutorial.as_mut().unwrap().objective = Objective::EatPowerPellet;
+        // Next to the bottom-left power pellet at (23, 1)
+        state.pacman.position = Position::new(3.0, 23.0);
+        state.tick(1.0 / 60.0);
+        assert!(state.events.contains(&GameEvent::HintShown {
+            hint: Hint::PelletNearby
+        }));
+        state.tick(1.0 / 60.0);
+        assert!(!state
+            .events
+            .iter()
+            .any(|e| matches!(e, GameEvent::HintShown { .. })));
+        assert_eq!(
+            state.tutorial.as_ref().unwrap().hints,
+            vec![Hint::PelletNearby]
+        );
+
+        let plain = GameStateInner::new(GameMode::Classic);
+        assert!(plain.tutorial.is_none());
+    }
+}
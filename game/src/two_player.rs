@@ -0,0 +1,165 @@
+// game/src/two_player.rs
+//
+// Alternating two-player Classic, as on the arcade cabinet.
+//
+// Two players take turns, each with their own game: score, lives, level,
+// and the board as they left it. The player at the controls plays on the
+// state as usual; the other one's game waits in
+// `GameStateInner::waiting_player`. Each death hands over to the other
+// player, if they have lives left:
+//
+// ```text
+// Dying ─► swap boards ─► PlayerChanged ─► Respawning ("PLAYER TWO / READY!")
+// ```
+//
+// A player out of lives is skipped, and the game is over when both are.
+// `active_player` (1 or 2) says who is playing, and the snapshot carries
+// the waiting game too, so the frontend can show both scores.
+
+use serde::{Deserialize, Serialize};
+
+use crate::entities::PacMan;
+use crate::events::GameEvent;
+use crate::maze::Maze;
+use crate::state::{GameMode, GameStateInner, ScoreBreakdown};
+
+// ─── Player boards ──────────────────────────────────────────────────────────
+
+/// The parts of a game that belong to one player.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlayerBoard {
+    pub pacman: PacMan,
+    pub maze: Maze,
+    pub dots_remaining: usize,
+    pub level: u32,
+    pub dots_eaten: u32,
+    pub extra_life_awarded: bool,
+    pub score_breakdown: ScoreBreakdown,
+}
+
+impl PlayerBoard {
+    /// A new game for the second player, on `state`'s first board.
+    fn fresh(state: &GameStateInner) -> PlayerBoard {
+        let mut pacman = PacMan::new();
+        pacman.respawn(state.level_maze.metadata.pacman_spawn);
+        pacman.lives = state
+            .config
+            .starting_lives
+            .saturating_add(state.config.assist.extra_lives);
+        PlayerBoard {
+            pacman,
+            maze: state.level_maze.clone(),
+            dots_remaining: state.level_maze.dots_remaining(),
+            level: 1,
+            dots_eaten: 0,
+            extra_life_awarded: false,
+            score_breakdown: ScoreBreakdown::default(),
+        }
+    }
+}
+
+impl GameStateInner {
+    /// Start an alternating two-player Classic game; player 1 goes first.
+    pub fn two_player() -> GameStateInner {
+        let mut state = GameStateInner::new(GameMode::Classic);
+        state.waiting_player = Some(Box::new(PlayerBoard::fresh(&state)));
+        state
+    }
+
+    /// Give the waiting player a new game too, after `reset()`.
+    pub(crate) fn reset_players(&mut self) {
+        self.active_player = 1;
+        if self.waiting_player.is_some() {
+            self.waiting_player = Some(Box::new(PlayerBoard::fresh(self)));
+        }
+    }
+
+    /// Whether the waiting player, if any, can take over.
+    pub(crate) fn player_waiting(&self) -> bool {
+        self.waiting_player
+            .as_ref()
+            .is_some_and(|p| p.pacman.lives > 0)
+    }
+
+    /// Hand the controls to the waiting player, if they have lives left.
+    pub(crate) fn switch_players(&mut self) {
+        if !self.player_waiting() {
+            return;
+        }
+        let Some(other) = self.waiting_player.as_mut() else {
+            return;
+        };
+        std::mem::swap(&mut self.pacman, &mut other.pacman);
+        std::mem::swap(&mut self.maze, &mut other.maze);
+        std::mem::swap(&mut self.dots_remaining, &mut other.dots_remaining);
+        std::mem::swap(&mut self.level, &mut other.level);
+        std::mem::swap(&mut self.dots_eaten, &mut other.dots_eaten);
+        std::mem::swap(&mut self.extra_life_awarded, &mut other.extra_life_awarded);
+        std::mem::swap(&mut self.score_breakdown, &mut other.score_breakdown);
+        self.active_player = 3 - self.active_player;
+        // Scores are per player, so `ScoreChanged` follows whoever is up
+        self.last_score = self.pacman.score;
+        self.events.push(GameEvent::PlayerChanged {
+            player: self.active_player,
+        });
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::GamePhase;
+
+    fn die(state: &mut GameStateInner) {
+        state.phase = GamePhase::Playing;
+        state.ghosts[0].position = state.pacman.position.clone();
+        state.tick(1.0 / 60.0);
+        assert_eq!(state.phase, GamePhase::Dying);
+        state.phase_timer = 0.01;
+        state.tick(0.02);
+    }
+
+    #[test]
+    fn each_death_hands_over_to_the_other_player() {
+        let mut state = GameStateInner::two_player();
+        state.pacman.score = 700;
+        state.maze.set_cell(23, 1, crate::maze::CellType::Empty);
+        state.dots_remaining -= 1;
+        die(&mut state);
+
+        assert_eq!(state.active_player, 2);
+        assert_eq!(state.phase, GamePhase::Respawning);
+        assert!(state
+            .events
+            .contains(&GameEvent::PlayerChanged { player: 2 }));
+        assert_eq!((state.pacman.score, state.pacman.lives), (0, 3));
+        assert_eq!(state.dots_remaining, state.maze.dots_remaining());
+        let waiting = state.waiting_player.as_ref().unwrap();
+        let p1_score = waiting.pacman.score;
+        assert!(p1_score >= 700);
+        assert_eq!(waiting.pacman.lives, 2);
+
+        die(&mut state);
+        assert_eq!(state.active_player, 1);
+        assert_eq!(state.pacman.score, p1_score);
+        assert_eq!(state.dots_remaining, state.maze.dots_remaining());
+    }
+
+    #[test]
+    fn a_player_out_of_lives_is_skipped() {
+        let mut state = GameStateInner::two_player();
+        state.waiting_player.as_mut().unwrap().pacman.lives = 0;
+        die(&mut state);
+        assert_eq!(state.active_player, 1);
+        assert_eq!(state.phase, GamePhase::Respawning);
+
+        state.pacman.lives = 1;
+        die(&mut state);
+        assert_eq!(state.phase, GamePhase::GameOver);
+
+        state.reset();
+        assert_eq!(state.waiting_player.as_ref().unwrap().pacman.lives, 3);
+    }
+}
@@ -0,0 +1,725 @@
+// game/src/typings.rs
+//
+// TypeScript types for the objects that cross the WASM boundary.
+//
+// wasm-bindgen types the `GameState` methods, but anything built by serde
+// (`to_js()`, `to_js_frame()`, `drain_events()`, `with_options()`) would be
+// `any` in the generated `.d.ts`. The section below is appended to it, and
+// the extern types give those methods real return and parameter types.
+//
+// The types are written by hand to match serde's output: structs become
+// objects with the Rust field names, unit variants become strings, and
+// other variants become `{ Variant: payload }`. Names that wasm-bindgen
+// already uses for the numeric enums (`Direction`, `GameMode`, `Phase`)
+// get a `Name` / `Value` suffix here.
+//
+// Keep it in step with the Rust structs: the tests compare every interface
+// with what serde actually produces.
+
+use wasm_bindgen::prelude::*;
+
+// ─── TypeScript section ─────────────────────────────────────────────────────
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_SECTION: &str = TS_TYPES;
+
+const TS_TYPES: &str = r#"
+export type DirectionName = "Up" | "Down" | "Left" | "Right";
+export type MazeVariant = "Classic" | "OuterPellets" | "TunnelDots";
+
+export type GameModeName = "Classic" | "PvP" | "Coop" | "Survival";
+export type GamePhaseValue =
+  | "Ready" | "Playing" | "UserPaused" | "GameOver" | "Dying"
+  | "Respawning" | "LevelComplete" | { Intermission: number } | "Demo";
+export type GhostType = "Blinky" | "Pinky" | "Inky" | "Clyde";
+export type GhostMode = "Chase" | "Scatter" | "Frightened" | "Eaten";
+export type GhostPattern = "Solid" | "Dots" | "Stripes" | "Checks";
+export type GhostLook = "Normal" | "Frightened" | "Eyes";
+export type HouseState = "Outside" | "InHouse" | "LeavingHouse";
+export type FruitKind =
+  | "Cherry" | "Strawberry" | "Orange" | "Apple" | "Melon" | "Galaxian"
+  | "Bell" | "Key";
+export type CellType =
+  | "Empty" | "Wall" | "Dot" | "PowerPellet" | "GhostHouse"
+  | { OneWay: DirectionName } | "Ice" | "Slow" | { Warp: number }
+  | "WallBreaker" | "TimeFreeze" | "SpeedBoost" | { TimedWall: { open: boolean } } | "Switch"
+  | { Gate: { open: boolean } } | { Key: number } | { LockedDoor: number }
+  | "Door";
+export type EntityId = "PacMan" | { Ghost: GhostType };
+export type ActorKind =
+  | "PacMan" | "BigPacMan" | "Blinky" | "FrightenedBlinky" | "TornBlinky"
+  | "PatchedBlinky" | "NakedBlinky";
+export type SoundChannel = "Music" | "Siren" | "Sfx" | "Voice";
+export type SoundAction = "Play" | "Start" | "Stop";
+export type SoundCue =
+  | "Waka" | "WakaAlt" | "PowerPellet" | "GhostEaten" | "Death"
+  | "ExtraLife" | { Siren: number } | "PowerLoop" | "EyesLoop";
+export type StuckRecovery = "Repath" | "Teleport";
+export type CollisionMode = "Distance" | "Tile";
+export type Fidelity = "Simplified" | "Standard" | "Arcade";
+export type Difficulty = "Easy" | "Normal" | "Hard";
+export type MazePolicy = "Classic" | "Rotate" | "Random" | "Generated";
+export type TutorialObjective = "EatDots" | "EatPowerPellet" | "EatGhost" | "Complete";
+export type TutorialHint = "PelletNearby" | "GhostNearby" | "GhostsFrightened";
+export type Landmark = "PacMan" | "HouseExit" | "HouseSlot" | "Fruit";
+/** (row, col) */
+export type Tile = [number, number];
+
+export interface Position {
+  x: number;
+  y: number;
+}
+
+export interface PacMan {
+  position: Position;
+  direction: DirectionName;
+  next_direction: DirectionName;
+  input_age: number;
+  /** Turns pressed after `next_direction`, oldest first, with their ages */
+  queued_turns: [DirectionName, number][];
+  lives: number;
+  score: number;
+  wall_breaks: number;
+  keys: number[];
+  /** Seconds left on a speed boost; 0 when there's none */
+  boost: number;
+}
+
+export interface Ghost {
+  ghost_type: GhostType;
+  position: Position;
+  direction: DirectionName;
+  next_direction: DirectionName;
+  input_age: number;
+  queued_turns: [DirectionName, number][];
+  mode: GhostMode;
+  pattern: GhostPattern;
+  look: GhostLook;
+  frightened_flashing: boolean;
+  flash_white: boolean;
+  house: HouseState;
+  dot_counter: number;
+}
+
+export interface Fruit {
+  kind: FruitKind;
+  position: Position;
+  remaining: number;
+}
+
+export interface Actor {
+  kind: ActorKind;
+  x: number;
+  y: number;
+  direction: DirectionName;
+}
+
+/** Sprite frame indices (see animation.rs) */
+export interface Animation {
+  mouth_frame: number;
+  wiggle_frame: number;
+  flash_frame: number;
+  death_frame: number;
+  mouth_travel: number;
+  wiggle_clock: number;
+}
+
+export interface ScorePopup {
+  points: number;
+  x: number;
+  y: number;
+  remaining: number;
+}
+
+export interface SwitchLink {
+  switch: Tile;
+  gates: Tile[];
+}
+
+export interface Maze {
+  cells: CellType[][];
+  width: number;
+  height: number;
+  slow_multiplier: number;
+  timed_wall_period: number;
+  switch_links: SwitchLink[];
+  tunnels: Tile[];
+  no_up: Tile[];
+  metadata: MazeMetadata;
+}
+
+/** Spawn points and targets; positions are [x, y], tiles [col, row] */
+export interface MazeMetadata {
+  pacman_spawn: [number, number];
+  house_exit: [number, number];
+  house_center: Tile;
+  house_slots: [number, number][];
+  fruit_spawn: [number, number];
+  /** By ghost code: Blinky, Pinky, Inky, Clyde */
+  scatter_targets: Tile[];
+}
+
+/** One thing `Maze::validate` found wrong */
+export type MazeProblem =
+  | { UnreachablePellet: { row: number; col: number } }
+  | "NoGhostHouse"
+  | { HouseOpen: { row: number; col: number } }
+  | { HouseDoors: { count: number } }
+  | { UnpairedTunnel: { row: number } }
+  | { SpawnInWall: { landmark: Landmark; row: number; col: number } };
+
+/** From `GameState.validate_layout()`; no problems means playable */
+export interface MazeReport {
+  problems: MazeProblem[];
+}
+
+export interface GameConfig {
+  urgency_bonus: number;
+  urgency_decay_per_second: number;
+  seed: number;
+  high_contrast: boolean;
+  stuck_timeout: number;
+  stuck_recovery: StuckRecovery;
+  starting_lives: number;
+  extra_life_score: number;
+  scoring: ScoringConfig;
+  cornering_window: number;
+  input_window: number;
+  overflow_bug: boolean;
+  collision: CollisionMode;
+  fidelity: Fidelity;
+  ghost_count: number;
+  ghost_roster: GhostType[];
+  chaos: boolean;
+  fog_radius: number;
+  maze_policy: MazePolicy;
+  mirror: boolean;
+  flip: boolean;
+  pacman_speed: number;
+  ghost_speed: number;
+  assist: Assist;
+}
+
+export interface ScoringConfig {
+  dot: number;
+  power_pellet: number;
+  ghost: number;
+  /** Per fruit, Cherry to Key */
+  fruit: number[];
+}
+
+export interface Assist {
+  invincible: boolean;
+  ghost_slowdown: number;
+  extra_lives: number;
+  game_speed: number;
+  infinite_frightened: boolean;
+  autopilot: boolean;
+}
+
+export interface GameOptions {
+  mode: GameModeName;
+  lives: number;
+  level: number;
+  seed: number;
+  difficulty: Difficulty;
+  /** Layout text for a custom maze (rows of equal width, with a ghost
+   * house); null for the classic maze */
+  maze: string | null;
+  /** Built-in maze per level when `maze` is null */
+  maze_policy: MazePolicy;
+  /** Mirror the maze left to right */
+  mirror: boolean;
+  /** Turn the maze upside down */
+  flip: boolean;
+  ghosts: number;
+  /** Personalities in roster order; overrides `ghosts` when not empty */
+  roster: GhostType[];
+  pacman_speed: number;
+  ghost_speed: number;
+  scoring: Partial<ScoringConfig>;
+  assist: Partial<Assist>;
+}
+
+export interface PacManPlacement {
+  col: number;
+  row: number;
+  direction: DirectionName;
+}
+
+export interface GhostPlacement {
+  ghost: GhostType;
+  col: number;
+  row: number;
+  mode: GhostMode;
+  /** Which ghost in the roster, for rosters that repeat a personality */
+  index?: number | null;
+}
+
+export interface PracticeSetup {
+  mode: GameModeName;
+  level: number;
+  lives: number;
+  seed: number;
+  pacman: PacManPlacement | null;
+  ghosts: GhostPlacement[];
+  /** (row, col) tiles already eaten */
+  eaten: Tile[];
+  /** Seconds into the scatter/chase schedule */
+  wave_offset: number;
+}
+
+/** A situation for `GameState.from_scenario()`; every field is optional */
+export interface Scenario {
+  mode: GameModeName;
+  config: GameConfig;
+  /** One string per row, in the layout legend; empty is the classic maze */
+  maze: string[];
+  pacman: PacManPlacement | null;
+  ghosts: GhostPlacement[];
+  /** (row, col) tiles already eaten */
+  eaten: Tile[];
+  score: number;
+  lives: number | null;
+  level: number;
+  phase: GamePhaseValue;
+  global_timer: number;
+  frightened_timer: number;
+}
+
+export interface ScoreBreakdown {
+  dots: number;
+  power_pellets: number;
+  ghosts: number;
+  fruit: number;
+  time_bonus: number;
+}
+
+/** Tallies for a stats screen, from `get_stats()` */
+export interface Stats {
+  dots_eaten: number;
+  pellets_eaten: number;
+  /** By ghost code: Blinky, Pinky, Inky, Clyde */
+  ghosts_eaten: [number, number, number, number];
+  deaths: number;
+  /** Tiles run, warps and tunnel wraps not included */
+  distance: number;
+  /** Seconds of play on each level, level 1 first */
+  level_times: number[];
+}
+
+/** The PvP ghost player's points (all zero outside PvP) */
+export interface GhostScore {
+  total: number;
+  survival: number;
+  captures: number;
+  pellets_protected: number;
+}
+
+export interface WarpCooldown {
+  id: number;
+  remaining: number;
+}
+
+export interface MazeResult {
+  maze: number;
+  score: number;
+  cleared: boolean;
+}
+
+export interface Campaign {
+  mazes: Maze[];
+  current: number;
+  results: MazeResult[];
+  maze_start_score: number;
+}
+
+export interface RoundResult {
+  round: number;
+  pacman_player: number;
+  pacman_score: number;
+  ghost_score: number;
+  winner: number | null;
+}
+
+/** A best-of-N PvP match; `winner` is `null` until decided, or on a draw */
+export interface Match {
+  rounds: number;
+  round: number;
+  pacman_player: number;
+  wins: [number, number];
+  totals: [number, number];
+  results: RoundResult[];
+  break_timer: number;
+  decided: boolean;
+  winner: number | null;
+}
+
+/** The waiting player's game in alternating two-player */
+export interface PlayerBoard {
+  pacman: PacMan;
+  maze: Maze;
+  dots_remaining: number;
+  level: number;
+  dots_eaten: number;
+  extra_life_awarded: boolean;
+  score_breakdown: ScoreBreakdown;
+}
+
+export interface CampaignSummary {
+  mazes_total: number;
+  mazes_played: number;
+  mazes_cleared: number;
+  final_score: number;
+  lives_remaining: number;
+  results: MazeResult[];
+}
+
+export type GameEvent =
+  | { CellChanged: { row: number; col: number; cell: CellType } }
+  | { DoorOpened: { row: number; col: number; key: number } }
+  | { DotEaten: { row: number; col: number } }
+  | { PowerPelletEaten: { row: number; col: number } }
+  | { FruitSpawned: { kind: FruitKind; row: number; col: number } }
+  | { GhostsFrozen: { row: number; col: number; seconds: number } }
+  | "GhostsThawed"
+  | { ScoreChanged: { score: number } }
+  | { FruitEaten: { kind: FruitKind; points: number } }
+  | { ExtraLife: { lives: number } }
+  | { GhostEaten: { ghost: GhostType; index: number; points: number } }
+  | { PhaseChanged: { from: GamePhaseValue; to: GamePhaseValue } }
+  | { LevelAdvanced: { level: number } }
+  | { Countdown: { seconds: number } }
+  | { LifeLost: { lives: number } }
+  | { LevelComplete: { level: number } }
+  | { RoundOver: { round: number; winner: number | null } }
+  | { RoundStarted: { round: number; pacman_player: number } }
+  | { MatchOver: { winner: number | null } }
+  | { PlayerChanged: { player: number } }
+  | { ObjectiveStarted: { objective: TutorialObjective } }
+  | { HintShown: { hint: TutorialHint } }
+  | { Sound: { cue: SoundCue; action: SoundAction; channel: SoundChannel; priority: number } }
+  | { EntityStuck: { entity: EntityId; row: number; col: number } };
+
+/** The full state, from `to_js()` */
+export interface GameSnapshot {
+  mode: GameModeName;
+  config: GameConfig;
+  phase: GamePhaseValue;
+  maze: Maze;
+  pacman: PacMan;
+  /** Player two, in Co-op only */
+  pacman2: PacMan | null;
+  ghosts: Ghost[];
+  dots_remaining: number;
+  level: number;
+  global_timer: number;
+  frightened_timer: number;
+  freeze_timer: number;
+  phase_timer: number;
+  countdown: number;
+  pellets_visible: boolean;
+  blink_clock: number;
+  cutscene: Actor[];
+  animation: Animation;
+  popups: ScorePopup[];
+  /** Dark mazes: tiles Pac-Man can see, row-major; empty when all lit */
+  visibility: boolean[];
+  global_dot_counter: number | null;
+  house_dot_timer: number;
+  eat_stall: number;
+  waka_alt: boolean;
+  sound_loop: SoundCue | null;
+  fruit: Fruit | null;
+  dots_eaten: number;
+  extra_life_awarded: boolean;
+  high_score: number;
+  warp_cooldowns: WarpCooldown[];
+  events: GameEvent[];
+  timed_wall_timer: number;
+  active_switch: Tile | null;
+  campaign: Campaign | null;
+  pvp_match: Match | null;
+  /** 1 or 2; always 1 outside alternating two-player */
+  active_player: number;
+  waiting_player: PlayerBoard | null;
+  urgency_bonus: number;
+  score_breakdown: ScoreBreakdown;
+  stats: Stats;
+  ghost_score: GhostScore;
+  /** What the seed decided, in a daily challenge */
+  daily: { seed: number; variant: MazeVariant; fruit_order: FruitKind[] } | null;
+  /** Survival mode's clocks, in seconds */
+  survival: { elapsed: number; regen_timer: number } | null;
+  /** Objectives and hints, in the tutorial */
+  tutorial: { objective: TutorialObjective; dots: number; hints: TutorialHint[] } | null;
+  rng: { state: number };
+  last_score: number;
+  last_phase: GamePhaseValue;
+  watchdog: { last_tiles: Tile[]; stalled: number[] };
+}
+
+/** From `get_hud()` */
+export interface Hud {
+  score: number;
+  high_score: number;
+  lives: number;
+  level: number;
+  fruits: FruitKind[];
+  one_up_visible: boolean;
+}
+
+/** Parts `to_js_with()` can leave out; each defaults to true */
+export interface SnapshotOptions {
+  includeMaze: boolean;
+  includeGhosts: boolean;
+  includeTimers: boolean;
+}
+
+/** Keys left out by `includeTimers: false` */
+export type SnapshotTimer =
+  | "global_timer" | "frightened_timer" | "freeze_timer" | "phase_timer"
+  | "blink_clock" | "house_dot_timer" | "eat_stall" | "timed_wall_timer"
+  | "warp_cooldowns";
+
+/** `to_js_with()`: a `GameSnapshot` that may lack the optional parts */
+export type PartialSnapshot =
+  Omit<GameSnapshot, "maze" | "ghosts" | SnapshotTimer>
+  & Partial<Pick<GameSnapshot, "maze" | "ghosts" | SnapshotTimer>>;
+
+/** The per-frame subset, from `to_js_frame()` */
+export interface FrameSnapshot {
+  mode: GameModeName;
+  phase: GamePhaseValue;
+  pacman: PacMan;
+  pacman2: PacMan | null;
+  ghosts: Ghost[];
+  fruit: Fruit | null;
+  dots_remaining: number;
+  level: number;
+  countdown: number;
+  pellets_visible: boolean;
+  animation: Animation;
+  events: GameEvent[];
+}
+"#;
+
+// ─── Typed JS values ────────────────────────────────────────────────────────
+
+#[wasm_bindgen]
+extern "C" {
+    /// A `JsValue` typed as `GameSnapshot` in the `.d.ts`.
+    #[wasm_bindgen(typescript_type = "GameSnapshot")]
+    pub type JsGameSnapshot;
+
+    #[wasm_bindgen(typescript_type = "PartialSnapshot")]
+    pub type JsPartialSnapshot;
+
+    #[wasm_bindgen(typescript_type = "Partial<SnapshotOptions> | undefined")]
+    pub type JsSnapshotOptions;
+
+    #[wasm_bindgen(typescript_type = "FrameSnapshot")]
+    pub type JsFrameSnapshot;
+
+    #[wasm_bindgen(typescript_type = "GameEvent[]")]
+    pub type JsGameEvents;
+
+    #[wasm_bindgen(typescript_type = "(event: GameEvent) => void")]
+    pub type JsEventCallback;
+
+    /// `[col, row]` per ghost, `null` when it has no target.
+    #[wasm_bindgen(typescript_type = "([number, number] | null)[]")]
+    pub type JsGhostTargets;
+
+    #[wasm_bindgen(typescript_type = "Hud")]
+    pub type JsHud;
+
+    #[wasm_bindgen(typescript_type = "Stats")]
+    pub type JsStats;
+
+    #[wasm_bindgen(typescript_type = "MazeReport")]
+    pub type JsMazeReport;
+
+    #[wasm_bindgen(typescript_type = "Partial<Assist>")]
+    pub type JsAssist;
+
+    #[wasm_bindgen(typescript_type = "Partial<PracticeSetup> | undefined")]
+    pub type JsPracticeSetup;
+
+    #[wasm_bindgen(typescript_type = "CampaignSummary | null")]
+    pub type JsCampaignSummary;
+
+    /// Options may leave out any field (or be `undefined` altogether).
+    #[wasm_bindgen(typescript_type = "Partial<GameOptions> | undefined")]
+    pub type JsGameOptions;
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{EntityId, FruitKind, GhostMode, GhostType};
+    use crate::events::{GameEvent, SoundCue};
+    use crate::maze::CellType;
+    use crate::options::GameOptions;
+    use crate::practice::{GhostPlacement, PracticeSetup};
+    use crate::scenario::Scenario;
+    use crate::snapshot::SnapshotOptions;
+    use crate::state::{GameMode, GamePhase, GameStateInner};
+    use crate::tutorial::{Hint, Objective};
+    use serde::Serialize;
+
+    /// Field names of `export interface <name>` in the TS section.
+    fn ts_fields(name: &str) -> Vec<String> {
+        let header = format!("export interface {} {{", name);
+        let start = TS_TYPES.find(&header).expect("interface missing") + header.len();
+        let body = &TS_TYPES[start..start + TS_TYPES[start..].find("\n}").unwrap()];
+        body.lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with("/*") && !l.starts_with('*'))
+            .map(|l| {
+                l.split(':')
+                    .next()
+                    .unwrap()
+                    .trim_end_matches('?')
+                    .to_string()
+            })
+            .collect()
+    }
+
+    /// Keys of `value` serialized as a JSON object.
+    fn json_keys(value: impl Serialize) -> Vec<String> {
+        let json = serde_json::to_value(value).unwrap();
+        json.as_object().unwrap().keys().cloned().collect()
+    }
+
+    fn assert_same_fields(name: &str, value: impl Serialize) {
+        let mut ts = ts_fields(name);
+        let mut rust = json_keys(value);
+        ts.sort();
+        rust.sort();
+        assert_eq!(ts, rust, "TS interface {} is out of date", name);
+    }
+
+    #[test]
+    fn interfaces_match_serialized_structs() {
+        let state = GameStateInner::new(GameMode::Classic);
+        assert_same_fields("GameSnapshot", &state);
+        assert_same_fields("FrameSnapshot", state.frame());
+        assert_same_fields("GameConfig", &state.config);
+        assert_same_fields("Assist", &state.config.assist);
+        assert_same_fields("ScoringConfig", &state.config.scoring);
+        assert_same_fields("GameOptions", GameOptions::default());
+        assert_same_fields("SnapshotOptions", SnapshotOptions::default());
+        assert_same_fields("PracticeSetup", PracticeSetup::default());
+        assert_same_fields("Scenario", Scenario::default());
+        let placed = GhostPlacement {
+            ghost: GhostType::Inky,
+            col: 0,
+            row: 0,
+            mode: GhostMode::Chase,
+            index: None,
+        };
+        assert_same_fields("GhostPlacement", placed);
+        assert_same_fields("PacMan", &state.pacman);
+        assert_same_fields("Ghost", &state.ghosts[0]);
+        assert_same_fields("Maze", &state.maze);
+        assert_same_fields("MazeMetadata", &state.maze.metadata);
+        assert_same_fields("MazeReport", state.maze.validate());
+        assert_same_fields("Animation", &state.animation);
+        assert_same_fields("Hud", state.hud());
+        assert_same_fields("Stats", &state.stats);
+        assert_same_fields("GhostScore", &state.ghost_score);
+    }
+
+    #[test]
+    fn every_event_variant_is_typed() {
+        let samples = [
+            GameEvent::CellChanged {
+                row: 0,
+                col: 0,
+                cell: CellType::Empty,
+            },
+            GameEvent::DoorOpened {
+                row: 0,
+                col: 0,
+                key: 0,
+            },
+            GameEvent::DotEaten { row: 0, col: 0 },
+            GameEvent::PowerPelletEaten { row: 0, col: 0 },
+            GameEvent::GhostsFrozen {
+                row: 0,
+                col: 0,
+                seconds: 0.0,
+            },
+            GameEvent::GhostsThawed,
+            GameEvent::FruitSpawned {
+                kind: FruitKind::Cherry,
+                row: 0,
+                col: 0,
+            },
+            GameEvent::ScoreChanged { score: 0 },
+            GameEvent::FruitEaten {
+                kind: FruitKind::Cherry,
+                points: 0,
+            },
+            GameEvent::ExtraLife { lives: 0 },
+            GameEvent::GhostEaten {
+                ghost: GhostType::Inky,
+                index: 0,
+                points: 0,
+            },
+            GameEvent::PhaseChanged {
+                from: GamePhase::Ready,
+                to: GamePhase::Playing,
+            },
+            GameEvent::LevelAdvanced { level: 0 },
+            GameEvent::Countdown { seconds: 0 },
+            GameEvent::LifeLost { lives: 0 },
+            GameEvent::LevelComplete { level: 0 },
+            GameEvent::RoundOver {
+                round: 0,
+                winner: None,
+            },
+            GameEvent::RoundStarted {
+                round: 0,
+                pacman_player: 0,
+            },
+            GameEvent::MatchOver { winner: None },
+            GameEvent::PlayerChanged { player: 0 },
+            GameEvent::ObjectiveStarted {
+                objective: Objective::EatDots,
+            },
+            GameEvent::HintShown {
+                hint: Hint::GhostNearby,
+            },
+            GameEvent::sound(SoundCue::Waka),
+            GameEvent::EntityStuck {
+                entity: EntityId::PacMan,
+                row: 0,
+                col: 0,
+            },
+        ];
+        for event in samples {
+            let json = serde_json::to_value(&event).unwrap();
+            // Variants without fields serialize to just their name
+            if let Some(variant) = json.as_str() {
+                assert!(
+                    TS_TYPES.contains(&format!("| \"{}\"", variant)),
+                    "{}",
+                    variant
+                );
+                continue;
+            }
+            let (variant, payload) = json.as_object().unwrap().iter().next().unwrap();
+            let line = TS_TYPES
+                .lines()
+                .find(|l| l.contains(&format!("{{ {}: {{", variant)))
+                .unwrap_or_else(|| panic!("GameEvent {} is not typed", variant));
+            for key in payload.as_object().unwrap().keys() {
+                assert!(line.contains(&format!(" {}: ", key)), "{}.{}", variant, key);
+            }
+        }
+    }
+}
@@ -0,0 +1,368 @@
+// game/src/validate.rs
+//
+// Checking that a maze is playable.
+//
+// `Maze::from_str` only checks that layout text is well formed; a board can
+// still be unwinnable or break the ghosts. `Maze::validate` looks for:
+//
+// - dots and power pellets Pac-Man can't reach from his spawn,
+// - a ghost house that leaks into the maze, or has no door or several,
+// - side tunnels open on one edge but not the other,
+// - spawn points (Pac-Man, ghosts, fruit) on a wall or off the board.
+//
+// It reports every problem it finds rather than stopping at the first, so
+// a maze editor can mark them all at once. Custom mazes from `GameOptions`
+// and scenarios are refused when the report isn't clean, and
+// `GameState.validate_layout()` hands the report to JS.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::entities::Direction;
+use crate::maze::{CellType, Maze};
+
+// ─── Report ─────────────────────────────────────────────────────────────────
+
+/// A spawn point a maze's metadata names.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum Landmark {
+    PacMan,
+    /// Outside the house door; the first ghost starts here.
+    HouseExit,
+    /// Where a ghost waits inside the house.
+    HouseSlot,
+    Fruit,
+}
+
+/// One thing wrong with a maze. Rows and columns count from 0.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum MazeProblem {
+    /// Pac-Man can't get to this dot or power pellet from his spawn.
+    UnreachablePellet { row: usize, col: usize },
+    /// There are no ghost house tiles at all.
+    NoGhostHouse,
+    /// The house's inside reaches Pac-Man's corridors (or the board's edge)
+    /// here without passing a door.
+    HouseOpen { row: usize, col: usize },
+    /// The house has `count` doors instead of one. A door is a run of
+    /// side-by-side door tiles.
+    HouseDoors { count: usize },
+    /// A row open on one side edge but not the other.
+    UnpairedTunnel { row: usize },
+    /// A spawn point on a wall or off the board.
+    SpawnInWall {
+        landmark: Landmark,
+        row: isize,
+        col: isize,
+    },
+}
+
+impl fmt::Display for MazeProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MazeProblem::UnreachablePellet { row, col } => {
+                write!(f, "pellet at row {}, column {} can't be reached", row, col)
+            }
+            MazeProblem::NoGhostHouse => write!(f, "there's no ghost house"),
+            MazeProblem::HouseOpen { row, col } => {
+                write!(f, "the ghost house is open at row {}, column {}", row, col)
+            }
+            MazeProblem::HouseDoors { count } => {
+                write!(f, "the ghost house has {} doors; it needs one", count)
+            }
+            MazeProblem::UnpairedTunnel { row } => {
+                write!(f, "the tunnel on row {} only opens on one side", row)
+            }
+            MazeProblem::SpawnInWall { landmark, row, col } => write!(
+                f,
+                "{:?} spawn at row {}, column {} is in a wall",
+                landmark, row, col
+            ),
+        }
+    }
+}
+
+/// Everything `Maze::validate` found.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct MazeReport {
+    pub problems: Vec<MazeProblem>,
+}
+
+impl MazeReport {
+    /// Whether the maze passed every check.
+    pub fn is_valid(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+impl fmt::Display for MazeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_valid() {
+            return write!(f, "maze is valid");
+        }
+        for (i, problem) in self.problems.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", problem)?;
+        }
+        Ok(())
+    }
+}
+
+// ─── Checks ─────────────────────────────────────────────────────────────────
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Left,
+    Direction::Down,
+    Direction::Right,
+];
+
+impl Maze {
+    /// Check the maze is playable (see the top of `validate.rs`).
+    pub fn validate(&self) -> MazeReport {
+        let mut problems = Vec::new();
+        let reachable = self.pacman_reachable();
+
+        for (row, cells) in self.cells.iter().enumerate() {
+            for (col, &cell) in cells.iter().enumerate() {
+                let pellet = matches!(cell, CellType::Dot | CellType::PowerPellet);
+                if pellet && !reachable[row * self.width + col] {
+                    problems.push(MazeProblem::UnreachablePellet { row, col });
+                }
+            }
+        }
+        self.check_house(&reachable, &mut problems);
+        for row in 0..self.height {
+            let left = self.is_walkable(0.0, row as f64);
+            let right = self.is_walkable(self.width as f64 - 1.0, row as f64);
+            if left != right {
+                problems.push(MazeProblem::UnpairedTunnel { row });
+            }
+        }
+
+        let meta = &self.metadata;
+        let spawns = [
+            (Landmark::PacMan, meta.pacman_spawn),
+            (Landmark::HouseExit, meta.house_exit),
+            (Landmark::Fruit, meta.fruit_spawn),
+        ]
+        .into_iter()
+        .chain(meta.house_slots.iter().map(|&p| (Landmark::HouseSlot, p)));
+        for (landmark, (x, y)) in spawns {
+            let (col, row) = (x.round() as isize, y.round() as isize);
+            let on_board = col >= 0 && row >= 0;
+            let cell = on_board
+                .then(|| self.get_cell(row as usize, col as usize))
+                .flatten();
+            if matches!(cell, None | Some(CellType::Wall)) {
+                problems.push(MazeProblem::SpawnInWall { landmark, row, col });
+            }
+        }
+        MazeReport { problems }
+    }
+
+    /// Tiles Pac-Man can get to from his spawn, row-major. Closed gates,
+    /// timed walls and locked doors count as open: they open in play.
+    fn pacman_reachable(&self) -> Vec<bool> {
+        let passable = |col: usize, row: usize| {
+            !matches!(
+                self.get_cell(row, col),
+                None | Some(CellType::Wall | CellType::GhostHouse | CellType::Door)
+            )
+        };
+        let mut seen = vec![false; self.width * self.height];
+        let (x, y) = self.metadata.pacman_spawn;
+        let (col, row) = (x.round() as isize, y.round() as isize);
+        if col < 0 || row < 0 || !passable(col as usize, row as usize) {
+            return seen;
+        }
+        let mut queue = VecDeque::from([(col as usize, row as usize)]);
+        seen[row as usize * self.width + col as usize] = true;
+
+        while let Some((col, row)) = queue.pop_front() {
+            let mut next = Vec::new();
+            for dir in DIRECTIONS {
+                let (dx, dy) = dir.to_vector();
+                if !self.can_leave(col as f64, row as f64, dir) {
+                    continue;
+                }
+                // Tunnels wrap around the sides
+                let to_col = (col as isize + dx as isize).rem_euclid(self.width as isize) as usize;
+                let to_row = row as isize + dy as isize;
+                if to_row < 0 || !passable(to_col, to_row as usize) {
+                    continue;
+                }
+                if let Some(CellType::OneWay(gate)) = self.get_cell(to_row as usize, to_col) {
+                    if gate != dir {
+                        continue;
+                    }
+                }
+                next.push((to_col, to_row as usize));
+            }
+            if let Some((r, c)) = self.warp_partner(row, col) {
+                next.push((c, r));
+            }
+            for (col, row) in next {
+                if !seen[row * self.width + col] {
+                    seen[row * self.width + col] = true;
+                    queue.push_back((col, row));
+                }
+            }
+        }
+        seen
+    }
+
+    /// The house must be closed off from `reachable` and the edges, with
+    /// one door.
+    fn check_house(&self, reachable: &[bool], problems: &mut Vec<MazeProblem>) {
+        let house = self.positions_of(CellType::GhostHouse);
+        if house.is_empty() {
+            problems.push(MazeProblem::NoGhostHouse);
+            return;
+        }
+        // The inside: everything the house floor connects to short of a
+        // wall or a door
+        let mut inside = vec![false; self.width * self.height];
+        let mut doors: Vec<(usize, usize)> = Vec::new();
+        let mut queue: VecDeque<(usize, usize)> = house.into_iter().collect();
+        for &(row, col) in &queue {
+            inside[row * self.width + col] = true;
+        }
+        let mut open_at = None;
+        while let Some((row, col)) = queue.pop_front() {
+            let at_edge = row == 0 || col == 0 || row + 1 == self.height || col + 1 == self.width;
+            if open_at.is_none() && (at_edge || reachable[row * self.width + col]) {
+                open_at = Some((row, col));
+            }
+            for dir in DIRECTIONS {
+                let (dx, dy) = dir.to_vector();
+                let (r, c) = (row as isize + dy as isize, col as isize + dx as isize);
+                if r < 0 || c < 0 {
+                    continue;
+                }
+                let (r, c) = (r as usize, c as usize);
+                match self.get_cell(r, c) {
+                    None | Some(CellType::Wall) => {}
+                    Some(CellType::Door) => {
+                        if !doors.contains(&(r, c)) {
+                            doors.push((r, c));
+                        }
+                    }
+                    Some(_) if !inside[r * self.width + c] => {
+                        inside[r * self.width + c] = true;
+                        queue.push_back((r, c));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        if let Some((row, col)) = open_at {
+            problems.push(MazeProblem::HouseOpen { row, col });
+        }
+
+        // Door tiles side by side make one door
+        let mut count = 0;
+        let mut grouped = vec![false; doors.len()];
+        for start in 0..doors.len() {
+            if grouped[start] {
+                continue;
+            }
+            count += 1;
+            grouped[start] = true;
+            let mut stack = vec![doors[start]];
+            while let Some((row, col)) = stack.pop() {
+                for (i, &(r, c)) in doors.iter().enumerate() {
+                    if !grouped[i] && r.abs_diff(row) + c.abs_diff(col) == 1 {
+                        grouped[i] = true;
+                        stack.push((r, c));
+                    }
+                }
+            }
+        }
+        if count != 1 {
+            problems.push(MazeProblem::HouseDoors { count });
+        }
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layouts::MazeLayout;
+
+    /// A small maze that passes, with every landmark marked.
+    const GOOD: &str = "\
+WWWWWWWWW
+W@.obF..W
+W.WW-WW.W
+W.WpicW.W
+W.WWWWW.W
+W.......W
+WWWWWWWWW";
+
+    fn check(layout: &str) -> Vec<MazeProblem> {
+        layout.parse::<Maze>().unwrap().validate().problems
+    }
+
+    #[test]
+    fn playable_mazes_pass() {
+        assert_eq!(check(GOOD), vec![]);
+        for layout in MazeLayout::ALL {
+            let report = Maze::builtin(layout).validate();
+            assert!(report.is_valid(), "{:?}: {}", layout, report);
+        }
+    }
+
+    #[test]
+    fn every_problem_is_reported() {
+        // A walled-off dot, a second door, and a tunnel with one mouth
+        let problems = check(
+            "\
+WWWWWWWWW
+W@.obF..W
+W.WW-WW.W
+W.WpicW.W
+W.WW-WW.W
+E.....W.W
+WWWWWWW.W",
+        );
+        assert!(problems.contains(&MazeProblem::HouseDoors { count: 2 }));
+        assert!(problems.contains(&MazeProblem::UnpairedTunnel { row: 5 }));
+
+        let problems = check(
+            "\
+WWWWWWWWW
+W@.obFW.W
+W.WW-WWWW
+W.WpicW.W
+W.WWWWW.W
+W.....W.W
+WWWWWWWWW",
+        );
+        assert_eq!(
+            problems,
+            vec![
+                MazeProblem::UnreachablePellet { row: 1, col: 7 },
+                MazeProblem::UnreachablePellet { row: 3, col: 7 },
+                MazeProblem::UnreachablePellet { row: 4, col: 7 },
+                MazeProblem::UnreachablePellet { row: 5, col: 7 },
+            ]
+        );
+
+        // No markers: the classic spawns are off this little board
+        let problems = check("WWWWW\nEGE.W\nWWWWW");
+        assert!(problems.contains(&MazeProblem::HouseOpen { row: 1, col: 0 }));
+        assert!(problems.contains(&MazeProblem::HouseDoors { count: 0 }));
+        assert!(problems.contains(&MazeProblem::SpawnInWall {
+            landmark: Landmark::PacMan,
+            row: 23,
+            col: 14,
+        }));
+    }
+}
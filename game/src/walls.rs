@@ -0,0 +1,216 @@
+// game/src/walls.rs
+//
+// Wall outlines for vector rendering.
+//
+// Instead of drawing every wall tile, the renderer can stroke the outlines
+// of connected wall regions (and round their corners) for the classic
+// arcade look. The outlines only depend on the static `Wall` cells, so JS
+// computes them once per maze.
+//
+// # Coordinates
+// Path points are tile *corners*: tile (col, row) spans `col..col+1` and
+// `row..row+1`. Multiply by the tile size to get pixels.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::maze::{CellType, Maze};
+
+// ─── Wall paths ─────────────────────────────────────────────────────────────
+
+/// One closed outline around wall tiles.
+///
+/// Points run clockwise on screen (y down) with the wall on the right-hand
+/// side, and only corners are kept — straight runs are merged.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WallPath {
+    /// `true` for the outside edge of a wall block, `false` for the edge of
+    /// an open area enclosed by walls (e.g. the inside of the border).
+    pub outer: bool,
+    pub points: Vec<(i32, i32)>,
+}
+
+impl Maze {
+    /// Trace the outlines of every connected group of wall tiles.
+    pub fn wall_paths(&self) -> Vec<WallPath> {
+        // Every wall side that faces a non-wall tile becomes a directed
+        // edge, oriented so the wall is on its right.
+        let mut edges = Vec::new();
+        for row in 0..self.height {
+            for col in 0..self.width {
+                if !self.is_wall(col as i32, row as i32) {
+                    continue;
+                }
+                let (c, r) = (col as i32, row as i32);
+                if !self.is_wall(c, r - 1) {
+                    edges.push(((c, r), (c + 1, r)));
+                }
+                if !self.is_wall(c + 1, r) {
+                    edges.push(((c + 1, r), (c + 1, r + 1)));
+                }
+                if !self.is_wall(c, r + 1) {
+                    edges.push(((c + 1, r + 1), (c, r + 1)));
+                }
+                if !self.is_wall(c - 1, r) {
+                    edges.push(((c, r + 1), (c, r)));
+                }
+            }
+        }
+
+        let mut outgoing: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        for (i, (from, _)) in edges.iter().enumerate() {
+            outgoing.entry(*from).or_default().push(i);
+        }
+
+        let mut used = vec![false; edges.len()];
+        let mut paths = Vec::new();
+        for first in 0..edges.len() {
+            if used[first] {
+                continue;
+            }
+            let start = edges[first].0;
+            let mut points = vec![start];
+            let mut current = first;
+            loop {
+                used[current] = true;
+                let (from, to) = edges[current];
+                if to == start {
+                    break;
+                }
+                points.push(to);
+
+                // Where two wall blocks touch diagonally, a corner has two
+                // ways out; turning right keeps hugging the same block.
+                let heading = (to.0 - from.0, to.1 - from.1);
+                let right = (-heading.1, heading.0);
+                let candidates: Vec<usize> = outgoing[&to]
+                    .iter()
+                    .copied()
+                    .filter(|&e| !used[e])
+                    .collect();
+                let next = candidates.iter().copied().find(|&e| {
+                    let (a, b) = edges[e];
+                    (b.0 - a.0, b.1 - a.1) == right
+                });
+                match next.or(candidates.first().copied()) {
+                    Some(e) => current = e,
+                    None => break,
+                }
+            }
+
+            let points = merge_straight_runs(points);
+            paths.push(WallPath {
+                outer: signed_area(&points) > 0,
+                points,
+            });
+        }
+        paths
+    }
+
+    /// Flatten `wall_paths()` for JS as
+    /// `[outer, point_count, x0, y0, x1, y1, ..., outer, point_count, ...]`.
+    pub fn wall_path_data(&self) -> Vec<f64> {
+        let mut data = Vec::new();
+        for path in self.wall_paths() {
+            data.push(if path.outer { 1.0 } else { 0.0 });
+            data.push(path.points.len() as f64);
+            for (x, y) in path.points {
+                data.push(x as f64);
+                data.push(y as f64);
+            }
+        }
+        data
+    }
+
+    /// Outside the grid counts as open, so border walls get an outline too.
+    fn is_wall(&self, col: i32, row: i32) -> bool {
+        col >= 0 && row >= 0 && self.get_cell(row as usize, col as usize) == Some(CellType::Wall)
+    }
+}
+
+/// Drop the points of a closed path that lie in the middle of a straight run.
+fn merge_straight_runs(points: Vec<(i32, i32)>) -> Vec<(i32, i32)> {
+    let n = points.len();
+    (0..n)
+        .filter(|&i| {
+            let prev = points[(i + n - 1) % n];
+            let here = points[i];
+            let next = points[(i + 1) % n];
+            let a = ((here.0 - prev.0).signum(), (here.1 - prev.1).signum());
+            let b = ((next.0 - here.0).signum(), (next.1 - here.1).signum());
+            a != b
+        })
+        .map(|i| points[i])
+        .collect()
+}
+
+/// Twice the signed area of a closed path; positive when it runs clockwise
+/// on screen.
+fn signed_area(points: &[(i32, i32)]) -> i32 {
+    let n = points.len();
+    (0..n)
+        .map(|i| {
+            let (x0, y0) = points[i];
+            let (x1, y1) = points[(i + 1) % n];
+            x0 * y1 - x1 * y0
+        })
+        .sum()
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_block_is_one_rectangle() {
+        let maze = Maze::from_rows(&["....", ".WW.", "...."], 4);
+        let paths = maze.wall_paths();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].outer);
+        assert_eq!(paths[0].points, vec![(1, 1), (3, 1), (3, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn ring_has_outer_and_inner_contours() {
+        let maze = Maze::from_rows(&["WWW", "W.W", "WWW"], 3);
+        let paths = maze.wall_paths();
+        assert_eq!(paths.len(), 2);
+
+        let outer = paths.iter().find(|p| p.outer).unwrap();
+        let inner = paths.iter().find(|p| !p.outer).unwrap();
+        assert_eq!(outer.points.len(), 4);
+        assert_eq!(inner.points.len(), 4);
+        assert!(inner.points.contains(&(1, 1)) && inner.points.contains(&(2, 2)));
+    }
+
+    #[test]
+    fn classic_maze_paths_cover_every_exposed_wall_edge() {
+        let maze = Maze::new();
+        let paths = maze.wall_paths();
+        assert!(!paths.is_empty());
+
+        // Every path is closed and axis-aligned
+        for path in &paths {
+            let n = path.points.len();
+            assert!(n >= 4);
+            for i in 0..n {
+                let (a, b) = (path.points[i], path.points[(i + 1) % n]);
+                assert!(a.0 == b.0 || a.1 == b.1, "diagonal segment {:?}", (a, b));
+            }
+        }
+
+        // The flattened form walks the same paths
+        let data = maze.wall_path_data();
+        let mut i = 0;
+        let mut count = 0;
+        while i < data.len() {
+            i += 2 + 2 * data[i + 1] as usize;
+            count += 1;
+        }
+        assert_eq!(i, data.len());
+        assert_eq!(count, paths.len());
+    }
+}
@@ -0,0 +1,274 @@
+// game/src/watchdog.rs
+//
+// Stuck-entity watchdog.
+//
+// A malformed custom maze (or an unwalkable ghost-house exit) can leave a
+// ghost pinned in place forever. The watchdog notices when an AI-driven
+// entity hasn't changed tile for `GameConfig::stuck_timeout` seconds,
+// reports it with an `EntityStuck` event, and applies the configured
+// `StuckRecovery`.
+//
+// Only AI-driven entities are watched: the ghosts, and Pac-Man while the
+// demo or the autopilot steers him. A player's Pac-Man and the PvP ghost
+// are allowed to stand still because a player chose to.
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::StuckRecovery;
+use crate::entities::{Direction, EntityId, HouseState, Position};
+use crate::events::GameEvent;
+use crate::maze::Maze;
+use crate::state::{GamePhase, GameStateInner};
+
+// ─── Watchdog state ─────────────────────────────────────────────────────────
+
+/// Per-ghost progress tracking, indexed like `GameStateInner::ghosts`,
+/// plus Pac-Man's while he's AI-driven.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Watchdog {
+    last_tiles: Vec<(usize, usize)>,
+    stalled: Vec<f64>,
+    /// Pac-Man's tile, `None` while a player steers him.
+    pacman_tile: Option<(usize, usize)>,
+    pacman_stalled: f64,
+}
+
+impl GameStateInner {
+    /// Advance the watchdog by `dt` seconds and recover stuck entities.
+    pub(crate) fn update_watchdog(&mut self, dt: f64) {
+        let timeout = self.config.stuck_timeout;
+        if timeout <= 0.0 {
+            return;
+        }
+        self.watch_pacman(dt, timeout);
+
+        let count = self.ghosts.len();
+        if self.watchdog.last_tiles.len() != count {
+            self.watchdog.last_tiles = self.ghosts.iter().map(|g| g.position.to_grid()).collect();
+            self.watchdog.stalled = vec![0.0; count];
+            return;
+        }
+
+        for i in 0..count {
+            let ghost = &self.ghosts[i];
+            let tile = ghost.position.to_grid();
+            let player_driven = self.player_ghost() == Some(i);
+            // Waiting in the house, or held by a time freeze, is expected,
+            // not stuck
+            let housed = ghost.house != HouseState::Outside;
+            let held = ghost.frozen || self.ghosts_frozen();
+            if player_driven || housed || held || tile != self.watchdog.last_tiles[i] {
+                self.watchdog.last_tiles[i] = tile;
+                self.watchdog.stalled[i] = 0.0;
+                continue;
+            }
+
+            self.watchdog.stalled[i] += dt;
+            if self.watchdog.stalled[i] < timeout {
+                continue;
+            }
+
+            let (col, row) = tile;
+            self.events.push(GameEvent::EntityStuck {
+                entity: EntityId::Ghost(ghost.ghost_type),
+                row,
+                col,
+            });
+            let (position, direction) = self.recovery(&ghost.position, ghost.direction);
+            let ghost = &mut self.ghosts[i];
+            ghost.position = position;
+            ghost.direction = direction;
+            ghost.next_direction = direction;
+            ghost.queued_turns.clear();
+            self.watchdog.last_tiles[i] = ghost.position.to_grid();
+            self.watchdog.stalled[i] = 0.0;
+        }
+    }
+
+    /// Watch Pac-Man while the demo or the autopilot steers him.
+    fn watch_pacman(&mut self, dt: f64, timeout: f64) {
+        let ai_driven = self.phase == GamePhase::Demo || self.config.assist.autopilot;
+        let tile = self.pacman.position.to_grid();
+        if !ai_driven || self.watchdog.pacman_tile != Some(tile) {
+            self.watchdog.pacman_tile = ai_driven.then_some(tile);
+            self.watchdog.pacman_stalled = 0.0;
+            return;
+        }
+
+        self.watchdog.pacman_stalled += dt;
+        if self.watchdog.pacman_stalled < timeout {
+            return;
+        }
+        let (col, row) = tile;
+        self.events.push(GameEvent::EntityStuck {
+            entity: EntityId::PacMan,
+            row,
+            col,
+        });
+        let (position, direction) = self.recovery(&self.pacman.position, self.pacman.direction);
+        self.pacman.position = position;
+        self.pacman.direction = direction;
+        self.pacman.next_direction = direction;
+        self.pacman.queued_turns.clear();
+        self.watchdog.pacman_tile = Some(self.pacman.position.to_grid());
+        self.watchdog.pacman_stalled = 0.0;
+    }
+
+    /// Where an entity stuck at `position`, heading `direction`, goes and
+    /// which way it then faces: a working exit from its tile under
+    /// `Repath`, otherwise the nearest open tile.
+    fn recovery(&self, position: &Position, direction: Direction) -> (Position, Direction) {
+        let (x, y) = (position.x.round(), position.y.round());
+
+        if self.config.stuck_recovery == StuckRecovery::Repath {
+            // Prefer any exit other than the one that isn't working
+            let exits = [
+                direction.opposite(),
+                Direction::Up,
+                Direction::Left,
+                Direction::Down,
+                Direction::Right,
+            ];
+            if let Some(dir) = exits
+                .into_iter()
+                .find(|&d| d != direction && self.maze.can_move(x, y, d))
+            {
+                return (Position::new(x, y), dir);
+            }
+        }
+
+        // Teleport, also the fallback when re-pathing finds no exit
+        match nearest_open_tile(&self.maze, x as usize, y as usize) {
+            Some((col, row)) => (Position::new(col as f64, row as f64), direction),
+            None => (position.clone(), direction),
+        }
+    }
+}
+
+/// The closest tile (by Manhattan distance, ties broken by row then column)
+/// other than (col, row) that is walkable and has at least one way out.
+fn nearest_open_tile(maze: &Maze, col: usize, row: usize) -> Option<(usize, usize)> {
+    let dirs = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+    let max = maze.width + maze.height;
+    for dist in 1..=max as isize {
+        for dr in -dist..=dist {
+            let dc_abs = dist - dr.abs();
+            for dc in [-dc_abs, dc_abs] {
+                let (c, r) = (col as isize + dc, row as isize + dr);
+                if c < 0 || r < 0 || c >= maze.width as isize || r >= maze.height as isize {
+                    continue;
+                }
+                let (x, y) = (c as f64, r as f64);
+                if maze.is_walkable(x, y) && dirs.iter().any(|&d| maze.can_move(x, y, d)) {
+                    return Some((c as usize, r as usize));
+                }
+                if dc_abs == 0 {
+                    break; // -0 and 0 are the same tile
+                }
+            }
+        }
+    }
+    None
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GameConfig;
+    use crate::entities::{GhostMode, GhostType};
+    use crate::freeze::FREEZE_SECONDS;
+    use crate::maze::CellType;
+    use crate::scenario::ScenarioBuilder;
+    use crate::state::GameMode;
+
+    /// Blinky boxed in on all four sides at (6, 5).
+    fn boxed_in(recovery: StuckRecovery) -> GameStateInner {
+        let config = GameConfig {
+            stuck_timeout: 1.0,
+            stuck_recovery: recovery,
+            ..GameConfig::default()
+        };
+        let mut state = ScenarioBuilder::new(GameMode::Classic)
+            .config(config)
+            .ghost(GhostType::Blinky, 5, 6, GhostMode::Chase)
+            .phase(GamePhase::Playing)
+            .build();
+        for (row, col) in [(4, 6), (6, 6), (5, 5), (5, 7)] {
+            state.maze.set_cell(row, col, CellType::Wall);
+        }
+        state
+    }
+
+    /// Drive only the watchdog, so Blinky stays pinned however the
+    /// movement code would treat the walls.
+    fn run(state: &mut GameStateInner, seconds: f64) -> bool {
+        reported(state, seconds, EntityId::Ghost(GhostType::Blinky))
+    }
+
+    /// Whether `entity` is reported stuck within `seconds` of watchdog.
+    fn reported(state: &mut GameStateInner, seconds: f64, entity: EntityId) -> bool {
+        for _ in 0..(seconds / 0.1) as usize {
+            state.update_watchdog(0.1);
+        }
+        state
+            .events
+            .iter()
+            .any(|e| matches!(e, GameEvent::EntityStuck { entity: id, .. } if *id == entity))
+    }
+
+    #[test]
+    fn trapped_ghost_is_reported_and_teleported() {
+        let mut state = boxed_in(StuckRecovery::Teleport);
+        assert!(run(&mut state, 1.5));
+        assert_ne!(state.ghosts[0].position.to_grid(), (6, 5));
+    }
+
+    #[test]
+    fn repath_falls_back_to_teleport_without_exits() {
+        let mut state = boxed_in(StuckRecovery::Repath);
+        assert!(run(&mut state, 1.5));
+        assert_ne!(state.ghosts[0].position.to_grid(), (6, 5));
+    }
+
+    #[test]
+    fn wedged_autopilot_pacman_is_reported_and_moved() {
+        let mut state = boxed_in(StuckRecovery::Teleport);
+        state.ghosts[0].position = Position::new(21.0, 5.0);
+        state.pacman.position = Position::new(6.0, 5.0);
+        assert!(!reported(&mut state, 1.5, EntityId::PacMan));
+
+        state.config.assist.autopilot = true;
+        assert!(reported(&mut state, 1.5, EntityId::PacMan));
+        assert_ne!(state.pacman.position.to_grid(), (6, 5));
+    }
+
+    #[test]
+    fn time_freeze_is_not_a_stall() {
+        let mut state = boxed_in(StuckRecovery::Teleport);
+        // Two back-to-back pickups hold Blinky longer than the timeout
+        state.freeze_timer = 2.0 * FREEZE_SECONDS;
+        assert!(!run(&mut state, 1.5));
+        assert_eq!(state.ghosts[0].position.to_grid(), (6, 5));
+
+        // The count starts over once he thaws
+        state.freeze_timer = 0.0;
+        assert!(!run(&mut state, 0.5));
+        assert!(run(&mut state, 1.0));
+    }
+
+    #[test]
+    fn disabled_watchdog_leaves_ghost_alone() {
+        let mut state = boxed_in(StuckRecovery::Teleport);
+        state.config.stuck_timeout = 0.0;
+        assert!(!run(&mut state, 1.5));
+        assert_eq!(state.ghosts[0].position.to_grid(), (6, 5));
+    }
+}